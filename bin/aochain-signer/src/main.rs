@@ -0,0 +1,45 @@
+//! A remote signer answering Aochain nodes started with `--remote-signer`.
+//!
+//! It signs with the keys of a keystore directory, plain or encrypted with `keys encrypt`, and
+//! is meant for testing the signer protocol: production signers keep their keys in hardware.
+
+use cli::keystore::{encrypted, remote};
+use std::{fs, path::PathBuf};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "aochain-signer", about = "Sign BABE blocks, GRANDPA votes and ImOnline heartbeats for a node.")]
+struct Opt {
+	/// The Unix socket to listen on, given to the node with `--remote-signer`.
+	#[structopt(long, value_name = "SOCKET", parse(from_os_str))]
+	socket: PathBuf,
+
+	/// The keystore directory holding the keys, such as `<base-path>/chains/<chain>/keystore`.
+	#[structopt(long = "keystore-path", value_name = "PATH", parse(from_os_str))]
+	keystore_path: PathBuf,
+
+	/// Read the keystore as encrypted with the password of `--password-filename`.
+	#[structopt(long)]
+	encrypted: bool,
+
+	/// The file holding the password of the encrypted keystore.
+	#[structopt(long = "password-filename", value_name = "PATH", parse(from_os_str))]
+	password_filename: Option<PathBuf>,
+}
+
+fn main() -> Result<(), String> {
+	let opt = Opt::from_args();
+
+	let keystore = if opt.encrypted {
+		let path = opt.password_filename.ok_or("The encrypted keystore needs `--password-filename`")?;
+		let password = fs::read_to_string(&path)
+			.map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+		encrypted::load(&opt.keystore_path, &password).map_err(|e| e.to_string())?
+	} else {
+		sc_keystore::Store::open(opt.keystore_path.clone(), None)
+			.map_err(|e| format!("Failed to open the keystore at {}: {:?}", opt.keystore_path.display(), e))?
+	};
+
+	eprintln!("Signing with {} on {}", opt.keystore_path.display(), opt.socket.display());
+	remote::serve(&opt.socket, keystore).map_err(|e| format!("The signer failed: {}", e))
+}
@@ -1,3 +0,0 @@
-fn main() -> cli::Result<()> {
-    cli::run()
-}
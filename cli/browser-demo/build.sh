@@ -0,0 +1,4 @@
+#!/usr/bin/env sh
+cargo +nightly build --release -p aochain-cli --target wasm32-unknown-unknown --no-default-features --features browser -Z features=itarget
+wasm-bindgen ../../target/wasm32-unknown-unknown/release/aochain_cli.wasm --out-dir pkg --target web
+python -m http.server 8000
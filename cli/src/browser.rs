@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::chain_spec::ChainSpec;
+use crate::chain_spec::gama::ChainSpec;
 use log::info;
 use wasm_bindgen::prelude::*;
 use browser_utils::{
@@ -39,7 +39,7 @@ async fn start_inner(chain_spec: Option<String>, log_level: String) -> Result<Cl
 	let chain_spec = match chain_spec {
 		Some(chain_spec) => ChainSpec::from_json_bytes(chain_spec.as_bytes().to_vec())
 			.map_err(|e| format!("{:?}", e))?,
-		None => crate::chain_spec::development_config(),
+		None => crate::chain_spec::gama::development_config(),
 	};
 
 	let config = browser_configuration(chain_spec).await?;
@@ -53,7 +53,7 @@ async fn start_inner(chain_spec: Option<String>, log_level: String) -> Result<Cl
 
 	// Create the service. This is the most heavy initialization step.
 	let (task_manager, rpc_handlers) =
-		crate::service::new_light_base(config)
+		crate::service::new_light_base::<gama_runtime::RuntimeApi, crate::service::GamaExecutor>(config)
 			.map(|(components, rpc_handlers, _, _, _)| (components, rpc_handlers))
 			.map_err(|e| format!("{:?}", e))?;
 
@@ -0,0 +1,41 @@
+//! Builds a raw chain spec from a network description, see `chain_spec::description`.
+
+use crate::{BuildGenesisCmd, chain_spec::{self, description::{Description, Network}}};
+use sc_cli::{Error, Result};
+use sp_core::crypto::Ss58AddressFormat;
+use std::{convert::TryFrom, fs, io::Write};
+
+impl BuildGenesisCmd {
+	/// Run the build-genesis command.
+	pub fn run(&self) -> Result<()> {
+		let content = fs::read_to_string(&self.description)?;
+		let description = match self.description.extension() {
+			Some(extension) if extension == "json" => Description::from_json(&content),
+			_ => Description::from_toml(&content),
+		}.map_err(Error::Input)?;
+
+		// SS58 addresses of the description are checked against the format of its network.
+		let prefix = description.ss58_format();
+		sp_core::crypto::set_default_ss58_version(
+			Ss58AddressFormat::try_from(prefix).unwrap_or(Ss58AddressFormat::Custom(prefix))
+		);
+
+		let genesis = description.validate().map_err(|errors| Error::Input(format!(
+			"Invalid network description {}:\n  {}",
+			self.description.display(),
+			errors.join("\n  "),
+		)))?;
+
+		let json = match genesis.network {
+			Network::Gama => chain_spec::gama::description_config(genesis).as_json(true),
+			Network::Colombo => chain_spec::colombo::description_config(genesis).as_json(true),
+		}?;
+
+		match &self.output {
+			Some(path) => fs::write(path, json)?,
+			None => std::io::stdout().write_all(json.as_bytes())?,
+		}
+
+		Ok(())
+	}
+}
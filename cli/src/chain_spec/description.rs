@@ -0,0 +1,627 @@
+//! Declarative description of a network genesis, turned into a chain spec by `build-genesis`.
+//!
+//! A description is a TOML or JSON document naming the network whose runtime the chain runs,
+//! its authorities with their session keys and bond, the endowed accounts, the members of the
+//! collectives and the vesting schedules. See `res/example-genesis.toml`.
+//!
+//! Accounts and keys are SS58 addresses or `0x` prefixed hex. Amounts are in the smallest unit
+//! of the token, as numbers or, beyond 64 bits, as strings.
+
+use std::{collections::HashMap, fmt, str::FromStr};
+use grandpa_primitives::AuthorityId as GrandpaId;
+use node_primitives::BlockNumber;
+use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
+use sc_network::config::MultiaddrWithPeerId;
+use sc_service::ChainType;
+use sc_telemetry::TelemetryEndpoints;
+use serde::{Deserialize, Deserializer, de::Error as _};
+use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
+use sp_consensus_babe::AuthorityId as BabeId;
+use sp_core::crypto::{Ss58Codec, UncheckedFrom};
+use super::{AccountId, Balance};
+
+/// The network, and so the runtime, a description is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Network {
+	/// Runs `gama-runtime`.
+	Gama,
+	/// Runs `colombo-runtime`.
+	Colombo,
+}
+
+impl Network {
+	/// The prefix the id of the chain spec must start with.
+	pub fn spec_name(&self) -> &'static str {
+		match self {
+			Network::Gama => gama_runtime::network::SPEC_NAME,
+			Network::Colombo => colombo_runtime::network::SPEC_NAME,
+		}
+	}
+
+	/// The default address format of the network.
+	pub fn ss58_prefix(&self) -> u8 {
+		match self {
+			Network::Gama => gama_runtime::network::SS58_PREFIX,
+			Network::Colombo => colombo_runtime::network::SS58_PREFIX,
+		}
+	}
+
+	fn existential_deposit(&self) -> Balance {
+		match self {
+			Network::Gama => gama_runtime::ExistentialDeposit::get(),
+			Network::Colombo => colombo_runtime::ExistentialDeposit::get(),
+		}
+	}
+}
+
+/// An amount in the smallest unit of the token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Amount(pub Balance);
+
+impl<'de> Deserialize<'de> for Amount {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum Raw {
+			Number(u64),
+			String(String),
+		}
+
+		match Raw::deserialize(deserializer)? {
+			Raw::Number(amount) => Ok(Amount(amount.into())),
+			Raw::String(amount) => amount.replace('_', "").parse().map(Amount)
+				.map_err(|e| D::Error::custom(format!("invalid amount `{}`: {}", amount, e))),
+		}
+	}
+}
+
+/// Token and address properties overriding the defaults of the network.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenProperties {
+	/// Symbol of the token.
+	pub symbol: Option<String>,
+	/// Decimals of the token.
+	pub decimals: Option<u8>,
+	/// Address format.
+	pub ss58_format: Option<u8>,
+}
+
+/// A validator of the genesis, with its session keys.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthorityDescription {
+	/// Stash account, also the validator id of the session keys.
+	pub stash: String,
+	/// Controller account.
+	pub controller: String,
+	/// Amount bonded by the stash, which must be endowed with at least as much.
+	pub bond: Amount,
+	/// GRANDPA session key.
+	pub grandpa: String,
+	/// BABE session key.
+	pub babe: String,
+	/// ImOnline session key.
+	pub im_online: String,
+	/// Authority discovery session key.
+	pub authority_discovery: String,
+}
+
+/// The free balance of an account at genesis.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EndowmentDescription {
+	/// The account.
+	pub account: String,
+	/// Its free balance.
+	pub amount: Amount,
+}
+
+/// A council member elected at genesis.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CouncilMemberDescription {
+	/// The member.
+	pub account: String,
+	/// Amount locked backing the member, which must be endowed with at least as much.
+	pub stake: Amount,
+}
+
+/// A vesting schedule of an endowed account.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VestingDescription {
+	/// The account, whose endowment minus `liquid` vests.
+	pub account: String,
+	/// Block the vesting starts at.
+	pub begin: BlockNumber,
+	/// Number of blocks the vesting lasts.
+	pub length: BlockNumber,
+	/// Part of the endowment free from the start.
+	pub liquid: Amount,
+}
+
+/// A network genesis as written by hand, checked by [`Description::validate`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Description {
+	/// The network whose runtime the chain runs.
+	pub network: Network,
+	/// Name of the chain.
+	pub name: String,
+	/// Id of the chain, starting with the name of the network.
+	pub id: String,
+	/// Type of the chain, `Live` unless given.
+	pub chain_type: Option<ChainType>,
+	/// Multiaddresses, with peer id, of the boot nodes.
+	#[serde(default)]
+	pub boot_nodes: Vec<String>,
+	/// Telemetry endpoints, all with verbosity 0.
+	#[serde(default)]
+	pub telemetry_endpoints: Vec<String>,
+	/// Network protocol id.
+	pub protocol_id: Option<String>,
+	/// Overrides of the token properties of the network.
+	#[serde(default)]
+	pub properties: TokenProperties,
+	/// Initial validators.
+	pub authorities: Vec<AuthorityDescription>,
+	/// Free balances.
+	#[serde(default)]
+	pub endowments: Vec<EndowmentDescription>,
+	/// Initial council.
+	#[serde(default)]
+	pub council: Vec<CouncilMemberDescription>,
+	/// Initial technical committee.
+	#[serde(default)]
+	pub technical_committee: Vec<String>,
+	/// Initial members of the Organization collective.
+	#[serde(default)]
+	pub organization: Vec<String>,
+	/// Initial society members.
+	#[serde(default)]
+	pub society: Vec<String>,
+	/// Vesting schedules.
+	#[serde(default)]
+	pub vesting: Vec<VestingDescription>,
+	/// Native balance of the account paying the native side of fees paid in assets, 0 unless
+	/// given.
+	pub asset_fee_balance: Option<Amount>,
+	/// Sudo key, required when the runtimes are built with the sudo pallet and rejected
+	/// otherwise.
+	pub sudo: Option<String>,
+	/// Number of validators staking elects, the number of authorities unless given.
+	pub validator_count: Option<u32>,
+	/// Minimum number of validators, the number of authorities unless given.
+	pub minimum_validator_count: Option<u32>,
+}
+
+/// A validator of a checked genesis.
+#[derive(Clone)]
+pub struct Authority {
+	/// Stash account.
+	pub stash: AccountId,
+	/// Controller account.
+	pub controller: AccountId,
+	/// Bonded amount.
+	pub bond: Balance,
+	/// GRANDPA session key.
+	pub grandpa: GrandpaId,
+	/// BABE session key.
+	pub babe: BabeId,
+	/// ImOnline session key.
+	pub im_online: ImOnlineId,
+	/// Authority discovery session key.
+	pub authority_discovery: AuthorityDiscoveryId,
+}
+
+/// A checked network genesis.
+#[derive(Clone)]
+pub struct Genesis {
+	/// The network whose runtime the chain runs.
+	pub network: Network,
+	/// Name of the chain.
+	pub name: String,
+	/// Id of the chain.
+	pub id: String,
+	/// Type of the chain.
+	pub chain_type: ChainType,
+	/// Boot nodes.
+	pub boot_nodes: Vec<MultiaddrWithPeerId>,
+	/// Telemetry endpoints.
+	pub telemetry_endpoints: Option<TelemetryEndpoints>,
+	/// Network protocol id.
+	pub protocol_id: Option<String>,
+	/// Token symbol override.
+	pub token_symbol: Option<String>,
+	/// Token decimals override.
+	pub token_decimals: Option<u8>,
+	/// Address format.
+	pub ss58_format: u8,
+	/// Initial validators.
+	pub authorities: Vec<Authority>,
+	/// Free balances.
+	pub endowments: Vec<(AccountId, Balance)>,
+	/// Initial council with the stake backing each member.
+	pub council: Vec<(AccountId, Balance)>,
+	/// Initial technical committee.
+	pub technical_committee: Vec<AccountId>,
+	/// Initial members of the Organization collective.
+	pub organization: Vec<AccountId>,
+	/// Initial society members.
+	pub society: Vec<AccountId>,
+	/// Vesting schedules, as `(account, begin, length, liquid)`.
+	pub vesting: Vec<(AccountId, BlockNumber, BlockNumber, Balance)>,
+	/// Native balance of the asset fee account.
+	pub asset_fee_balance: Balance,
+	/// Sudo key, set when the runtimes are built with the sudo pallet.
+	pub sudo: Option<AccountId>,
+	/// Number of validators staking elects.
+	pub validator_count: u32,
+	/// Minimum number of validators.
+	pub minimum_validator_count: u32,
+}
+
+/// Parses accounts and keys, collecting every error instead of stopping at the first one.
+#[derive(Default)]
+struct Checker {
+	errors: Vec<String>,
+	/// Where each account or key was first seen, by kind and value.
+	seen: HashMap<(&'static str, Vec<u8>), String>,
+}
+
+impl Checker {
+	fn error(&mut self, error: impl fmt::Display) {
+		self.errors.push(error.to_string());
+	}
+
+	fn bytes(&mut self, what: &str, value: &str) -> Option<[u8; 32]> {
+		let decoded = if value.starts_with("0x") {
+			sp_core::bytes::from_hex(value).ok().filter(|bytes| bytes.len() == 32)
+		} else {
+			AccountId::from_ss58check(value).ok().map(|account| AsRef::<[u8]>::as_ref(&account).to_vec())
+		};
+
+		match decoded {
+			Some(bytes) => {
+				let mut raw = [0u8; 32];
+				raw.copy_from_slice(&bytes);
+				Some(raw)
+			},
+			None => {
+				self.error(format_args!("{}: `{}` is neither an SS58 address nor 32 bytes of hex", what, value));
+				None
+			},
+		}
+	}
+
+	fn account(&mut self, what: &str, value: &str) -> Option<AccountId> {
+		self.bytes(what, value).map(AccountId::from)
+	}
+
+	fn key<T: UncheckedFrom<[u8; 32]>>(&mut self, what: &str, value: &str) -> Option<T> {
+		self.bytes(what, value).map(T::unchecked_from)
+	}
+
+	/// Records that `what` uses `bytes` as a `kind`, failing if something else already does.
+	fn unique(&mut self, kind: &'static str, bytes: &[u8], what: &str) {
+		match self.seen.get(&(kind, bytes.to_vec())) {
+			Some(first) => {
+				let error = format!("{} uses the same {} as {}", what, kind, first);
+				self.error(error);
+			},
+			None => {
+				self.seen.insert((kind, bytes.to_vec()), what.to_string());
+			},
+		}
+	}
+
+	/// Parses `values` as a set of accounts, failing on duplicates.
+	fn members(&mut self, collective: &'static str, values: &[String]) -> Vec<AccountId> {
+		values.iter().enumerate().filter_map(|(index, value)| {
+			let what = format!("{} member #{}", collective, index);
+			let account = self.account(&what, value)?;
+			self.unique(collective, account.as_ref(), &what);
+			Some(account)
+		}).collect()
+	}
+}
+
+impl Description {
+	/// Parses a TOML description.
+	pub fn from_toml(toml: &str) -> Result<Self, String> {
+		toml::from_str(toml).map_err(|e| format!("Invalid network description: {}", e))
+	}
+
+	/// Parses a JSON description.
+	pub fn from_json(json: &str) -> Result<Self, String> {
+		serde_json::from_str(json).map_err(|e| format!("Invalid network description: {}", e))
+	}
+
+	/// The address format accounts and keys of the description are expected in.
+	pub fn ss58_format(&self) -> u8 {
+		self.properties.ss58_format.unwrap_or_else(|| self.network.ss58_prefix())
+	}
+
+	/// Parses the accounts and keys and checks the genesis would build, returning every problem
+	/// found otherwise.
+	pub fn validate(self) -> Result<Genesis, Vec<String>> {
+		let mut checker = Checker::default();
+		let existential_deposit = self.network.existential_deposit();
+
+		let sudo = match &self.sudo {
+			Some(sudo) if cfg!(feature = "sudo") => checker.account("sudo", sudo),
+			Some(sudo) => {
+				checker.error(format_args!(
+					"sudo `{}`: the runtime has no sudo pallet, root is only reachable through governance",
+					sudo,
+				));
+				None
+			},
+			None if cfg!(feature = "sudo") => {
+				checker.error("sudo: the runtime has a sudo pallet, which needs a key");
+				None
+			},
+			None => None,
+		};
+
+		let spec_name = self.network.spec_name();
+		if !self.id.starts_with(spec_name) {
+			checker.error(format_args!(
+				"id `{}` must start with `{}`, which tells the node which runtime to run the chain with",
+				self.id,
+				spec_name,
+			));
+		}
+
+		let boot_nodes = self.boot_nodes.iter().filter_map(|node| {
+			MultiaddrWithPeerId::from_str(node)
+				.map_err(|e| checker.error(format_args!("boot node `{}`: {}", node, e)))
+				.ok()
+		}).collect();
+
+		let telemetry_endpoints = if self.telemetry_endpoints.is_empty() {
+			None
+		} else {
+			TelemetryEndpoints::new(self.telemetry_endpoints.iter().map(|url| (url.clone(), 0)).collect())
+				.map_err(|e| checker.error(format_args!("telemetry endpoints: {}", e)))
+				.ok()
+		};
+
+		let mut endowed = HashMap::new();
+		let endowments = self.endowments.iter().enumerate().filter_map(|(index, endowment)| {
+			let what = format!("endowment #{}", index);
+			let account = checker.account(&what, &endowment.account)?;
+			checker.unique("endowed account", account.as_ref(), &what);
+			if endowment.amount.0 < existential_deposit {
+				checker.error(format_args!(
+					"{}: {} is below the existential deposit {}",
+					what, endowment.amount.0, existential_deposit,
+				));
+			}
+			endowed.insert(account.clone(), endowment.amount.0);
+			Some((account, endowment.amount.0))
+		}).collect::<Vec<_>>();
+		let endowment_of = |account: &AccountId| endowed.get(account).copied().unwrap_or_default();
+
+		if self.authorities.is_empty() {
+			checker.error("at least one authority is required");
+		}
+
+		let authorities = self.authorities.iter().enumerate().filter_map(|(index, authority)| {
+			let what = format!("authority #{}", index);
+			let stash = checker.account(&format!("{} stash", what), &authority.stash);
+			let controller = checker.account(&format!("{} controller", what), &authority.controller);
+			let grandpa = checker.key::<GrandpaId>(&format!("{} grandpa key", what), &authority.grandpa);
+			let babe = checker.key::<BabeId>(&format!("{} babe key", what), &authority.babe);
+			let im_online = checker.key::<ImOnlineId>(&format!("{} im_online key", what), &authority.im_online);
+			let authority_discovery = checker.key::<AuthorityDiscoveryId>(
+				&format!("{} authority_discovery key", what),
+				&authority.authority_discovery,
+			);
+			let (stash, controller, grandpa, babe, im_online, authority_discovery) =
+				(stash?, controller?, grandpa?, babe?, im_online?, authority_discovery?);
+
+			// A stash can neither be the stash nor the controller of another validator.
+			checker.unique("stash or controller", stash.as_ref(), &format!("{} stash", what));
+			if controller != stash {
+				checker.unique("stash or controller", controller.as_ref(), &format!("{} controller", what));
+			}
+			checker.unique("grandpa key", grandpa.as_ref(), &what);
+			checker.unique("babe key", babe.as_ref(), &what);
+			checker.unique("im_online key", im_online.as_ref(), &what);
+			checker.unique("authority_discovery key", authority_discovery.as_ref(), &what);
+
+			let bond = authority.bond.0;
+			if bond < existential_deposit {
+				checker.error(format_args!(
+					"{}: bond {} is below the existential deposit {}",
+					what, bond, existential_deposit,
+				));
+			}
+			if endowment_of(&stash) < bond {
+				checker.error(format_args!(
+					"{}: insufficient stash, {} is endowed with {} but bonds {}",
+					what, authority.stash, endowment_of(&stash), bond,
+				));
+			}
+
+			Some(Authority { stash, controller, bond, grandpa, babe, im_online, authority_discovery })
+		}).collect::<Vec<_>>();
+
+		let council = self.council.iter().enumerate().filter_map(|(index, member)| {
+			let what = format!("council member #{}", index);
+			let account = checker.account(&what, &member.account)?;
+			checker.unique("council", account.as_ref(), &what);
+			if member.stake.0 == 0 || endowment_of(&account) < member.stake.0 {
+				checker.error(format_args!(
+					"{}: insufficient balance, {} is endowed with {} but stakes {}",
+					what, member.account, endowment_of(&account), member.stake.0,
+				));
+			}
+			Some((account, member.stake.0))
+		}).collect();
+
+		let technical_committee = checker.members("technical committee", &self.technical_committee);
+		let organization = checker.members("organization", &self.organization);
+		let society = checker.members("society", &self.society);
+
+		let vesting = self.vesting.iter().enumerate().filter_map(|(index, schedule)| {
+			let what = format!("vesting schedule #{}", index);
+			let account = checker.account(&what, &schedule.account)?;
+			checker.unique("vesting schedule", account.as_ref(), &what);
+			if schedule.length == 0 {
+				checker.error(format_args!("{}: the length must not be 0", what));
+			}
+			if endowment_of(&account) <= schedule.liquid.0 {
+				checker.error(format_args!(
+					"{}: nothing vests, {} is endowed with {} and {} is liquid",
+					what, schedule.account, endowment_of(&account), schedule.liquid.0,
+				));
+			}
+			Some((account, schedule.begin, schedule.length, schedule.liquid.0))
+		}).collect();
+
+		let asset_fee_balance = self.asset_fee_balance.map_or(0, |amount| amount.0);
+		if asset_fee_balance != 0 && asset_fee_balance < existential_deposit {
+			checker.error(format_args!(
+				"asset_fee_balance {} is below the existential deposit {}",
+				asset_fee_balance, existential_deposit,
+			));
+		}
+
+		let authority_count = self.authorities.len() as u32;
+		let validator_count = self.validator_count.unwrap_or(authority_count);
+		let minimum_validator_count = self.minimum_validator_count.unwrap_or(authority_count);
+		if minimum_validator_count > validator_count {
+			checker.error(format_args!(
+				"minimum_validator_count {} is above validator_count {}",
+				minimum_validator_count, validator_count,
+			));
+		}
+
+		if !checker.errors.is_empty() {
+			return Err(checker.errors);
+		}
+
+		Ok(Genesis {
+			network: self.network,
+			ss58_format: self.ss58_format(),
+			name: self.name,
+			id: self.id,
+			chain_type: self.chain_type.unwrap_or(ChainType::Live),
+			boot_nodes,
+			telemetry_endpoints,
+			protocol_id: self.protocol_id,
+			token_symbol: self.properties.symbol,
+			token_decimals: self.properties.decimals,
+			authorities,
+			endowments,
+			council,
+			technical_committee,
+			organization,
+			society,
+			vesting,
+			asset_fee_balance,
+			sudo,
+			validator_count,
+			minimum_validator_count,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use gama_runtime::constants::currency::DOLLARS;
+	use sp_core::crypto::Ss58AddressFormat;
+	use std::convert::TryFrom;
+	use crate::chain_spec::authority_keys_from_seed;
+
+	fn parse(toml: &str) -> Result<Genesis, Vec<String>> {
+		sp_core::crypto::set_default_ss58_version(Ss58AddressFormat::SubstrateAccount);
+		Description::from_toml(toml).unwrap().validate()
+	}
+
+	/// A description of `seeds` as authorities, each stash endowed with `endowment` dollars and
+	/// bonding 100.
+	fn description(seeds: &[&str], endowment: u128) -> String {
+		let mut toml = String::from("network = \"gama\"\nname = \"Test\"\nid = \"gama_test\"\n");
+		for seed in seeds {
+			let (stash, controller, grandpa, babe, im_online, authority_discovery) =
+				authority_keys_from_seed(seed);
+			toml += &format!(
+				"[[authorities]]\nstash = \"{}\"\ncontroller = \"{}\"\nbond = \"{}\"\n\
+				grandpa = \"{}\"\nbabe = \"{}\"\nim_online = \"{}\"\nauthority_discovery = \"{}\"\n",
+				stash.to_ss58check(), controller.to_ss58check(), 100 * DOLLARS,
+				grandpa.to_ss58check(), babe.to_ss58check(), im_online.to_ss58check(),
+				authority_discovery.to_ss58check(),
+			);
+			toml += &format!(
+				"[[endowments]]\naccount = \"{}\"\namount = \"{}\"\n",
+				stash.to_ss58check(), endowment * DOLLARS,
+			);
+		}
+		toml
+	}
+
+	#[test]
+	fn example_description_is_valid() {
+		let description = Description::from_toml(include_str!("../../res/example-genesis.toml")).unwrap();
+		let prefix = description.ss58_format();
+		sp_core::crypto::set_default_ss58_version(
+			Ss58AddressFormat::try_from(prefix).unwrap_or(Ss58AddressFormat::Custom(prefix))
+		);
+		let genesis = description.validate().unwrap();
+
+		assert_eq!(genesis.network, Network::Colombo);
+		assert_eq!(genesis.authorities.len(), 1);
+		assert_eq!(genesis.authorities[0].stash, authority_keys_from_seed("Alice").0);
+		assert_eq!(genesis.council.len(), 1);
+		assert_eq!(genesis.vesting.len(), 1);
+	}
+
+	#[test]
+	fn valid_description_builds_a_raw_spec() {
+		let genesis = parse(&description(&["Alice", "Bob"], 1_000)).unwrap();
+		let spec = crate::chain_spec::gama::description_config(genesis);
+
+		let raw: serde_json::Value = serde_json::from_str(&spec.as_json(true).unwrap()).unwrap();
+		assert_eq!(raw["id"], "gama_test");
+		assert!(raw["genesis"]["raw"]["top"].as_object().map_or(false, |top| !top.is_empty()));
+	}
+
+	#[test]
+	fn duplicate_keys_are_rejected() {
+		let errors = parse(&description(&["Alice", "Alice"], 1_000)).err().unwrap();
+
+		for kind in &["endowed account", "stash or controller", "grandpa key", "babe key", "im_online key"] {
+			assert!(
+				errors.iter().any(|error| error.contains(&format!("the same {} as", kind))),
+				"no duplicate {} in {:?}", kind, errors,
+			);
+		}
+	}
+
+	#[test]
+	fn insufficient_stash_is_rejected() {
+		let errors = parse(&description(&["Alice"], 10)).err().unwrap();
+
+		assert_eq!(errors.len(), 1, "{:?}", errors);
+		assert!(errors[0].contains("insufficient stash"), "{:?}", errors);
+	}
+
+	#[test]
+	#[cfg(not(feature = "sudo"))]
+	fn sudo_and_foreign_ids_are_rejected() {
+		let mut toml = description(&["Alice"], 1_000)
+			.replace("id = \"gama_test\"", "id = \"colombo_test\"");
+		toml.insert_str(0, &format!("sudo = \"{}\"\n", authority_keys_from_seed("Alice").1.to_ss58check()));
+
+		let errors = parse(&toml).err().unwrap();
+		assert_eq!(errors.len(), 2, "{:?}", errors);
+		assert!(errors[0].contains("no sudo pallet"), "{:?}", errors);
+		assert!(errors[1].contains("must start with `gama`"), "{:?}", errors);
+	}
+}
@@ -0,0 +1,207 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate chain configurations.
+//!
+//! Every network has its own module with the same set of chain specs, built from the genesis
+//! code in `network.rs` against the runtime of that network. The id of a chain spec starts with
+//! the network name, which is how the node picks the runtime to run it with.
+
+use sc_chain_spec::ChainSpecExtension;
+use sp_core::{Pair, Public, sr25519};
+use serde::{Serialize, Deserialize};
+use node_primitives::Block;
+use grandpa_primitives::{AuthorityId as GrandpaId};
+use sp_consensus_babe::{AuthorityId as BabeId};
+use pallet_im_online::sr25519::{AuthorityId as ImOnlineId};
+use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
+use sp_runtime::traits::{Verify, IdentifyAccount};
+
+pub use node_primitives::{AccountId, Balance, Signature};
+
+type AccountPublic = <Signature as Verify>::Signer;
+
+pub mod description;
+
+/// Node `ChainSpec` extensions.
+///
+/// Additional parameters for some Substrate core modules,
+/// customizable from the chain spec.
+#[derive(Default, Clone, Serialize, Deserialize, ChainSpecExtension)]
+#[serde(rename_all = "camelCase")]
+pub struct Extensions {
+	/// Block numbers with known hashes.
+	pub fork_blocks: sc_client_api::ForkBlocks<Block>,
+	/// Known bad block hashes.
+	pub bad_blocks: sc_client_api::BadBlocks<Block>,
+	/// GRANDPA parameters of the network.
+	#[serde(default)]
+	pub grandpa: GrandpaParameters,
+}
+
+/// GRANDPA parameters of a network, each falling back to the node default when not given.
+///
+/// Command line flags take precedence over these.
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GrandpaParameters {
+	/// Milliseconds between two gossip rounds.
+	pub gossip_duration: Option<u64>,
+	/// Number of blocks between two justifications stored by every node.
+	pub justification_period: Option<u32>,
+	/// Number of blocks voters stay behind the best block.
+	pub vote_back_off: Option<u32>,
+}
+
+/// Chain specs of the gama network.
+pub mod gama {
+	use gama_runtime as node_runtime;
+
+	const NETWORK_NAME: &str = "Gama";
+	const PROTOCOL_ID: &str = "com";
+	const STAGING_DESCRIPTION: &str = include_str!("../../res/gama-staging.toml");
+
+	include!("network.rs");
+
+	/// The chain spec of the gama network, which is not part of the sources.
+	pub fn config() -> Result<ChainSpec, String> {
+		Err(super::live_spec_missing("gama"))
+	}
+}
+
+/// Chain specs of the colombo network.
+pub mod colombo {
+	use colombo_runtime as node_runtime;
+
+	const NETWORK_NAME: &str = "Colombo";
+	const PROTOCOL_ID: &str = "tcom";
+	const STAGING_DESCRIPTION: &str = include_str!("../../res/colombo-staging.toml");
+
+	include!("network.rs");
+
+	/// The chain spec of the colombo network, which is not part of the sources.
+	pub fn config() -> Result<ChainSpec, String> {
+		Err(super::live_spec_missing("colombo"))
+	}
+}
+
+/// The error for a live network whose spec, generated with `build-genesis`, must be passed to
+/// `--chain` by path.
+fn live_spec_missing(network: &str) -> String {
+	format!(
+		"The {} chain spec is not built into the node, generate it with `aochain build-genesis` and pass its path to `--chain`",
+		network,
+	)
+}
+
+/// The network a chain spec belongs to.
+pub trait IdentifyVariant {
+	/// Returns `true` if this is a chain spec of the colombo network.
+	fn is_colombo(&self) -> bool;
+}
+
+impl IdentifyVariant for Box<dyn sc_service::ChainSpec> {
+	fn is_colombo(&self) -> bool {
+		self.id().starts_with("colombo")
+	}
+}
+
+/// Helper function to generate a crypto pair from seed
+pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
+	TPublic::Pair::from_string(&format!("//{}", seed), None)
+		.expect("static values are valid; qed")
+		.public()
+}
+
+/// Helper function to generate an account ID from seed
+pub fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId where
+	AccountPublic: From<<TPublic::Pair as Pair>::Public>
+{
+	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+}
+
+/// Helper function to generate stash, controller and session key from seed
+pub fn authority_keys_from_seed(seed: &str) -> (
+	AccountId,
+	AccountId,
+	GrandpaId,
+	BabeId,
+	ImOnlineId,
+	AuthorityDiscoveryId,
+) {
+	(
+		get_account_id_from_seed::<sr25519::Public>(&format!("{}//stash", seed)),
+		get_account_id_from_seed::<sr25519::Public>(seed),
+		get_from_seed::<GrandpaId>(seed),
+		get_from_seed::<BabeId>(seed),
+		get_from_seed::<ImOnlineId>(seed),
+		get_from_seed::<AuthorityDiscoveryId>(seed),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_chain_spec::ChainSpec;
+
+	fn grandpa_parameters(edit: impl FnOnce(&mut serde_json::Value)) -> GrandpaParameters {
+		let mut json: serde_json::Value =
+			serde_json::from_str(&gama::development_config().as_json(false).unwrap()).unwrap();
+		edit(&mut json);
+
+		let spec = gama::ChainSpec::from_json_bytes(json.to_string().into_bytes()).unwrap();
+		sc_chain_spec::get_extension::<GrandpaParameters>(spec.extensions()).cloned().unwrap()
+	}
+
+	#[test]
+	fn staging_specs_are_built_from_their_description() {
+		let gama: serde_json::Value =
+			serde_json::from_str(&gama::staging_testnet_config().as_json(false).unwrap()).unwrap();
+		let colombo: serde_json::Value =
+			serde_json::from_str(&colombo::staging_testnet_config().as_json(false).unwrap()).unwrap();
+
+		assert_eq!(gama["id"], "gama");
+		assert_eq!(colombo["id"], "colombo");
+		assert_eq!(gama["protocolId"], "com");
+		assert_eq!(colombo["protocolId"], "tcom");
+		let stakers = &gama["genesis"]["runtime"]["palletStaking"]["stakers"];
+		assert_eq!(stakers.as_array().map(Vec::len), Some(4));
+	}
+
+	#[test]
+	fn specs_without_grandpa_parameters_use_the_defaults() {
+		let parameters = grandpa_parameters(|json| {
+			json.as_object_mut().unwrap().remove("grandpa");
+		});
+
+		assert_eq!(parameters, GrandpaParameters::default());
+	}
+
+	#[test]
+	fn grandpa_parameters_are_read_from_the_spec() {
+		let parameters = grandpa_parameters(|json| {
+			json["grandpa"] = serde_json::json!({ "gossipDuration": 500, "voteBackOff": 4 });
+		});
+
+		assert_eq!(parameters, GrandpaParameters {
+			gossip_duration: Some(500),
+			justification_period: None,
+			vote_back_off: Some(4),
+		});
+	}
+}
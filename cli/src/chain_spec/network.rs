@@ -0,0 +1,390 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// Genesis and chain specs of a network, included by the module of every network in `mod.rs`
+// after it brings its runtime in scope as `node_runtime`.
+
+use sp_core::sr25519;
+use serde_json::json;
+use node_runtime::{
+	AuthorityDiscoveryConfig, BabeConfig, BalancesConfig, ContractsConfig, CouncilConfig,
+	DemocracyConfig,GrandpaConfig, ImOnlineConfig, SessionConfig, SessionKeys, StakerStatus,
+	StakingConfig, ElectionsConfig, IndicesConfig, SocietyConfig, SystemConfig,
+	TechnicalCommitteeConfig, wasm_binary_unwrap, OrganizationConfig, FungibleAssetsConfig,
+	VestingConfig, AssetTxPaymentConfig, MigrationsConfig,
+};
+#[cfg(feature = "sudo")]
+use node_runtime::SudoConfig;
+use node_runtime::constants::currency::*;
+use node_runtime::network::{SPEC_NAME, SS58_PREFIX, TOKEN_DECIMALS, TOKEN_SYMBOL};
+use sc_service::ChainType;
+use grandpa_primitives::{AuthorityId as GrandpaId};
+use sp_consensus_babe::{AuthorityId as BabeId};
+use pallet_im_online::sr25519::{AuthorityId as ImOnlineId};
+use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
+use sp_runtime::Perbill;
+use super::{
+	AccountId, Balance, Extensions, authority_keys_from_seed, get_account_id_from_seed,
+	description::{Description, Genesis},
+};
+
+pub use node_runtime::GenesisConfig;
+
+/// Specialized `ChainSpec`.
+pub type ChainSpec = sc_service::GenericChainSpec<
+	GenesisConfig,
+	Extensions,
+>;
+
+fn session_keys(
+	grandpa: GrandpaId,
+	babe: BabeId,
+	im_online: ImOnlineId,
+	authority_discovery: AuthorityDiscoveryId,
+) -> SessionKeys {
+	SessionKeys { grandpa, babe, im_online, authority_discovery }
+}
+
+/// Staging testnet config, built from the description in `res`.
+pub fn staging_testnet_config() -> ChainSpec {
+	let mut description = Description::from_toml(STAGING_DESCRIPTION)
+		.expect("the staging description is valid TOML; qed");
+	// Runtimes built with sudo give its key to the endowed account, which holds the rest of the
+	// genesis roles.
+	if cfg!(feature = "sudo") {
+		description.sudo = description.endowments.first().map(|endowment| endowment.account.clone());
+	}
+	let genesis = description.validate()
+		.expect("the staging description is a valid genesis; qed");
+	description_config(genesis)
+}
+
+/// Token and address properties of the network the runtime is built for.
+fn network_properties() -> sc_chain_spec::Properties {
+	json!({"tokenDecimals": TOKEN_DECIMALS, "tokenSymbol": TOKEN_SYMBOL, "ss58Format": SS58_PREFIX})
+		.as_object()
+		.expect("network properties generation can not fail; qed")
+		.to_owned()
+}
+
+/// Helper function to create GenesisConfig for testing
+pub fn testnet_genesis(
+	initial_authorities: Vec<(
+		AccountId,
+		AccountId,
+		GrandpaId,
+		BabeId,
+		ImOnlineId,
+		AuthorityDiscoveryId,
+	)>,
+	endowed_accounts: Option<Vec<AccountId>>,
+	enable_println: bool,
+) -> GenesisConfig {
+	let endowed_accounts: Vec<AccountId> = endowed_accounts.unwrap_or_else(|| {
+		vec![
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+			get_account_id_from_seed::<sr25519::Public>("Bob"),
+			get_account_id_from_seed::<sr25519::Public>("Charlie"),
+			get_account_id_from_seed::<sr25519::Public>("Dave"),
+			get_account_id_from_seed::<sr25519::Public>("Eve"),
+			get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+			get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
+			get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
+			get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
+			get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
+			get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
+			get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
+		]
+	});
+	let num_endowed_accounts = endowed_accounts.len();
+
+	const ENDOWMENT: Balance = 1_000_000_000 * DOLLARS;
+	const STASH: Balance = 100 * DOLLARS;
+
+	GenesisConfig {
+		frame_system: Some(SystemConfig {
+			code: wasm_binary_unwrap().to_vec(),
+			changes_trie_config: Default::default(),
+		}),
+		pallet_balances: Some(BalancesConfig {
+			balances: endowed_accounts.iter().cloned()
+				.map(|k| (k, ENDOWMENT))
+				.chain(initial_authorities.iter().map(|x| (x.0.clone(), STASH)))
+				.collect(),
+		}),
+		pallet_indices: Some(IndicesConfig {
+			indices: vec![],
+		}),
+		pallet_session: Some(SessionConfig {
+			keys: initial_authorities.iter().map(|x| {
+				(x.0.clone(), x.0.clone(), session_keys(
+					x.2.clone(),
+					x.3.clone(),
+					x.4.clone(),
+					x.5.clone(),
+				))
+			}).collect::<Vec<_>>(),
+		}),
+		pallet_staking: Some(StakingConfig {
+			validator_count: initial_authorities.len() as u32 * 10 + 10,
+			minimum_validator_count: initial_authorities.len() as u32,
+			stakers: initial_authorities.iter().map(|x| {
+				(x.0.clone(), x.1.clone(), STASH, StakerStatus::Validator)
+			}).collect(),
+			invulnerables: initial_authorities.iter().map(|x| x.0.clone()).collect(),
+			slash_reward_fraction: Perbill::from_percent(10),
+			.. Default::default()
+		}),
+		pallet_democracy: Some(DemocracyConfig::default()),
+		pallet_elections_phragmen: Some(ElectionsConfig {
+			members: endowed_accounts.iter()
+						.take((num_endowed_accounts + 1) / 2)
+						.cloned()
+						.map(|member| (member, STASH))
+						.collect(),
+		}),
+		pallet_collective_Instance1: Some(CouncilConfig::default()),
+		pallet_collective_Instance2: Some(TechnicalCommitteeConfig {
+			members: endowed_accounts.iter()
+						.take((num_endowed_accounts + 1) / 2)
+						.cloned()
+						.collect(),
+			phantom: Default::default(),
+		}),
+		pallet_collective_Instance3: Some(OrganizationConfig {
+			members: endowed_accounts.iter()
+						.take((num_endowed_accounts + 1) / 2)
+						.cloned()
+						.collect(),
+			phantom: Default::default(),
+		}),
+		pallet_contracts: Some(ContractsConfig {
+			current_schedule: pallet_contracts::Schedule {
+				enable_println, // this should only be enabled on development chains
+				..Default::default()
+			},
+		}),
+		pallet_babe: Some(BabeConfig {
+			authorities: vec![],
+		}),
+		pallet_im_online: Some(ImOnlineConfig {
+			keys: vec![],
+		}),
+		pallet_authority_discovery: Some(AuthorityDiscoveryConfig {
+			keys: vec![],
+		}),
+		pallet_grandpa: Some(GrandpaConfig {
+			authorities: vec![],
+		}),
+		pallet_membership_Instance1: Some(Default::default()),
+		pallet_treasury: Some(Default::default()),
+		pallet_society: Some(SocietyConfig {
+			members: endowed_accounts.iter()
+						.take((num_endowed_accounts + 1) / 2)
+						.cloned()
+						.collect(),
+			pot: 0,
+			max_members: 999,
+		}),
+		pallet_vesting: Some(Default::default()),
+		pallet_fungible_assets: Some(FungibleAssetsConfig {
+			assets: vec![],
+			metadata: vec![],
+			accounts: vec![],
+		}),
+		migrations: Some(MigrationsConfig {}),
+		pallet_asset_tx_payment: Some(AssetTxPaymentConfig {
+			fee_account_balance: 1_000 * DOLLARS,
+		}),
+		#[cfg(feature = "sudo")]
+		pallet_sudo: Some(SudoConfig {
+			key: endowed_accounts[0].clone(),
+		}),
+	}
+}
+
+fn development_config_genesis() -> GenesisConfig {
+	testnet_genesis(
+		vec![
+			authority_keys_from_seed("Alice"),
+		],
+		None,
+		true,
+	)
+}
+
+/// Development config (single validator Alice)
+pub fn development_config() -> ChainSpec {
+	ChainSpec::from_genesis(
+		&format!("{} Development", NETWORK_NAME),
+		&format!("{}_dev", SPEC_NAME),
+		ChainType::Development,
+		development_config_genesis,
+		vec![],
+		None,
+		None,
+		Some(network_properties()),
+		Default::default(),
+	)
+}
+
+fn local_testnet_genesis() -> GenesisConfig {
+	testnet_genesis(
+		vec![
+			authority_keys_from_seed("Alice"),
+			authority_keys_from_seed("Bob"),
+		],
+		None,
+		false,
+	)
+}
+
+/// Local testnet config (multivalidator Alice + Bob)
+pub fn local_testnet_config() -> ChainSpec {
+	ChainSpec::from_genesis(
+		&format!("{} Local Testnet", NETWORK_NAME),
+		&format!("{}_local_testnet", SPEC_NAME),
+		ChainType::Local,
+		local_testnet_genesis,
+		vec![],
+		None,
+		None,
+		Some(network_properties()),
+		Default::default(),
+	)
+}
+
+fn description_genesis(genesis: &Genesis) -> GenesisConfig {
+	GenesisConfig {
+		frame_system: Some(SystemConfig {
+			code: wasm_binary_unwrap().to_vec(),
+			changes_trie_config: Default::default(),
+		}),
+		pallet_balances: Some(BalancesConfig {
+			balances: genesis.endowments.clone(),
+		}),
+		pallet_indices: Some(IndicesConfig {
+			indices: vec![],
+		}),
+		pallet_session: Some(SessionConfig {
+			keys: genesis.authorities.iter().map(|x| {
+				(x.stash.clone(), x.stash.clone(), session_keys(
+					x.grandpa.clone(),
+					x.babe.clone(),
+					x.im_online.clone(),
+					x.authority_discovery.clone(),
+				))
+			}).collect::<Vec<_>>(),
+		}),
+		pallet_staking: Some(StakingConfig {
+			validator_count: genesis.validator_count,
+			minimum_validator_count: genesis.minimum_validator_count,
+			stakers: genesis.authorities.iter().map(|x| {
+				(x.stash.clone(), x.controller.clone(), x.bond, StakerStatus::Validator)
+			}).collect(),
+			invulnerables: genesis.authorities.iter().map(|x| x.stash.clone()).collect(),
+			slash_reward_fraction: Perbill::from_percent(10),
+			.. Default::default()
+		}),
+		pallet_democracy: Some(DemocracyConfig::default()),
+		pallet_elections_phragmen: Some(ElectionsConfig {
+			members: genesis.council.clone(),
+		}),
+		pallet_collective_Instance1: Some(CouncilConfig::default()),
+		pallet_collective_Instance2: Some(TechnicalCommitteeConfig {
+			members: genesis.technical_committee.clone(),
+			phantom: Default::default(),
+		}),
+		pallet_collective_Instance3: Some(OrganizationConfig {
+			members: genesis.organization.clone(),
+			phantom: Default::default(),
+		}),
+		pallet_contracts: Some(ContractsConfig {
+			current_schedule: pallet_contracts::Schedule {
+				enable_println: genesis.chain_type == ChainType::Development,
+				..Default::default()
+			},
+		}),
+		pallet_babe: Some(BabeConfig {
+			authorities: vec![],
+		}),
+		pallet_im_online: Some(ImOnlineConfig {
+			keys: vec![],
+		}),
+		pallet_authority_discovery: Some(AuthorityDiscoveryConfig {
+			keys: vec![],
+		}),
+		pallet_grandpa: Some(GrandpaConfig {
+			authorities: vec![],
+		}),
+		pallet_membership_Instance1: Some(Default::default()),
+		pallet_treasury: Some(Default::default()),
+		pallet_society: Some(SocietyConfig {
+			members: genesis.society.clone(),
+			pot: 0,
+			max_members: 999,
+		}),
+		pallet_vesting: Some(VestingConfig {
+			vesting: genesis.vesting.clone(),
+		}),
+		pallet_fungible_assets: Some(FungibleAssetsConfig {
+			assets: vec![],
+			metadata: vec![],
+			accounts: vec![],
+		}),
+		migrations: Some(MigrationsConfig {}),
+		pallet_asset_tx_payment: Some(AssetTxPaymentConfig {
+			fee_account_balance: genesis.asset_fee_balance,
+		}),
+		#[cfg(feature = "sudo")]
+		pallet_sudo: Some(SudoConfig {
+			key: genesis.sudo.clone().expect("descriptions without a sudo key are rejected; qed"),
+		}),
+	}
+}
+
+/// Config of a network genesis checked by `build-genesis`.
+pub fn description_config(genesis: Genesis) -> ChainSpec {
+	let mut properties = network_properties();
+	if let Some(symbol) = &genesis.token_symbol {
+		properties.insert("tokenSymbol".into(), json!(symbol));
+	}
+	if let Some(decimals) = genesis.token_decimals {
+		properties.insert("tokenDecimals".into(), json!(decimals));
+	}
+	properties.insert("ss58Format".into(), json!(genesis.ss58_format));
+
+	let name = genesis.name.clone();
+	let id = genesis.id.clone();
+	let chain_type = genesis.chain_type.clone();
+	let boot_nodes = genesis.boot_nodes.clone();
+	let telemetry_endpoints = genesis.telemetry_endpoints.clone();
+	let protocol_id = genesis.protocol_id.clone();
+
+	ChainSpec::from_genesis(
+		&name,
+		&id,
+		chain_type,
+		move || description_genesis(&genesis),
+		boot_nodes,
+		telemetry_endpoints,
+		Some(protocol_id.as_deref().unwrap_or(PROTOCOL_ID)),
+		Some(properties),
+		Default::default(),
+	)
+}
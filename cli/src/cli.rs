@@ -0,0 +1,383 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use sc_cli::{RunCmd, KeySubcommand, KeystoreParams, SharedParams, SignCmd, VanityCmd, VerifyCmd};
+use std::{path::PathBuf, str::FromStr};
+use structopt::StructOpt;
+
+/// An overarching CLI command definition.
+#[derive(Debug, StructOpt)]
+pub struct Cli {
+	/// Possible subcommand with parameters.
+	#[structopt(subcommand)]
+	pub subcommand: Option<Subcommand>,
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub run: RunCmd,
+
+	/// Seal blocks with manual seal instead of BABE and GRANDPA: `instant`, `manual` or
+	/// `interval=<ms>`.
+	///
+	/// Blocks can always be sealed and finalized with the `engine_createBlock` and
+	/// `engine_finalizeBlock` RPCs. Only accepted with development and local chains.
+	#[structopt(long)]
+	pub sealing: Option<Sealing>,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub grandpa: GrandpaParams,
+
+	/// Index extrinsics and events by account, pallet and asset, served by the `indexer_*` RPCs.
+	///
+	/// The index is stored next to the chain database. Blocks imported before are indexed in
+	/// the background, which needs their state: run an archive node to index the whole chain.
+	#[structopt(long)]
+	pub indexer: bool,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub health: HealthParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub signing: SigningParams,
+}
+
+/// Settings of the HTTP endpoint answering `/health` and `/ready`.
+#[derive(Debug, Clone, StructOpt)]
+pub struct HealthParams {
+	/// Serve `/health` and `/ready` over HTTP on this port, for load balancers.
+	///
+	/// `/ready` answers 503 while the node syncs, lacks peers, lags in finality or stops
+	/// importing blocks. Answers are JSON, or text when the request accepts `text/plain`.
+	#[structopt(long = "health-port", value_name = "PORT")]
+	pub port: Option<u16>,
+
+	/// Listen to the health endpoint on all interfaces instead of only the local one.
+	#[structopt(long = "health-external")]
+	pub external: bool,
+
+	/// Fewest connected peers of a ready node.
+	#[structopt(long = "health-min-peers", value_name = "PEERS", default_value = "1")]
+	pub min_peers: usize,
+
+	/// Most blocks between the best and the finalized block of a ready node.
+	#[structopt(long = "health-max-finality-lag", value_name = "BLOCKS", default_value = "20")]
+	pub max_finality_lag: u32,
+
+	/// Most seconds a ready node goes without importing a block.
+	#[structopt(long = "health-max-import-age", value_name = "SECS", default_value = "60")]
+	pub max_import_age: u64,
+}
+
+impl Default for HealthParams {
+	fn default() -> Self {
+		HealthParams {
+			port: None,
+			external: false,
+			min_peers: 1,
+			max_finality_lag: 20,
+			max_import_age: 60,
+		}
+	}
+}
+
+/// Where the keys the node signs with are kept.
+#[derive(Debug, Clone, Default, StructOpt)]
+pub struct SigningParams {
+	/// Read the keystore as encrypted with the keystore password, see `keys encrypt`.
+	///
+	/// The keys are decrypted when the node starts and kept in memory, and the keys of
+	/// `author_insertKey` and `author_rotateKeys` are written encrypted as well.
+	#[structopt(long = "keystore-encrypted")]
+	pub keystore_encrypted: bool,
+
+	/// Sign BABE blocks, GRANDPA votes and ImOnline heartbeats through the signer listening on
+	/// this Unix socket, such as `aochain-signer`.
+	///
+	/// AuthorityDiscovery keys stay in the keystore of the node.
+	#[structopt(long = "remote-signer", value_name = "SOCKET", parse(from_os_str))]
+	pub remote_signer: Option<PathBuf>,
+}
+
+/// GRANDPA settings, taking precedence over the `grandpa` extension of the chain spec.
+#[derive(Debug, Clone, Default, StructOpt)]
+pub struct GrandpaParams {
+	/// Milliseconds between two GRANDPA gossip rounds [default: 333].
+	#[structopt(long = "grandpa-gossip-duration", value_name = "MILLIS")]
+	pub gossip_duration: Option<u64>,
+
+	/// Number of blocks between two GRANDPA justifications stored by every node [default: 512].
+	#[structopt(long = "grandpa-justification-period", value_name = "BLOCKS")]
+	pub justification_period: Option<u32>,
+
+	/// Number of blocks the GRANDPA voter stays behind the best block [default: 2].
+	#[structopt(long = "grandpa-vote-back-off", value_name = "BLOCKS")]
+	pub vote_back_off: Option<u32>,
+
+	/// Run the GRANDPA observer instead of the full voter on a node that is not an authority.
+	///
+	/// The observer follows finality from the votes of others without gossiping its own view
+	/// of the rounds. Authorities always run the full voter.
+	#[structopt(long = "grandpa-observer")]
+	pub observer: bool,
+}
+
+/// How blocks are sealed without BABE and GRANDPA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sealing {
+	/// A block is sealed and finalized as soon as a transaction enters the pool.
+	Instant,
+	/// Blocks are only sealed and finalized through RPC.
+	Manual,
+	/// A block, empty if need be, is sealed and finalized every given number of milliseconds.
+	Interval(u64),
+}
+
+impl FromStr for Sealing {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"instant" => Ok(Sealing::Instant),
+			"manual" => Ok(Sealing::Manual),
+			_ => match s.strip_prefix("interval=") {
+				Some(millis) => match millis.parse() {
+					Ok(millis) if millis > 0 => Ok(Sealing::Interval(millis)),
+					_ => Err(format!("Invalid sealing interval `{}`", millis)),
+				},
+				None => Err(format!("Unknown sealing `{}`, expected `instant`, `manual` or `interval=<ms>`", s)),
+			},
+		}
+	}
+}
+
+/// Possible subcommands of the main binary.
+#[derive(Debug, StructOpt)]
+pub enum Subcommand {
+	/// Key management cli utilities
+	Key(KeySubcommand),
+
+	/// Session key utilities of validators.
+	Keys(KeysSubcommand),
+
+	/// The custom inspect subcommmand for decoding blocks and extrinsics.
+	#[structopt(
+		name = "inspect",
+		about = "Decode given block or extrinsic using current native runtime."
+	)]
+	Inspect(node_inspect::cli::InspectCmd),
+
+	/// The custom benchmark subcommmand benchmarking runtime pallets.
+	#[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
+	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Verify a signature for a message, provided on STDIN, with a given (public or secret) key.
+	Verify(VerifyCmd),
+
+	/// Generate a seed that provides a vanity address.
+	Vanity(VanityCmd),
+
+	/// Sign a message, with a given (secret) key.
+	Sign(SignCmd),
+
+	/// Build a chain specification.
+	BuildSpec(sc_cli::BuildSpecCmd),
+
+	/// Build a raw chain specification from a network description.
+	#[structopt(
+		name = "build-genesis",
+		about = "Build a raw chain specification from a TOML or JSON network description."
+	)]
+	BuildGenesis(BuildGenesisCmd),
+
+	/// Create a local test chain from the state of a live one.
+	#[structopt(
+		name = "fork-off",
+		about = "Turn a state written by export-state into a chain spec run by development authorities."
+	)]
+	ForkOff(ForkOffCmd),
+
+	/// Build a chain specification with a light client sync state.
+	BuildSyncSpec(sc_cli::BuildSyncSpecCmd),
+
+	/// Validate blocks.
+	CheckBlock(sc_cli::CheckBlockCmd),
+
+	/// Export blocks.
+	ExportBlocks(sc_cli::ExportBlocksCmd),
+
+	/// Export the state of a given block into a chain spec.
+	ExportState(sc_cli::ExportStateCmd),
+
+	/// Import blocks.
+	ImportBlocks(sc_cli::ImportBlocksCmd),
+
+	/// Remove the whole chain.
+	PurgeChain(sc_cli::PurgeChainCmd),
+
+	/// Revert the chain to a previous state.
+	Revert(sc_cli::RevertCmd),
+
+	/// Dry run a runtime upgrade against the local chain state.
+	#[structopt(
+		name = "try-runtime-upgrade",
+		about = "Apply a candidate runtime to the local chain state and execute the following blocks."
+	)]
+	TryRuntimeUpgrade(TryRuntimeUpgradeCmd),
+}
+
+/// The `build-genesis` command.
+///
+/// Every problem with the description, such as a key used twice or a stash endowed with less
+/// than its bond, is reported before anything is written.
+#[derive(Debug, StructOpt)]
+pub struct BuildGenesisCmd {
+	/// The network description, read as JSON if its extension is `.json` and as TOML otherwise.
+	#[structopt(parse(from_os_str))]
+	pub description: PathBuf,
+
+	/// Write the chain spec to this file instead of stdout.
+	#[structopt(long, short, parse(from_os_str))]
+	pub output: Option<PathBuf>,
+}
+
+/// The `fork-off` command.
+///
+/// The runtime code and the state of the pallets stay those of the live chain, except for the
+/// consensus, session and staking pallets, which start over with the development authorities.
+#[derive(Debug, StructOpt)]
+pub struct ForkOffCmd {
+	/// The chain spec written by `export-state`.
+	#[structopt(parse(from_os_str))]
+	pub state: PathBuf,
+
+	/// Seed of a development authority, such as `Alice`. Repeat for several authorities.
+	#[structopt(long = "authority", default_value = "Alice")]
+	pub authorities: Vec<String>,
+
+	/// Address or secret URI, such as `//Alice`, of the sudo key.
+	///
+	/// Only possible when the forked runtime has a sudo pallet. Without one, the members of the
+	/// collectives and of the elections are replaced by the development accounts instead.
+	#[structopt(long)]
+	pub sudo: Option<String>,
+
+	/// Write the chain spec to this file instead of stdout.
+	#[structopt(long, short, parse(from_os_str))]
+	pub output: Option<PathBuf>,
+}
+
+/// The `try-runtime-upgrade` command.
+///
+/// Nothing is written to the database: the state is copied into memory and the blocks are
+/// executed twice, once with the current and once with the candidate runtime.
+#[derive(Debug, StructOpt)]
+pub struct TryRuntimeUpgradeCmd {
+	/// The candidate runtime WASM blob.
+	#[structopt(long, parse(from_os_str))]
+	pub wasm: PathBuf,
+
+	/// Load the state from a file written by `export-state` instead of the database.
+	#[structopt(long, parse(from_os_str))]
+	pub state: Option<PathBuf>,
+
+	/// Number of the block whose state the upgrade is applied to.
+	///
+	/// Defaults to the best block minus `--blocks`. Required with `--state` and `--blocks`, it
+	/// then names the block the state was exported at.
+	#[structopt(long)]
+	pub at: Option<u32>,
+
+	/// Number of stored blocks to execute after the upgrade.
+	///
+	/// With 0, a single empty block is built on top of the state instead.
+	#[structopt(long, default_value = "0")]
+	pub blocks: u32,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+}
+
+/// Session key utilities of validators.
+#[derive(Debug, StructOpt)]
+pub enum KeysSubcommand {
+	/// Derive the four session keys from one secret and insert them into the keystore.
+	#[structopt(
+		name = "provision",
+		about = "Insert the GRANDPA, BABE, ImOnline and AuthorityDiscovery keys derived from one secret."
+	)]
+	Provision(ProvisionKeysCmd),
+
+	/// Encrypt the plain key files of the keystore with the keystore password.
+	#[structopt(
+		name = "encrypt",
+		about = "Encrypt the keystore in place, to be read with `--keystore-encrypted`."
+	)]
+	Encrypt(EncryptKeysCmd),
+}
+
+/// The `keys provision` command.
+///
+/// Each key is derived from the secret with a hard junction named after the key, such as
+/// `<suri>//babe` or `<suri>//im_online`, so that no two keys are the same. The keys are inserted into the keystore
+/// of the chain under `--base-path`, and the SCALE encoded `SessionKeys` are printed for
+/// `session.setKeys`.
+#[derive(Debug, StructOpt)]
+pub struct ProvisionKeysCmd {
+	/// The secret URI, a mnemonic or a raw seed with an optional derivation path, the session
+	/// keys are derived from.
+	#[structopt(long, value_name = "SURI")]
+	pub suri: Option<String>,
+
+	/// Check that the keystore holds the keys registered for this stash in
+	/// `Session::NextKeys`, at the best block of the local database.
+	///
+	/// With `--suri`, the keys are inserted first and must be those registered.
+	#[structopt(long, value_name = "STASH")]
+	pub verify: Option<String>,
+
+	/// Write the keys encrypted with the keystore password, for `--keystore-encrypted`.
+	#[structopt(long = "keystore-encrypted")]
+	pub keystore_encrypted: bool,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub keystore_params: KeystoreParams,
+}
+
+/// The `keys encrypt` command.
+///
+/// Every plain key file of the keystore of the chain under `--base-path` is replaced by one
+/// holding its secret encrypted with the keystore password, given with `--password`,
+/// `--password-filename` or `--password-interactive`. Files already encrypted are left as is.
+#[derive(Debug, StructOpt)]
+pub struct EncryptKeysCmd {
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub keystore_params: KeystoreParams,
+}
@@ -0,0 +1,406 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{chain_spec::{self, IdentifyVariant}, service, Cli, KeysSubcommand, Subcommand};
+use crate::service::{new_partial, new_full_base, FullOptions, NewFullBase};
+use node_primitives::Block;
+use sc_cli::{Result, SubstrateCli, RuntimeVersion, Role, ChainSpec, CliConfiguration};
+use sc_service::{ChainType, PartialComponents};
+use sp_core::crypto::Ss58AddressFormat;
+use std::convert::TryFrom;
+
+impl SubstrateCli for Cli {
+	fn impl_name() -> String {
+		"Aochain Node".into()
+	}
+
+	fn impl_version() -> String {
+		env!("SUBSTRATE_CLI_IMPL_VERSION").into()
+	}
+
+	fn description() -> String {
+		env!("CARGO_PKG_DESCRIPTION").into()
+	}
+
+	fn author() -> String {
+		env!("CARGO_PKG_AUTHORS").into()
+	}
+
+	fn support_url() -> String {
+		" ".into()
+	}
+
+	fn copyright_start_year() -> i32 {
+		2020
+	}
+
+	fn load_spec(&self, id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, String> {
+		Ok(match id {
+			"dev" | "gama-dev" => Box::new(chain_spec::gama::development_config()),
+			"local" | "gama-local" => Box::new(chain_spec::gama::local_testnet_config()),
+			"" | "gama" => Box::new(chain_spec::gama::config()?),
+			"staging" | "gama-staging" => Box::new(chain_spec::gama::staging_testnet_config()),
+			"colombo-dev" => Box::new(chain_spec::colombo::development_config()),
+			"colombo-local" => Box::new(chain_spec::colombo::local_testnet_config()),
+			"colombo" => Box::new(chain_spec::colombo::config()?),
+			"colombo-staging" => Box::new(chain_spec::colombo::staging_testnet_config()),
+			path => {
+				let path = std::path::PathBuf::from(path);
+				let chain_spec: Box<dyn sc_service::ChainSpec> =
+					Box::new(chain_spec::gama::ChainSpec::from_json_file(path.clone())?);
+
+				if chain_spec.is_colombo() {
+					Box::new(chain_spec::colombo::ChainSpec::from_json_file(path)?)
+				} else {
+					chain_spec
+				}
+			},
+		})
+	}
+
+	fn native_runtime_version(chain_spec: &Box<dyn ChainSpec>) -> &'static RuntimeVersion {
+		if chain_spec.is_colombo() {
+			&colombo_runtime::VERSION
+		} else {
+			&gama_runtime::VERSION
+		}
+	}
+}
+
+/// Runs `$code` with `RuntimeApi` and `Executor` naming the runtime of `$chain_spec`.
+macro_rules! with_runtime {
+	($chain_spec:expr, { $( $code:tt )* }) => {
+		if $chain_spec.is_colombo() {
+			#[allow(unused_imports)]
+			use colombo_runtime::RuntimeApi;
+			#[allow(unused_imports)]
+			use crate::service::ColomboExecutor as Executor;
+
+			$( $code )*
+		} else {
+			#[allow(unused_imports)]
+			use gama_runtime::RuntimeApi;
+			#[allow(unused_imports)]
+			use crate::service::GamaExecutor as Executor;
+
+			$( $code )*
+		}
+	}
+}
+
+/// The address format of `spec`: its `ss58Format` property, or else the prefix of its network.
+fn ss58_version(spec: &Box<dyn ChainSpec>) -> Ss58AddressFormat {
+	let prefix = spec.properties().get("ss58Format")
+		.and_then(|format| format.as_u64())
+		.and_then(|format| u8::try_from(format).ok())
+		.unwrap_or_else(|| if spec.is_colombo() {
+			colombo_runtime::network::SS58_PREFIX
+		} else {
+			gama_runtime::network::SS58_PREFIX
+		});
+
+	Ss58AddressFormat::try_from(prefix).unwrap_or(Ss58AddressFormat::Custom(prefix))
+}
+
+fn set_default_ss58_version(spec: &Box<dyn ChainSpec>) {
+	sp_core::crypto::set_default_ss58_version(ss58_version(spec));
+}
+
+/// Sets the address format of the chain given by `--chain` or `--dev`, for the subcommands
+/// that do not load a chain spec themselves.
+fn set_default_ss58_version_from_args(cli: &Cli) -> Result<()> {
+	let shared_params = cli.run.shared_params();
+	let chain_id = shared_params.chain_id(shared_params.dev);
+
+	let spec = if chain_id.is_empty() {
+		// Only the properties are used, which the development spec of the default network shares.
+		Box::new(chain_spec::gama::development_config())
+	} else {
+		cli.load_spec(&chain_id)?
+	};
+	set_default_ss58_version(&spec);
+
+	Ok(())
+}
+
+/// The options of a full node given on the command line.
+fn full_options(cli: &Cli) -> FullOptions {
+	FullOptions {
+		grandpa: cli.grandpa.clone(),
+		indexer: cli.indexer,
+		health: cli.health.clone(),
+		signing: cli.signing.clone(),
+	}
+}
+
+/// Whether `chain_spec` describes a development or local chain, the only ones a node may seal with
+/// `--sealing`.
+fn is_development(chain_spec: &Box<dyn ChainSpec>) -> bool {
+	matches!(chain_spec.chain_type(), ChainType::Development | ChainType::Local)
+}
+
+/// Parse command line arguments into service configuration.
+pub fn run() -> Result<()> {
+	let cli = Cli::from_args();
+
+	match &cli.subcommand {
+		None => {
+			let runner = cli.create_runner(&cli.run)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.run_node_until_exit(|config| match (&config.role, cli.sealing) {
+					(Role::Light, None) => service::new_light::<RuntimeApi, Executor>(config),
+					(Role::Light, Some(_)) =>
+						Err("Light clients cannot seal blocks, `--sealing` needs a full node".into()),
+					(_, Some(_)) if !is_development(&config.chain_spec) => Err(
+						"`--sealing` is only for development and local chains, run with `--dev` or a local chain spec".into()
+					),
+					(_, Some(sealing)) =>
+						service::new_sealing::<RuntimeApi, Executor>(config, sealing, cli.signing.clone()),
+					(_, None) => service::new_full::<RuntimeApi, Executor>(config, full_options(&cli)),
+				})
+			})
+		}
+		Some(Subcommand::Inspect(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.sync_run(|config| cmd.run::<Block, RuntimeApi, Executor>(config))
+			})
+		}
+		Some(Subcommand::Benchmark(cmd)) => {
+			if cfg!(feature = "runtime-benchmarks") {
+				let runner = cli.create_runner(cmd)?;
+				set_default_ss58_version(&runner.config().chain_spec);
+
+				with_runtime!(runner.config().chain_spec, {
+					runner.sync_run(|config| cmd.run::<Block, Executor>(config))
+				})
+			} else {
+				Err("Benchmarking wasn't enabled when building the node. \
+				You can enable it with `--features runtime-benchmarks`.".into())
+			}
+		}
+		Some(Subcommand::Key(cmd)) => {
+			set_default_ss58_version_from_args(&cli)?;
+			cmd.run()
+		},
+		Some(Subcommand::Keys(KeysSubcommand::Provision(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.sync_run(|config| {
+					// Only verifying reads the chain database.
+					let client = match cmd.verify {
+						Some(_) => Some(new_partial::<RuntimeApi, Executor>(&config, &Default::default())?.client),
+						None => None,
+					};
+					cmd.run(&config, client.as_deref())
+				})
+			})
+		},
+		Some(Subcommand::Keys(KeysSubcommand::Encrypt(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| cmd.run(&config))
+		},
+		Some(Subcommand::Sign(cmd)) => {
+			set_default_ss58_version_from_args(&cli)?;
+			cmd.run()
+		},
+		Some(Subcommand::Verify(cmd)) => {
+			set_default_ss58_version_from_args(&cli)?;
+			cmd.run()
+		},
+		Some(Subcommand::Vanity(cmd)) => {
+			set_default_ss58_version_from_args(&cli)?;
+			cmd.run()
+		},
+		Some(Subcommand::BuildSpec(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			runner.sync_run(|config| cmd.run(config.chain_spec, config.network))
+		},
+		Some(Subcommand::BuildGenesis(cmd)) => cmd.run(),
+		Some(Subcommand::ForkOff(cmd)) => {
+			let spec = cli.load_spec(&cmd.state.to_string_lossy())?;
+			set_default_ss58_version(&spec);
+
+			cmd.run(spec)
+		},
+		Some(Subcommand::BuildSyncSpec(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.async_run(|config| {
+					let chain_spec = config.chain_spec.cloned_box();
+					let network_config = config.network.clone();
+					let NewFullBase { task_manager, client, network_status_sinks, .. }
+						= new_full_base::<RuntimeApi, Executor>(config, full_options(&cli), |_, _| ())?;
+
+					Ok((cmd.run(chain_spec, network_config, client, network_status_sinks), task_manager))
+				})
+			})
+		},
+		Some(Subcommand::CheckBlock(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.async_run(|config| {
+					let PartialComponents { client, task_manager, import_queue, ..}
+						= new_partial::<RuntimeApi, Executor>(&config, &Default::default())?;
+					Ok((cmd.run(client, import_queue), task_manager))
+				})
+			})
+		},
+		Some(Subcommand::ExportBlocks(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.async_run(|config| {
+					let PartialComponents { client, task_manager, ..}
+						= new_partial::<RuntimeApi, Executor>(&config, &Default::default())?;
+					Ok((cmd.run(client, config.database), task_manager))
+				})
+			})
+		},
+		Some(Subcommand::ExportState(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.async_run(|config| {
+					let PartialComponents { client, task_manager, ..}
+						= new_partial::<RuntimeApi, Executor>(&config, &Default::default())?;
+					Ok((cmd.run(client, config.chain_spec), task_manager))
+				})
+			})
+		},
+		Some(Subcommand::ImportBlocks(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.async_run(|config| {
+					let PartialComponents { client, task_manager, import_queue, ..}
+						= new_partial::<RuntimeApi, Executor>(&config, &Default::default())?;
+					Ok((cmd.run(client, import_queue), task_manager))
+				})
+			})
+		},
+		Some(Subcommand::PurgeChain(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			runner.sync_run(|config| cmd.run(config.database))
+		},
+		Some(Subcommand::Revert(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.async_run(|config| {
+					let PartialComponents { client, task_manager, backend, ..}
+						= new_partial::<RuntimeApi, Executor>(&config, &Default::default())?;
+					Ok((cmd.run(client, backend), task_manager))
+				})
+			})
+		},
+		Some(Subcommand::TryRuntimeUpgrade(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			set_default_ss58_version(&runner.config().chain_spec);
+
+			with_runtime!(runner.config().chain_spec, {
+				runner.sync_run(|config| {
+					let PartialComponents { client, task_manager, ..}
+						= new_partial::<RuntimeApi, Executor>(&config, &Default::default())?;
+					let max_block_weight = if config.chain_spec.is_colombo() {
+						colombo_runtime::MaximumBlockWeight::get()
+					} else {
+						gama_runtime::MaximumBlockWeight::get()
+					};
+
+					cmd.run::<_, Executor>(&*client, &config, max_block_weight, task_manager.spawn_handle())
+				})
+			})
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// The development spec of colombo, with its properties replaced by `properties`.
+	fn colombo_with_properties(properties: serde_json::Value) -> Box<dyn ChainSpec> {
+		let spec = chain_spec::colombo::development_config();
+		let mut json: serde_json::Value = serde_json::from_str(&spec.as_json(false).unwrap()).unwrap();
+		json["properties"] = properties;
+
+		Box::new(chain_spec::colombo::ChainSpec::from_json_bytes(json.to_string().into_bytes()).unwrap())
+	}
+
+	#[test]
+	fn networks_have_distinct_prefixes() {
+		let gama: Box<dyn ChainSpec> = Box::new(chain_spec::gama::development_config());
+		let colombo: Box<dyn ChainSpec> = Box::new(chain_spec::colombo::development_config());
+
+		assert_eq!(u8::from(ss58_version(&gama)), gama_runtime::network::SS58_PREFIX);
+		assert_eq!(u8::from(ss58_version(&colombo)), colombo_runtime::network::SS58_PREFIX);
+		assert_ne!(ss58_version(&gama), ss58_version(&colombo));
+	}
+
+	#[test]
+	fn every_spec_declares_its_prefix() {
+		let cli = Cli::from_iter(&["aochain"]);
+		for id in &["dev", "local", "staging", "colombo-dev", "colombo-local", "colombo-staging"] {
+			let spec = cli.load_spec(id).unwrap();
+			let expected = if spec.is_colombo() {
+				colombo_runtime::network::SS58_PREFIX
+			} else {
+				gama_runtime::network::SS58_PREFIX
+			};
+
+			assert_eq!(
+				spec.properties().get("ss58Format").and_then(|format| format.as_u64()),
+				Some(expected as u64),
+				"{}", id,
+			);
+		}
+	}
+
+	#[test]
+	fn prefix_comes_from_the_properties() {
+		let spec = colombo_with_properties(serde_json::json!({ "ss58Format": 7 }));
+
+		assert_eq!(u8::from(ss58_version(&spec)), 7);
+	}
+
+	#[test]
+	fn prefix_falls_back_to_the_network() {
+		let spec = colombo_with_properties(serde_json::json!({}));
+
+		assert_eq!(u8::from(ss58_version(&spec)), colombo_runtime::network::SS58_PREFIX);
+	}
+}
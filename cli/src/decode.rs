@@ -0,0 +1,669 @@
+//! Decoding of blocks with the native runtime of each network.
+
+use crate::chain_spec::IdentifyVariant;
+use codec::{Compact, Decode, Encode};
+use frame_metadata::{DecodeDifferent, RuntimeMetadata, RuntimeMetadataPrefixed};
+use node_primitives::{AccountId, AccountIndex, AssetId, Hash};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sp_core::{crypto::Ss58Codec, storage::StorageKey, twox_128};
+use sp_runtime::OpaqueExtrinsic;
+use sp_version::RuntimeVersion;
+use std::collections::HashMap;
+
+/// An asset named by an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AssetRef {
+	/// An asset of the `AssetNFT` pallet.
+	Nft(Hash),
+	/// An asset of the `OrganizationAssetNFT` pallet.
+	OrganizationNft(Hash),
+	/// An asset of the `FungibleAssets` pallet.
+	Fungible(AssetId),
+}
+
+/// The key of `System::Events`.
+pub fn events_key() -> StorageKey {
+	StorageKey([twox_128(b"System"), twox_128(b"Events")].concat())
+}
+
+/// The signer of an extrinsic, as given by its address.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Signer {
+	/// An account id.
+	Id(AccountId),
+	/// An index of the indices pallet, resolved against the state of the block.
+	Index(AccountIndex),
+}
+
+/// An extrinsic of a block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedExtrinsic {
+	/// Index of the pallet and of the call in the pallet.
+	pub call: (u8, u8),
+	/// The signer, `None` for inherents.
+	pub signer: Option<Signer>,
+}
+
+/// An event of a block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedEvent {
+	/// Index of the pallet and of the event in the pallet.
+	pub event: (u8, u8),
+	/// The SCALE encoded fields of the event.
+	pub fields: Vec<u8>,
+	/// The extrinsic that emitted the event.
+	pub extrinsic: Option<u32>,
+	/// Accounts the event names.
+	pub accounts: Vec<AccountId>,
+	/// Assets the event names.
+	pub assets: Vec<AssetRef>,
+	/// `Some(success)` for the event closing an extrinsic.
+	pub outcome: Option<bool>,
+}
+
+/// The extrinsics and events of a block.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DecodedBlock {
+	/// Extrinsics, in block order.
+	pub extrinsics: Vec<DecodedExtrinsic>,
+	/// Events, in block order.
+	pub events: Vec<DecodedEvent>,
+}
+
+/// Names of the pallets, calls and events of a runtime, by index.
+#[derive(Debug, Clone, Default)]
+pub struct Names {
+	pallets: HashMap<u8, String>,
+	calls: HashMap<(u8, u8), String>,
+	events: HashMap<(u8, u8), String>,
+	event_arguments: HashMap<(u8, u8), Vec<String>>,
+}
+
+fn decoded<B, O: Clone>(value: &DecodeDifferent<B, O>) -> Option<O> {
+	match value {
+		DecodeDifferent::Decoded(value) => Some(value.clone()),
+		DecodeDifferent::Encode(_) => None,
+	}
+}
+
+impl Names {
+	/// Reads the names from encoded `RuntimeMetadataPrefixed`.
+	pub fn from_metadata(metadata: &[u8]) -> Result<Self, String> {
+		let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+			.map_err(|e| format!("Invalid runtime metadata: {}", e))?;
+		let modules = match metadata.1 {
+			RuntimeMetadata::V12(metadata) => decoded(&metadata.modules).unwrap_or_default(),
+			_ => return Err("Unsupported runtime metadata version, expected V12".into()),
+		};
+
+		let mut names = Names::default();
+		for module in modules {
+			let pallet = decoded(&module.name).unwrap_or_default();
+			let calls = module.calls.as_ref().and_then(decoded).unwrap_or_default();
+			let events = module.event.as_ref().and_then(decoded).unwrap_or_default();
+
+			for (index, call) in calls.iter().enumerate() {
+				names.calls.insert((module.index, index as u8), decoded(&call.name).unwrap_or_default());
+			}
+			for (index, event) in events.iter().enumerate() {
+				names.events.insert((module.index, index as u8), decoded(&event.name).unwrap_or_default());
+				names.event_arguments.insert((module.index, index as u8), decoded(&event.arguments).unwrap_or_default());
+			}
+			names.pallets.insert(module.index, pallet);
+		}
+
+		Ok(names)
+	}
+
+	fn name(&self, names: &HashMap<(u8, u8), String>, index: (u8, u8)) -> (String, String) {
+		(
+			self.pallets.get(&index.0).cloned().unwrap_or_else(|| format!("Pallet{}", index.0)),
+			names.get(&index).cloned().unwrap_or_else(|| format!("Unknown{}", index.1)),
+		)
+	}
+
+	/// The pallet and call names of a call index.
+	pub fn call(&self, index: (u8, u8)) -> (String, String) {
+		self.name(&self.calls, index)
+	}
+
+	/// The pallet and event names of an event index.
+	pub fn event(&self, index: (u8, u8)) -> (String, String) {
+		self.name(&self.events, index)
+	}
+
+	/// The types of the fields of an event index, as named by the metadata.
+	pub fn event_arguments(&self, index: (u8, u8)) -> &[String] {
+		self.event_arguments.get(&index).map_or(&[], |arguments| &arguments[..])
+	}
+}
+
+/// A type named by the runtime metadata, without its path and `<T as Trait>::` qualifier.
+#[derive(Debug, Clone, PartialEq)]
+enum TypeName {
+	/// A type and its generic arguments, such as `Option<AccountId>`.
+	Named(String, Vec<TypeName>),
+	/// A tuple, `()` when empty.
+	Tuple(Vec<TypeName>),
+	/// A fixed size array, such as `[u8; 32]`.
+	Array(Box<TypeName>, usize),
+}
+
+impl TypeName {
+	/// Parses a type name of the metadata, `None` if it is not a plain Rust type.
+	fn parse(name: &str) -> Option<Self> {
+		let name = name.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+		let mut input = &name[..];
+		let parsed = Self::parse_from(&mut input)?;
+		if input.is_empty() { Some(parsed) } else { None }
+	}
+
+	fn parse_from(input: &mut &str) -> Option<Self> {
+		if let Some(rest) = input.strip_prefix('(') {
+			*input = rest;
+			let mut types = Vec::new();
+			while !Self::eat(input, ')') {
+				types.push(Self::parse_from(input)?);
+				if !Self::eat(input, ',') && !input.starts_with(')') {
+					return None;
+				}
+			}
+			return Some(TypeName::Tuple(types));
+		}
+
+		if let Some(rest) = input.strip_prefix('[') {
+			*input = rest;
+			let item = Self::parse_from(input)?;
+			let rest = input.strip_prefix(';')?;
+			let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or_else(|| rest.len());
+			let len = rest[..digits].parse().ok()?;
+			*input = rest[digits..].strip_prefix(']')?;
+			return Some(TypeName::Array(Box::new(item), len));
+		}
+
+		// `<T as Trait>::Name` is named by `Name`.
+		if input.starts_with('<') {
+			let mut depth = 0;
+			let end = input.find(|c| {
+				match c {
+					'<' => depth += 1,
+					'>' => depth -= 1,
+					_ => {},
+				}
+				depth == 0
+			})?;
+			*input = input[end + 1..].strip_prefix("::")?;
+		}
+
+		// Only the last segment of a path is kept.
+		let mut name;
+		loop {
+			let end = input.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or_else(|| input.len());
+			if end == 0 {
+				return None;
+			}
+			name = input[..end].to_string();
+			*input = &input[end..];
+			match input.strip_prefix("::") {
+				Some(rest) => *input = rest,
+				None => break,
+			}
+		}
+
+		let mut arguments = Vec::new();
+		if Self::eat(input, '<') {
+			while !Self::eat(input, '>') {
+				arguments.push(Self::parse_from(input)?);
+				if !Self::eat(input, ',') && !input.starts_with('>') {
+					return None;
+				}
+			}
+		}
+
+		Some(TypeName::Named(name, arguments))
+	}
+
+	/// Consumes `c` if `input` starts with it.
+	fn eat(input: &mut &str, c: char) -> bool {
+		match input.strip_prefix(c) {
+			Some(rest) => {
+				*input = rest;
+				true
+			},
+			None => false,
+		}
+	}
+
+	fn is_u8(&self) -> bool {
+		matches!(self, TypeName::Named(name, arguments) if name == "u8" && arguments.is_empty())
+	}
+}
+
+fn decode<T: Decode>(input: &mut &[u8]) -> Option<T> {
+	T::decode(input).ok()
+}
+
+fn hex(bytes: &[u8]) -> Value {
+	sp_core::bytes::to_hex(bytes, false).into()
+}
+
+fn dispatch_error(error: sp_runtime::DispatchError) -> Value {
+	match error {
+		sp_runtime::DispatchError::Module { index, error, .. } => json!({ "module": { "index": index, "error": error } }),
+		error => <&'static str>::from(error).into(),
+	}
+}
+
+/// Decodes a field of `pallet` of the type `name`, without generic arguments.
+///
+/// Beside primitives, these are the names the events of the runtimes give to their fields.
+fn named_field(pallet: &str, name: &str, input: &mut &[u8]) -> Option<Value> {
+	Some(match name {
+		"bool" => decode::<bool>(input)?.into(),
+		"u8" => decode::<u8>(input)?.into(),
+		"u16" => decode::<u16>(input)?.into(),
+		"u32" | "BlockNumber" | "AccountIndex" | "Index" | "MemberCount" | "ProposalIndex" | "PropIndex" |
+		"ReferendumIndex" | "SessionIndex" | "EraIndex" | "AuthorityIndex" => decode::<u32>(input)?.into(),
+		"u64" | "Moment" | "Weight" | "AuthorityWeight" => decode::<u64>(input)?.into(),
+		// Beyond the precision of JSON numbers.
+		"u128" | "Balance" | "AssetBalance" | "FixedU128" => decode::<u128>(input)?.to_string().into(),
+		"AssetId" if pallet == "FungibleAssets" => decode::<AssetId>(input)?.into(),
+		// The other pallets name their NFT asset ids, hashes, `AssetId`.
+		"AssetId" | "Hash" | "H256" | "CallHash" | "AuthorityId" => hex(&decode::<[u8; 32]>(input)?),
+		"AccountId" | "ValidatorId" => decode::<AccountId>(input)?.to_ss58check().into(),
+		"Bytes" | "OpaqueTimeSlot" => hex(&decode::<Vec<u8>>(input)?),
+		"Kind" => hex(&decode::<[u8; 16]>(input)?),
+		"LockIdentifier" => hex(&decode::<[u8; 8]>(input)?),
+		"AuthorityList" => decode::<Vec<([u8; 32], u64)>>(input)?.into_iter()
+			.map(|(authority, weight)| json!([hex(&authority), weight]))
+			.collect(),
+		"DispatchInfo" => {
+			let info = decode::<frame_support::weights::DispatchInfo>(input)?;
+			json!({
+				"weight": info.weight,
+				"class": format!("{:?}", info.class),
+				"paysFee": format!("{:?}", info.pays_fee),
+			})
+		},
+		"DispatchError" => dispatch_error(decode(input)?),
+		"DispatchResult" => match decode::<Result<(), sp_runtime::DispatchError>>(input)? {
+			Ok(()) => json!({ "ok": null }),
+			Err(error) => json!({ "err": dispatch_error(error) }),
+		},
+		"BalanceStatus" | "Status" => match decode::<u8>(input)? {
+			0 => "Free".into(),
+			1 => "Reserved".into(),
+			_ => return None,
+		},
+		"VoteThreshold" => match decode::<u8>(input)? {
+			0 => "SuperMajorityApprove".into(),
+			1 => "SuperMajorityAgainst".into(),
+			2 => "SimpleMajority".into(),
+			_ => return None,
+		},
+		"ElectionCompute" => match decode::<u8>(input)? {
+			0 => "OnChain".into(),
+			1 => "Signed".into(),
+			2 => "Unsigned".into(),
+			_ => return None,
+		},
+		"SplitRatio" => {
+			let (treasury, author, burn) = decode::<(u32, u32, u32)>(input)?;
+			json!({ "treasury": treasury, "author": author, "burn": burn })
+		},
+		_ => return None,
+	})
+}
+
+/// Decodes a field of `pallet` of the type `ty`.
+fn field(pallet: &str, ty: &TypeName, input: &mut &[u8]) -> Option<Value> {
+	match ty {
+		TypeName::Tuple(types) => types.iter()
+			.map(|ty| field(pallet, ty, input))
+			.collect::<Option<Vec<_>>>()
+			.map(Value::Array),
+		TypeName::Array(item, len) if item.is_u8() =>
+			Some(hex(&(0..*len).map(|_| decode::<u8>(input)).collect::<Option<Vec<_>>>()?)),
+		TypeName::Array(item, len) => fields(pallet, *len as u32, item, input),
+		TypeName::Named(name, arguments) => match (name.as_str(), &arguments[..]) {
+			("Vec", [item]) if item.is_u8() => Some(hex(&decode::<Vec<u8>>(input)?)),
+			("Vec", [item]) => {
+				let len = decode::<Compact<u32>>(input)?.0;
+				fields(pallet, len, item, input)
+			},
+			("Option", [item]) => match decode::<u8>(input)? {
+				0 => Some(Value::Null),
+				1 => field(pallet, item, input),
+				_ => None,
+			},
+			("Box", [item]) => field(pallet, item, input),
+			("Compact", [_]) => Some(decode::<Compact<u128>>(input)?.0.to_string().into()),
+			("BalanceOf", _) => named_field(pallet, "Balance", input),
+			("TaskAddress", _) => Some(json!([decode::<u32>(input)?, decode::<u32>(input)?])),
+			("Timepoint", _) => Some(json!({ "height": decode::<u32>(input)?, "index": decode::<u32>(input)? })),
+			(name, []) => named_field(pallet, name, input),
+			_ => None,
+		},
+	}
+}
+
+/// Decodes `count` fields of `pallet` of the type `ty` into an array.
+fn fields(pallet: &str, count: u32, ty: &TypeName, input: &mut &[u8]) -> Option<Value> {
+	(0..count)
+		.map(|_| field(pallet, ty, input))
+		.collect::<Option<Vec<_>>>()
+		.map(Value::Array)
+}
+
+/// Decodes the SCALE encoded fields of an event of `pallet` into JSON, from the names of their
+/// `types` in the metadata. `None` if the node does not know one of the types.
+pub fn fields_to_json(pallet: &str, types: &[String], fields: &[u8]) -> Option<Vec<Value>> {
+	let mut input = fields;
+	let values = types.iter()
+		.map(|ty| field(pallet, &TypeName::parse(ty)?, &mut input))
+		.collect::<Option<Vec<_>>>()?;
+
+	// Bytes left over mean the types were not those of the fields.
+	if input.is_empty() { Some(values) } else { None }
+}
+
+/// Decodes the blocks of one network, as long as they run the version of its native runtime.
+#[derive(Clone, Copy)]
+pub struct RuntimeDecoder {
+	/// `spec_name` of the native runtime.
+	pub spec_name: &'static str,
+	/// `spec_version` of the native runtime, the only one whose blocks the decoder reads.
+	pub spec_version: u32,
+	/// Encoded `RuntimeMetadataPrefixed` of the native runtime.
+	pub metadata: fn() -> Vec<u8>,
+	/// Decodes the extrinsics of a block and its encoded `System::Events`.
+	pub block: fn(&[OpaqueExtrinsic], &[u8]) -> Result<DecodedBlock, codec::Error>,
+}
+
+impl RuntimeDecoder {
+	/// The decoder of the network of `chain_spec`.
+	pub fn for_chain(chain_spec: &Box<dyn sc_service::ChainSpec>) -> Self {
+		if chain_spec.is_colombo() { COLOMBO } else { GAMA }
+	}
+
+	/// The decoder of the blocks running `version`, if it is the version of a native runtime.
+	///
+	/// Calls and events are decoded with the types of the native runtime, which would mislabel
+	/// those of any other version.
+	pub fn for_version(version: &RuntimeVersion) -> Option<Self> {
+		[GAMA, COLOMBO].iter().copied().find(|decoder| {
+			&*version.spec_name == decoder.spec_name && version.spec_version == decoder.spec_version
+		})
+	}
+}
+
+/// The first two bytes of an encoded outer call or event, its pallet and variant indices.
+fn indices(encoded: &[u8]) -> (u8, u8) {
+	(encoded.get(0).copied().unwrap_or_default(), encoded.get(1).copied().unwrap_or_default())
+}
+
+macro_rules! runtime_decoder {
+	($name:ident, $module:ident, $runtime:ident, $doc:expr) => {
+		#[doc = $doc]
+		pub const $name: RuntimeDecoder = RuntimeDecoder {
+			spec_name: $runtime::network::SPEC_NAME,
+			spec_version: $runtime::VERSION.spec_version,
+			metadata: $module::metadata,
+			block: $module::block,
+		};
+
+		mod $module {
+			use super::*;
+			use $runtime::{Event, Runtime, UncheckedExtrinsic};
+
+			pub fn metadata() -> Vec<u8> {
+				Runtime::metadata().encode()
+			}
+
+			pub fn block(extrinsics: &[OpaqueExtrinsic], events: &[u8]) -> Result<DecodedBlock, codec::Error> {
+				type EventRecord = frame_system::EventRecord<Event, <Runtime as frame_system::Trait>::Hash>;
+
+				let extrinsics = extrinsics.iter().map(|extrinsic| {
+					let extrinsic = UncheckedExtrinsic::decode(&mut &extrinsic.encode()[..])?;
+					Ok(DecodedExtrinsic {
+						call: indices(&extrinsic.function.encode()),
+						signer: extrinsic.signature.map(|(address, _, _)| match address {
+							pallet_indices::address::Address::Id(account) => Signer::Id(account),
+							pallet_indices::address::Address::Index(index) => Signer::Index(index),
+						}),
+					})
+				}).collect::<Result<Vec<_>, codec::Error>>()?;
+
+				let events = Vec::<EventRecord>::decode(&mut &events[..])?.into_iter().map(|record| {
+					let mut accounts = Vec::new();
+					let mut assets = Vec::new();
+					let mut outcome = None;
+
+					match &record.event {
+						Event::frame_system(event) => match event {
+							frame_system::RawEvent::ExtrinsicSuccess(..) => outcome = Some(true),
+							frame_system::RawEvent::ExtrinsicFailed(..) => outcome = Some(false),
+							frame_system::RawEvent::NewAccount(who) |
+							frame_system::RawEvent::KilledAccount(who) => accounts.push(who.clone()),
+							_ => {},
+						},
+						Event::pallet_balances(event) => match event {
+							pallet_balances::RawEvent::Endowed(who, _) |
+							pallet_balances::RawEvent::DustLost(who, _) |
+							pallet_balances::RawEvent::BalanceSet(who, _, _) |
+							pallet_balances::RawEvent::Deposit(who, _) |
+							pallet_balances::RawEvent::Reserved(who, _) |
+							pallet_balances::RawEvent::Unreserved(who, _) => accounts.push(who.clone()),
+							pallet_balances::RawEvent::Transfer(from, to, _) |
+							pallet_balances::RawEvent::ReserveRepatriated(from, to, _, _) =>
+								accounts.extend(vec![from.clone(), to.clone()]),
+							_ => {},
+						},
+						Event::pallet_staking(event) => match event {
+							pallet_staking::RawEvent::Reward(who, _) |
+							pallet_staking::RawEvent::Slash(who, _) |
+							pallet_staking::RawEvent::Bonded(who, _) |
+							pallet_staking::RawEvent::Unbonded(who, _) |
+							pallet_staking::RawEvent::Withdrawn(who, _) => accounts.push(who.clone()),
+							_ => {},
+						},
+						Event::pallet_contracts(event) => match event {
+							pallet_contracts::RawEvent::Instantiated(deployer, contract) =>
+								accounts.extend(vec![deployer.clone(), contract.clone()]),
+							pallet_contracts::RawEvent::Evicted(contract, _) |
+							pallet_contracts::RawEvent::ContractExecution(contract, _) =>
+								accounts.push(contract.clone()),
+							pallet_contracts::RawEvent::Restored(donor, destination, _, _) =>
+								accounts.extend(vec![donor.clone(), destination.clone()]),
+							_ => {},
+						},
+						Event::pallet_assets_nft_Instance1(event) =>
+							nft_event(event, AssetRef::OrganizationNft, &mut accounts, &mut assets),
+						Event::pallet_assets_nft_Instance2(event) =>
+							nft_event(event, AssetRef::Nft, &mut accounts, &mut assets),
+						Event::pallet_fungible_assets(event) => {
+							use pallet_fungible_assets::RawEvent::*;
+
+							let (asset, named) = match event {
+								Created(asset, owner, admin) => (asset, vec![owner, admin]),
+								Issued(asset, who, _) | Burned(asset, who, _) |
+								Frozen(asset, who) | Thawed(asset, who) => (asset, vec![who]),
+								Transferred(asset, from, to, _) => (asset, vec![from, to]),
+								TransferredApproved(asset, owner, delegate, to, _) => (asset, vec![owner, delegate, to]),
+								ApprovedTransfer(asset, owner, delegate, _) |
+								ApprovalCancelled(asset, owner, delegate) => (asset, vec![owner, delegate]),
+								Destroyed(asset) | AssetFrozen(asset) | AssetThawed(asset) |
+								MetadataSet(asset, _, _, _) => (asset, vec![]),
+							};
+							assets.push(AssetRef::Fungible(*asset));
+							accounts.extend(named.into_iter().cloned());
+						},
+						_ => {},
+					}
+
+					accounts.sort();
+					accounts.dedup();
+
+					let encoded = record.event.encode();
+					DecodedEvent {
+						event: indices(&encoded),
+						fields: encoded.get(2..).unwrap_or_default().to_vec(),
+						extrinsic: match record.phase {
+							frame_system::Phase::ApplyExtrinsic(index) => Some(index),
+							_ => None,
+						},
+						accounts,
+						assets,
+						outcome,
+					}
+				}).collect();
+
+				Ok(DecodedBlock { extrinsics, events })
+			}
+		}
+	}
+}
+
+/// Collects the accounts and asset of an event of an NFT pallet instance.
+fn nft_event<Balance, I>(
+	event: &pallet_assets_nft::RawEvent<Hash, AccountId, Balance, I>,
+	asset_ref: fn(Hash) -> AssetRef,
+	accounts: &mut Vec<AccountId>,
+	assets: &mut Vec<AssetRef>,
+) {
+	use pallet_assets_nft::RawEvent::*;
+
+	let (asset, named, operator) = match event {
+		Burned(asset, owner, _, operator) => (asset, vec![owner], operator),
+		Minted(asset, owner, _, _, operator) => (asset, vec![owner], operator),
+		AssetTransferred(asset, from, to, operator) |
+		TokenTransferred(asset, from, to, _, operator) => (asset, vec![from, to], operator),
+		_ => return,
+	};
+
+	assets.push(asset_ref(*asset));
+	accounts.extend(named.into_iter().chain(operator).cloned());
+}
+
+runtime_decoder!(GAMA, gama, gama_runtime, "Decodes the blocks of gama chains.");
+runtime_decoder!(COLOMBO, colombo, colombo_runtime, "Decodes the blocks of colombo chains.");
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn names_come_from_the_native_metadata() {
+		let names = Names::from_metadata(&(GAMA.metadata)()).unwrap();
+
+		let remark = indices(&gama_runtime::Call::System(frame_system::Call::remark(vec![])).encode());
+		assert_eq!(names.call(remark), ("System".into(), "remark".into()));
+
+		let minted = indices(&gama_runtime::Event::pallet_assets_nft_Instance2(
+			pallet_assets_nft::RawEvent::Minted(Default::default(), Default::default(), 1, [0; 32], None)
+		).encode());
+		assert_eq!(names.event(minted), ("AssetNFT".into(), "Minted".into()));
+	}
+
+	#[test]
+	fn type_names_drop_paths_and_qualifiers() {
+		let named = |name: &str, arguments| TypeName::Named(name.into(), arguments);
+
+		assert_eq!(TypeName::parse("<T as frame_system::Trait>::AccountId"), Some(named("AccountId", vec![])));
+		assert_eq!(
+			TypeName::parse("Option<T::AccountId>"),
+			Some(named("Option", vec![named("AccountId", vec![])])),
+		);
+		assert_eq!(
+			TypeName::parse("Vec<(AccountId, [u8; 32])>"),
+			Some(named("Vec", vec![TypeName::Tuple(vec![
+				named("AccountId", vec![]),
+				TypeName::Array(Box::new(named("u8", vec![])), 32),
+			])])),
+		);
+		assert_eq!(TypeName::parse("Vec<u8"), None);
+	}
+
+	#[test]
+	fn fields_are_decoded_with_the_types_of_the_metadata() {
+		sp_core::crypto::set_default_ss58_version(sp_core::crypto::Ss58AddressFormat::SubstrateAccount);
+		let names = Names::from_metadata(&(GAMA.metadata)()).unwrap();
+		let json = |event: gama_runtime::Event| {
+			let encoded = event.encode();
+			let (pallet, _) = names.event(indices(&encoded));
+			fields_to_json(&pallet, names.event_arguments(indices(&encoded)), &encoded[2..])
+		};
+		let alice = AccountId::from([1; 32]);
+
+		assert_eq!(
+			json(gama_runtime::Event::pallet_balances(pallet_balances::RawEvent::Transfer(alice.clone(), alice.clone(), 5))),
+			Some(vec![alice.to_ss58check().into(), alice.to_ss58check().into(), "5".into()]),
+		);
+		assert_eq!(
+			json(gama_runtime::Event::pallet_assets_nft_Instance2(
+				pallet_assets_nft::RawEvent::Minted([7; 32].into(), alice.clone(), 1, [0; 32], None)
+			)),
+			Some(vec![hex(&[7; 32]), alice.to_ss58check().into(), "1".into(), hex(&[0; 32]), Value::Null]),
+		);
+		assert_eq!(
+			json(gama_runtime::Event::pallet_fungible_assets(pallet_fungible_assets::RawEvent::Destroyed(3))),
+			Some(vec![3.into()]),
+		);
+		assert_eq!(
+			json(gama_runtime::Event::frame_system(frame_system::RawEvent::ExtrinsicSuccess(Default::default()))),
+			Some(vec![json!({ "weight": 0, "class": "Normal", "paysFee": "Yes" })]),
+		);
+	}
+
+	#[test]
+	fn unknown_types_leave_the_fields_undecoded() {
+		assert_eq!(fields_to_json("Pallet", &["Unknown".into()], &[0]), None);
+		assert_eq!(fields_to_json("Pallet", &["u8".into()], &[0, 1]), None);
+		assert_eq!(fields_to_json("Pallet", &["u8".into()], &[1]), Some(vec![1.into()]));
+	}
+
+	#[test]
+	fn decoders_are_picked_by_runtime_version() {
+		let gama = RuntimeDecoder::for_version(&gama_runtime::VERSION).unwrap();
+		let colombo = RuntimeDecoder::for_version(&colombo_runtime::VERSION).unwrap();
+		assert_eq!(gama.spec_name, "gama");
+		assert_eq!(colombo.spec_name, "colombo");
+
+		let mut upgraded = gama_runtime::VERSION;
+		upgraded.spec_version += 1;
+		assert!(RuntimeDecoder::for_version(&upgraded).is_none());
+	}
+
+	#[test]
+	fn events_name_their_accounts_and_assets() {
+		let alice = AccountId::from([1; 32]);
+		let bob = AccountId::from([2; 32]);
+		let records = vec![
+			frame_system::EventRecord {
+				phase: frame_system::Phase::ApplyExtrinsic(1),
+				event: gama_runtime::Event::pallet_assets_nft_Instance2(
+					pallet_assets_nft::RawEvent::AssetTransferred(
+						[7; 32].into(), alice.clone(), bob.clone(), Some(alice.clone()),
+					)
+				),
+				topics: vec![],
+			},
+			frame_system::EventRecord {
+				phase: frame_system::Phase::ApplyExtrinsic(1),
+				event: gama_runtime::Event::frame_system(
+					frame_system::RawEvent::ExtrinsicSuccess(Default::default())
+				),
+				topics: vec![],
+			},
+		];
+
+		let block = (GAMA.block)(&[], &records.encode()).unwrap();
+
+		assert_eq!(block.events[0].extrinsic, Some(1));
+		assert_eq!(block.events[0].accounts, vec![alice, bob]);
+		assert_eq!(block.events[0].assets, vec![AssetRef::Nft([7; 32].into())]);
+		assert_eq!(block.events[1].outcome, Some(true));
+	}
+}
@@ -0,0 +1,292 @@
+//! Turns the state of a live chain into the genesis of a private test chain.
+//!
+//! The storage written by `export-state` is kept as is, runtime code included, except for the
+//! pallets driving consensus and the validator set. Those are taken from a development genesis
+//! of the same network, with the given development authorities, so the forked chain produces and
+//! finalizes blocks right away. The balances of the development accounts are overwritten and the
+//! total issuance is recomputed to match.
+//!
+//! Without a sudo pallet root is only reachable through governance, so the members of the
+//! collectives, of the technical membership and of the elections are taken from the development
+//! genesis too, handing governance to the development accounts.
+
+use crate::{ForkOffCmd, chain_spec::{self, IdentifyVariant, authority_keys_from_seed}};
+use codec::{Decode, Encode};
+use node_primitives::{AccountId, Balance, Index};
+use sc_cli::{Error, Result};
+use sc_service::ChainSpec;
+use sp_core::{
+	crypto::{Pair, Ss58Codec},
+	hexdisplay::HexDisplay,
+	sr25519,
+	storage::Storage,
+	twox_128,
+};
+use sp_runtime::BuildStorage;
+use std::{fs, io::Write};
+
+/// Pallets whose storage comes from the development genesis, by storage prefix.
+const REPLACED_PALLETS: &[&str] = &[
+	"System",
+	"Babe",
+	"Authorship",
+	"Staking",
+	"Session",
+	"GrandpaFinality",
+	"ImOnline",
+	"AuthorityDiscovery",
+];
+
+/// Governance storage taken from the development genesis when the forked runtime has no sudo
+/// pallet, by storage prefix of the pallet and item.
+///
+/// The collectives are `Council`, `TechnicalCommittee` and `Organization`. Their proposals and
+/// votes are kept, the new members can vote on them.
+const REPLACED_GOVERNANCE_ITEMS: &[(&str, &str)] = &[
+	("Instance1Collective", "Members"),
+	("Instance1Collective", "Prime"),
+	("Instance2Collective", "Members"),
+	("Instance2Collective", "Prime"),
+	("Instance3Collective", "Members"),
+	("Instance3Collective", "Prime"),
+	("Instance1Membership", "Members"),
+	("Instance1Membership", "Prime"),
+];
+
+/// Governance pallets taken as a whole from the development genesis when the forked runtime has
+/// no sudo pallet: the council members, runners up, candidates and votes of `Elections`.
+const REPLACED_GOVERNANCE_PALLETS: &[&str] = &["PhragmenElection"];
+
+/// Items of the replaced pallets kept from the forked state.
+///
+/// The account balances are the point of the fork, and the last runtime upgrade matches the
+/// forked `:code`, which would otherwise run `on_runtime_upgrade` again.
+const KEPT_ITEMS: &[(&str, &str)] = &[
+	("System", "Account"),
+	("System", "LastRuntimeUpgrade"),
+	("System", "UpgradedToU32RefCount"),
+];
+
+/// Well known keys taken from the development genesis.
+const REPLACED_WELL_KNOWN_KEYS: &[&[u8]] = &[
+	// Defined by `sp-finality-grandpa`, which only exposes it to the runtime.
+	b":grandpa_authorities",
+];
+
+type AccountInfo = frame_system::AccountInfo<Index, pallet_balances::AccountData<Balance>>;
+
+fn pallet_prefix(pallet: &str) -> Vec<u8> {
+	twox_128(pallet.as_bytes()).to_vec()
+}
+
+fn storage_prefix(pallet: &str, item: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+/// Replaces consensus and staking in `forked` by their state in `dev`, optionally setting the
+/// key of the sudo pallet if the forked runtime has one, and replacing the governance members
+/// otherwise.
+pub fn fork_off(mut forked: Storage, dev: Storage, sudo: Option<AccountId>) -> std::result::Result<Storage, String> {
+	let sudo_prefix = pallet_prefix("Sudo");
+	let has_sudo = forked.top.keys().any(|key| key.starts_with(&sudo_prefix));
+
+	let mut replaced = REPLACED_PALLETS.iter().map(|pallet| pallet_prefix(pallet)).collect::<Vec<_>>();
+	if !has_sudo {
+		replaced.extend(REPLACED_GOVERNANCE_PALLETS.iter().map(|pallet| pallet_prefix(pallet)));
+		replaced.extend(REPLACED_GOVERNANCE_ITEMS.iter().map(|(pallet, item)| storage_prefix(pallet, item)));
+	}
+	let kept = KEPT_ITEMS.iter().map(|(pallet, item)| storage_prefix(pallet, item)).collect::<Vec<_>>();
+	let is_replaced = |key: &[u8]| {
+		replaced.iter().any(|prefix| key.starts_with(prefix))
+			&& !kept.iter().any(|prefix| key.starts_with(prefix))
+	};
+
+	forked.top.retain(|key, _| !is_replaced(key));
+	for key in REPLACED_WELL_KNOWN_KEYS {
+		forked.top.remove(*key);
+	}
+
+	// The development accounts get their balances and staking locks from the development genesis.
+	let overridden = [storage_prefix("System", "Account"), storage_prefix("Balances", "Locks")];
+	for (key, value) in dev.top {
+		let taken = is_replaced(&key)
+			|| REPLACED_WELL_KNOWN_KEYS.contains(&&key[..])
+			|| overridden.iter().any(|prefix| key.starts_with(prefix));
+		if taken {
+			forked.top.insert(key, value);
+		}
+	}
+
+	let accounts = storage_prefix("System", "Account");
+	let total_issuance = forked.top.iter()
+		.filter(|(key, _)| key.starts_with(&accounts))
+		.try_fold(0 as Balance, |total, (key, value)| {
+			AccountInfo::decode(&mut &value[..])
+				.map(|info| total.saturating_add(info.data.free).saturating_add(info.data.reserved))
+				.map_err(|e| format!("Undecodable account 0x{}: {}", HexDisplay::from(key), e))
+		})?;
+	forked.top.insert(storage_prefix("Balances", "TotalIssuance"), total_issuance.encode());
+
+	if let Some(sudo) = sudo {
+		if !has_sudo {
+			return Err(
+				"The forked runtime has no sudo pallet, root calls go through governance, which the \
+				development accounts are given instead".into()
+			);
+		}
+		forked.top.insert(storage_prefix("Sudo", "Key"), sudo.encode());
+	}
+
+	Ok(forked)
+}
+
+impl ForkOffCmd {
+	/// Run the fork-off command on `spec`, the chain spec written by `export-state`.
+	pub fn run(&self, mut spec: Box<dyn ChainSpec>) -> Result<()> {
+		let sudo = self.sudo.as_ref().map(|sudo| parse_account(sudo)).transpose()?;
+		let authorities = self.authorities.iter()
+			.map(|seed| authority_keys_from_seed(seed))
+			.collect::<Vec<_>>();
+
+		let forked = spec.build_storage()?;
+		let dev = if spec.is_colombo() {
+			chain_spec::colombo::testnet_genesis(authorities, None, true).build_storage()?
+		} else {
+			chain_spec::gama::testnet_genesis(authorities, None, true).build_storage()?
+		};
+		let name = format!("{} Fork", spec.name());
+		let id = format!("{}_fork", spec.id());
+
+		spec.set_storage(fork_off(forked, dev, sudo)?);
+
+		// The forked chain must not connect to the nodes and telemetry of the live one.
+		let mut json: serde_json::Value = serde_json::from_str(&spec.as_json(true)?)
+			.map_err(|e| format!("Invalid chain spec: {}", e))?;
+		json["name"] = name.into();
+		json["id"] = id.into();
+		json["chainType"] = "Local".into();
+		json["bootNodes"] = serde_json::Value::Array(Vec::new());
+		json["telemetryEndpoints"] = serde_json::Value::Null;
+		let json = serde_json::to_string_pretty(&json)
+			.map_err(|e| format!("Failed to write the chain spec: {}", e))?;
+
+		match &self.output {
+			Some(path) => fs::write(path, json)?,
+			None => std::io::stdout().write_all(json.as_bytes())?,
+		}
+
+		Ok(())
+	}
+}
+
+/// Parses an SS58 address, or a secret URI such as `//Alice` of an sr25519 account.
+fn parse_account(account: &str) -> Result<AccountId> {
+	AccountId::from_ss58check(account)
+		.or_else(|_| sr25519::Pair::from_string(account, None).map(|pair| pair.public().into()))
+		.map_err(|_| Error::Input(format!("`{}` is neither an address nor a secret URI", account)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chain_spec::{get_account_id_from_seed, gama::testnet_genesis};
+	use sp_consensus_babe::AuthorityId as BabeId;
+	use sp_core::storage::well_known_keys;
+
+	fn live_state() -> Storage {
+		testnet_genesis(
+			vec![authority_keys_from_seed("Bob"), authority_keys_from_seed("Charlie")],
+			Some(vec![get_account_id_from_seed::<sr25519::Public>("Live")]),
+			false,
+		).build_storage().unwrap()
+	}
+
+	fn dev_state() -> Storage {
+		testnet_genesis(vec![authority_keys_from_seed("Alice")], None, true).build_storage().unwrap()
+	}
+
+	fn get<T: Decode>(storage: &Storage, key: &[u8]) -> Option<T> {
+		storage.top.get(key).map(|value| T::decode(&mut &value[..]).unwrap())
+	}
+
+	fn account_key(account: &AccountId) -> Vec<u8> {
+		let encoded = account.encode();
+		[storage_prefix("System", "Account"), sp_core::blake2_128(&encoded).to_vec(), encoded].concat()
+	}
+
+	#[test]
+	fn consensus_comes_from_the_development_genesis() {
+		let mut live = live_state();
+		live.top.insert(well_known_keys::CODE.to_vec(), b"live runtime".to_vec());
+
+		let forked = fork_off(live, dev_state(), None).unwrap();
+
+		let babe = get::<Vec<(BabeId, u64)>>(&forked, &storage_prefix("Babe", "Authorities")).unwrap();
+		assert_eq!(babe, vec![(authority_keys_from_seed("Alice").3, 1)]);
+		assert_eq!(
+			get::<Vec<AccountId>>(&forked, &storage_prefix("Session", "Validators")).unwrap(),
+			vec![authority_keys_from_seed("Alice").0],
+		);
+		assert_eq!(
+			get::<Vec<AccountId>>(&forked, &storage_prefix("Staking", "Invulnerables")).unwrap(),
+			vec![authority_keys_from_seed("Alice").0],
+		);
+		assert_eq!(forked.top[well_known_keys::CODE], b"live runtime".to_vec());
+	}
+
+	#[test]
+	fn live_balances_are_kept_and_issuance_recomputed() {
+		let live_account = get_account_id_from_seed::<sr25519::Public>("Live");
+		let alice_stash = authority_keys_from_seed("Alice").0;
+		let live = live_state();
+		let live_account_before = get::<AccountInfo>(&live, &account_key(&live_account)).unwrap();
+		assert!(get::<AccountInfo>(&live, &account_key(&alice_stash)).is_none());
+
+		let forked = fork_off(live, dev_state(), None).unwrap();
+
+		assert_eq!(get::<AccountInfo>(&forked, &account_key(&live_account)).unwrap(), live_account_before);
+		assert!(get::<AccountInfo>(&forked, &account_key(&alice_stash)).is_some());
+
+		let accounts = storage_prefix("System", "Account");
+		let total = forked.top.iter()
+			.filter(|(key, _)| key.starts_with(&accounts))
+			.map(|(_, value)| AccountInfo::decode(&mut &value[..]).unwrap())
+			.map(|info| info.data.free + info.data.reserved)
+			.sum::<Balance>();
+		assert_eq!(get::<Balance>(&forked, &storage_prefix("Balances", "TotalIssuance")), Some(total));
+	}
+
+	#[test]
+	fn governance_comes_from_the_development_genesis_without_sudo() {
+		let council = storage_prefix("PhragmenElection", "Members");
+		let technical_committee = storage_prefix("Instance2Collective", "Members");
+		let dev = dev_state();
+		let dev_council = get::<Vec<(AccountId, Balance)>>(&dev, &council).unwrap();
+		let dev_technical_committee = get::<Vec<AccountId>>(&dev, &technical_committee).unwrap();
+		let live_technical_committee = vec![get_account_id_from_seed::<sr25519::Public>("Live")];
+		assert_eq!(get::<Vec<AccountId>>(&live_state(), &technical_committee), Some(live_technical_committee.clone()));
+
+		let forked = fork_off(live_state(), dev.clone(), None).unwrap();
+
+		assert_eq!(get::<Vec<(AccountId, Balance)>>(&forked, &council), Some(dev_council));
+		assert_eq!(get::<Vec<AccountId>>(&forked, &technical_committee), Some(dev_technical_committee));
+
+		let mut live = live_state();
+		live.top.insert(storage_prefix("Sudo", "Key"), authority_keys_from_seed("Bob").1.encode());
+		let forked = fork_off(live, dev, None).unwrap();
+
+		assert_eq!(get::<Vec<AccountId>>(&forked, &technical_committee), Some(live_technical_committee));
+	}
+
+	#[test]
+	fn sudo_requires_a_sudo_pallet() {
+		let alice = parse_account("//Alice").unwrap();
+
+		assert!(fork_off(live_state(), dev_state(), Some(alice.clone())).is_err());
+
+		let mut live = live_state();
+		live.top.insert(storage_prefix("Sudo", "Key"), authority_keys_from_seed("Bob").1.encode());
+		let forked = fork_off(live, dev_state(), Some(alice.clone())).unwrap();
+		assert_eq!(get::<AccountId>(&forked, &storage_prefix("Sudo", "Key")), Some(alice));
+	}
+}
@@ -0,0 +1,304 @@
+//! A lightweight HTTP endpoint telling load balancers whether the node is healthy and ready.
+//!
+//! `GET /health` answers `200` as long as the node runs, and `GET /ready` answers `200` when the
+//! node is synced, has enough peers, keeps finalizing and keeps importing blocks, `503`
+//! otherwise. Both return the status of the node as JSON, or as one line of text when the
+//! request accepts `text/plain`.
+
+use crate::HealthParams;
+use futures::prelude::*;
+use hyper::{
+	header::{ACCEPT, CONTENT_TYPE},
+	service::{make_service_fn, service_fn},
+	Body, Method, Request, Response, Server, StatusCode,
+};
+use node_primitives::{Block, BlockNumber};
+use parking_lot::Mutex;
+use sc_client_api::BlockchainEvents;
+use sc_network::{NetworkStatus, SyncState};
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use std::{
+	net::{Ipv4Addr, SocketAddr},
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+/// How often the network status is refreshed.
+const NETWORK_STATUS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// When the node stops being ready.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+	/// Fewest connected peers.
+	pub min_peers: usize,
+	/// Most blocks between the best and the finalized block.
+	pub max_finality_lag: BlockNumber,
+	/// Longest time without importing a block.
+	pub max_import_age: Duration,
+}
+
+impl From<&HealthParams> for Thresholds {
+	fn from(params: &HealthParams) -> Self {
+		Thresholds {
+			min_peers: params.min_peers,
+			max_finality_lag: params.max_finality_lag,
+			max_import_age: Duration::from_secs(params.max_import_age),
+		}
+	}
+}
+
+/// What the network worker last reported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NetworkView {
+	syncing: bool,
+	peers: usize,
+}
+
+impl From<&NetworkStatus<Block>> for NetworkView {
+	fn from(status: &NetworkStatus<Block>) -> Self {
+		NetworkView {
+			syncing: status.sync_state == SyncState::Downloading,
+			peers: status.num_connected_peers,
+		}
+	}
+}
+
+/// The status of the node, as returned by the endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+	/// Whether the node is ready to serve requests.
+	pub ready: bool,
+	/// Why the node is not ready, empty when it is.
+	pub reasons: Vec<String>,
+	/// Whether the node is downloading blocks, `null` before the network reported.
+	pub syncing: Option<bool>,
+	/// Connected peers, `null` before the network reported.
+	pub peers: Option<usize>,
+	/// Number of the best block.
+	pub best: BlockNumber,
+	/// Number of the last finalized block.
+	pub finalized: BlockNumber,
+	/// Blocks between the best and the finalized block.
+	pub finality_lag: BlockNumber,
+	/// Seconds since the last block import, or since the node started.
+	pub last_import_secs: u64,
+}
+
+impl Status {
+	fn new(
+		network: Option<NetworkView>,
+		best: BlockNumber,
+		finalized: BlockNumber,
+		last_import: Duration,
+		thresholds: &Thresholds,
+	) -> Self {
+		let finality_lag = best.saturating_sub(finalized);
+		let mut reasons = Vec::new();
+
+		match network {
+			None => reasons.push("the network has not reported its status yet".into()),
+			Some(network) => {
+				if network.syncing {
+					reasons.push("the node is syncing".into());
+				}
+				if network.peers < thresholds.min_peers {
+					reasons.push(format!("{} peers, {} needed", network.peers, thresholds.min_peers));
+				}
+			},
+		}
+		if finality_lag > thresholds.max_finality_lag {
+			reasons.push(format!(
+				"finality is {} blocks behind, at most {} allowed",
+				finality_lag, thresholds.max_finality_lag,
+			));
+		}
+		if last_import > thresholds.max_import_age {
+			reasons.push(format!("no block imported for {}s", last_import.as_secs()));
+		}
+
+		Status {
+			ready: reasons.is_empty(),
+			reasons,
+			syncing: network.map(|network| network.syncing),
+			peers: network.map(|network| network.peers),
+			best,
+			finalized,
+			finality_lag,
+			last_import_secs: last_import.as_secs(),
+		}
+	}
+
+	fn plain(&self) -> String {
+		if self.ready {
+			"ready\n".into()
+		} else {
+			format!("not ready: {}\n", self.reasons.join(", "))
+		}
+	}
+}
+
+/// What the node tracks besides its client.
+struct State {
+	network: Option<NetworkView>,
+	last_import: Instant,
+}
+
+/// The health endpoint of a node.
+pub struct Health<C> {
+	client: Arc<C>,
+	state: Arc<Mutex<State>>,
+	thresholds: Thresholds,
+}
+
+impl<C> Clone for Health<C> {
+	fn clone(&self) -> Self {
+		Health { client: self.client.clone(), state: self.state.clone(), thresholds: self.thresholds }
+	}
+}
+
+impl<C> Health<C> where
+	C: HeaderBackend<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+{
+	/// Creates the endpoint of the node of `client`.
+	pub fn new(client: Arc<C>, thresholds: Thresholds) -> Self {
+		let state = State { network: None, last_import: Instant::now() };
+		Health { client, state: Arc::new(Mutex::new(state)), thresholds }
+	}
+
+	/// The current status of the node.
+	pub fn status(&self) -> Status {
+		let info = self.client.info();
+		let state = self.state.lock();
+		Status::new(state.network, info.best_number, info.finalized_number, state.last_import.elapsed(), &self.thresholds)
+	}
+
+	fn respond(&self, request: &Request<Body>) -> Response<Body> {
+		let ready = match (request.method(), request.uri().path()) {
+			(&Method::GET, "/health") => false,
+			(&Method::GET, "/ready") => true,
+			_ => return Response::builder()
+				.status(StatusCode::NOT_FOUND)
+				.body(Body::from("Not found, try /health or /ready.\n"))
+				.expect("The response is valid; qed"),
+		};
+
+		let status = self.status();
+		let code = if ready && !status.ready { StatusCode::SERVICE_UNAVAILABLE } else { StatusCode::OK };
+		let plain = request.headers().get(ACCEPT)
+			.and_then(|accept| accept.to_str().ok())
+			.map_or(false, |accept| accept.contains("text/plain"));
+		let (content_type, body) = if plain {
+			("text/plain; charset=utf-8", status.plain())
+		} else {
+			("application/json", serde_json::to_string(&status).expect("The status serializes; qed"))
+		};
+
+		Response::builder()
+			.status(code)
+			.header(CONTENT_TYPE, content_type)
+			.body(Body::from(body))
+			.expect("The response is valid; qed")
+	}
+
+	/// Follows the network status from `network_status_sinks` and the block imports, and serves
+	/// the endpoint on `port`, on every interface if `external`.
+	pub async fn run(
+		self,
+		network_status_sinks: sc_service::NetworkStatusSinks<Block>,
+		port: u16,
+		external: bool,
+	) {
+		let network = network_status_sinks.network_status(NETWORK_STATUS_INTERVAL).for_each({
+			let state = self.state.clone();
+			move |(status, _)| {
+				state.lock().network = Some(NetworkView::from(&status));
+				future::ready(())
+			}
+		});
+		let imports = self.client.import_notification_stream().for_each({
+			let state = self.state.clone();
+			move |_| {
+				state.lock().last_import = Instant::now();
+				future::ready(())
+			}
+		});
+
+		let interface = if external { Ipv4Addr::UNSPECIFIED } else { Ipv4Addr::LOCALHOST };
+		let address = SocketAddr::from((interface, port));
+		let health = self.clone();
+		let make_service = make_service_fn(move |_| {
+			let health = health.clone();
+			async move {
+				Ok::<_, hyper::Error>(service_fn(move |request| {
+					future::ready(Ok::<_, hyper::Error>(health.respond(&request)))
+				}))
+			}
+		});
+		let server = async move {
+			match Server::try_bind(&address) {
+				Ok(builder) => {
+					log::info!("〽️ Health endpoint on http://{}", address);
+					if let Err(e) = builder.serve(make_service).await {
+						log::error!("Health endpoint failed: {}", e);
+					}
+				},
+				Err(e) => log::error!("Failed to bind the health endpoint to {}: {}", address, e),
+			}
+		};
+
+		future::join3(network, imports, server).await;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const THRESHOLDS: Thresholds = Thresholds {
+		min_peers: 2,
+		max_finality_lag: 10,
+		max_import_age: Duration::from_secs(60),
+	};
+
+	#[test]
+	fn synced_node_is_ready() {
+		let network = NetworkView { syncing: false, peers: 3 };
+		let status = Status::new(Some(network), 110, 100, Duration::from_secs(6), &THRESHOLDS);
+
+		assert!(status.ready);
+		assert_eq!(status.finality_lag, 10);
+		assert_eq!(status.plain(), "ready\n");
+		assert_eq!(
+			serde_json::to_value(&status).unwrap(),
+			serde_json::json!({
+				"ready": true,
+				"reasons": [],
+				"syncing": false,
+				"peers": 3,
+				"best": 110,
+				"finalized": 100,
+				"finalityLag": 10,
+				"lastImportSecs": 6,
+			}),
+		);
+	}
+
+	#[test]
+	fn every_threshold_makes_the_node_unready() {
+		let network = NetworkView { syncing: true, peers: 1 };
+		let status = Status::new(Some(network), 120, 100, Duration::from_secs(61), &THRESHOLDS);
+
+		assert!(!status.ready);
+		assert_eq!(status.reasons, vec![
+			"the node is syncing".to_string(),
+			"1 peers, 2 needed".into(),
+			"finality is 20 blocks behind, at most 10 allowed".into(),
+			"no block imported for 61s".into(),
+		]);
+
+		let status = Status::new(None, 0, 0, Duration::from_secs(0), &THRESHOLDS);
+		assert_eq!(status.plain(), "not ready: the network has not reported its status yet\n");
+	}
+}
@@ -0,0 +1,304 @@
+//! Storage of the index in its own key-value database.
+//!
+//! Entries are keyed by block and position, with the bits of both inverted so that iterating a
+//! prefix yields the newest entries first. Each lookup column maps a subject followed by the key
+//! of an entry to nothing.
+
+use super::{AssetRef, Entry, UnsupportedVersion};
+use codec::{Decode, Encode};
+use kvdb::{DBTransaction, KeyValueDB};
+use node_primitives::{AccountId, BlockNumber, Hash};
+use std::{io, path::Path, sync::Arc};
+
+const NUM_COLUMNS: u32 = 5;
+
+mod columns {
+	/// Entries, by block and position in the block.
+	pub const ENTRIES: u32 = 0;
+	/// Keys of the entries, by account.
+	pub const ACCOUNTS: u32 = 1;
+	/// Keys of the entries, by pallet.
+	pub const PALLETS: u32 = 2;
+	/// Keys of the entries, by asset.
+	pub const ASSETS: u32 = 3;
+	/// Progress of the indexer.
+	pub const META: u32 = 4;
+}
+
+/// Number of the last block whose forks have been removed.
+const PRUNED: &[u8] = b"pruned";
+/// Number of the highest block indexed.
+const BEST: &[u8] = b"best";
+/// Runtime versions whose blocks are not indexed.
+const UNSUPPORTED: &[u8] = b"unsupported";
+
+/// What entries are looked up by.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Subject {
+	/// Entries involving an account.
+	Account(AccountId),
+	/// Entries of a pallet.
+	Pallet(String),
+	/// Entries involving an asset.
+	Asset(AssetRef),
+}
+
+impl Subject {
+	fn column(&self) -> u32 {
+		match self {
+			Subject::Account(_) => columns::ACCOUNTS,
+			Subject::Pallet(_) => columns::PALLETS,
+			Subject::Asset(_) => columns::ASSETS,
+		}
+	}
+
+	/// The prefix of the keys of the subject, never a prefix of the keys of another subject of
+	/// the same column.
+	fn prefix(&self) -> Vec<u8> {
+		match self {
+			Subject::Account(account) => account.encode(),
+			// Length prefixed.
+			Subject::Pallet(pallet) => pallet.encode(),
+			Subject::Asset(asset) => asset.encode(),
+		}
+	}
+}
+
+fn block_key(number: BlockNumber, hash: &Hash) -> Vec<u8> {
+	[&(!number).to_be_bytes()[..], hash.as_bytes()].concat()
+}
+
+fn entry_key(number: BlockNumber, hash: &Hash, position: u32) -> Vec<u8> {
+	[block_key(number, hash), (!position).to_be_bytes().to_vec()].concat()
+}
+
+fn subjects(entry: &Entry) -> impl Iterator<Item = Subject> + '_ {
+	entry.accounts.iter().cloned().map(Subject::Account)
+		.chain(std::iter::once(Subject::Pallet(entry.pallet.clone())))
+		.chain(entry.assets.iter().copied().map(Subject::Asset))
+}
+
+fn decode_entry(value: &[u8]) -> io::Result<Entry> {
+	Entry::decode(&mut &value[..]).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+}
+
+/// The database of the indexer.
+pub struct IndexDb {
+	db: Arc<dyn KeyValueDB>,
+}
+
+impl IndexDb {
+	/// Opens, or creates, the RocksDB database at `path`.
+	pub fn open(path: &Path) -> io::Result<Self> {
+		let config = kvdb_rocksdb::DatabaseConfig::with_columns(NUM_COLUMNS);
+		let db = kvdb_rocksdb::Database::open(&config, &path.to_string_lossy())?;
+		Ok(IndexDb { db: Arc::new(db) })
+	}
+
+	/// Creates an empty database held in memory.
+	#[cfg(test)]
+	pub fn in_memory() -> Self {
+		IndexDb { db: Arc::new(kvdb_memorydb::create(NUM_COLUMNS)) }
+	}
+
+	fn meta(&self, key: &[u8]) -> io::Result<Option<BlockNumber>> {
+		Ok(self.db.get(columns::META, key)?
+			.and_then(|value| BlockNumber::decode(&mut &value[..]).ok()))
+	}
+
+	/// Number of the highest block indexed.
+	pub fn best(&self) -> io::Result<Option<BlockNumber>> {
+		self.meta(BEST)
+	}
+
+	/// Number of the last block whose forks have been removed.
+	pub fn pruned(&self) -> io::Result<Option<BlockNumber>> {
+		self.meta(PRUNED)
+	}
+
+	/// Records that the forks of the blocks up to `number` have been removed.
+	pub fn set_pruned(&self, number: BlockNumber) -> io::Result<()> {
+		let mut transaction = DBTransaction::new();
+		transaction.put(columns::META, PRUNED, &number.encode());
+		self.db.write(transaction)
+	}
+
+	/// Runtime versions met whose blocks are not indexed, in the order they were met.
+	pub fn unsupported_versions(&self) -> io::Result<Vec<UnsupportedVersion>> {
+		self.db.get(columns::META, UNSUPPORTED)?
+			.map_or(Ok(Vec::new()), |value| Vec::<UnsupportedVersion>::decode(&mut &value[..])
+				.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string())))
+	}
+
+	/// Records a runtime version whose blocks are not indexed, unless it already is.
+	pub fn add_unsupported_version(&self, version: UnsupportedVersion) -> io::Result<()> {
+		let mut versions = self.unsupported_versions()?;
+		let known = |known: &UnsupportedVersion|
+			known.spec_name == version.spec_name && known.spec_version == version.spec_version;
+		if versions.iter().any(known) {
+			return Ok(());
+		}
+		versions.push(version);
+
+		let mut transaction = DBTransaction::new();
+		transaction.put(columns::META, UNSUPPORTED, &versions.encode());
+		self.db.write(transaction)
+	}
+
+	/// Adds the deletion of the entries of a block to `transaction`.
+	fn delete_block(&self, transaction: &mut DBTransaction, number: BlockNumber, hash: &Hash) -> io::Result<()> {
+		for (key, value) in self.db.iter_with_prefix(columns::ENTRIES, &block_key(number, hash)) {
+			for subject in subjects(&decode_entry(&value)?) {
+				transaction.delete(subject.column(), &[subject.prefix(), key.to_vec()].concat());
+			}
+			transaction.delete(columns::ENTRIES, &key);
+		}
+		Ok(())
+	}
+
+	/// Stores the entries of a block, in block order, replacing those it had.
+	pub fn insert(&self, number: BlockNumber, hash: Hash, entries: &[Entry]) -> io::Result<()> {
+		let mut transaction = DBTransaction::new();
+		self.delete_block(&mut transaction, number, &hash)?;
+
+		for (position, entry) in entries.iter().enumerate() {
+			let key = entry_key(number, &hash, position as u32);
+			for subject in subjects(entry) {
+				transaction.put(subject.column(), &[subject.prefix(), key.clone()].concat(), &[]);
+			}
+			transaction.put(columns::ENTRIES, &key, &entry.encode());
+		}
+		if self.best()?.map_or(true, |best| best < number) {
+			transaction.put(columns::META, BEST, &number.encode());
+		}
+
+		self.db.write(transaction)
+	}
+
+	/// Removes the entries of a block.
+	pub fn remove(&self, number: BlockNumber, hash: Hash) -> io::Result<()> {
+		let mut transaction = DBTransaction::new();
+		self.delete_block(&mut transaction, number, &hash)?;
+		self.db.write(transaction)
+	}
+
+	/// Hashes of the indexed blocks at `number`.
+	pub fn hashes_at(&self, number: BlockNumber) -> io::Result<Vec<Hash>> {
+		let mut hashes = Vec::<Hash>::new();
+		for (key, _) in self.db.iter_with_prefix(columns::ENTRIES, &(!number).to_be_bytes()) {
+			let hash = Hash::from_slice(&key[4..36]);
+			if hashes.last() != Some(&hash) {
+				hashes.push(hash);
+			}
+		}
+		Ok(hashes)
+	}
+
+	/// The entries of a subject, newest first, with those of every fork.
+	pub fn entries<'a>(&'a self, subject: &Subject) -> impl Iterator<Item = io::Result<Entry>> + 'a {
+		let prefix_len = subject.prefix().len();
+		self.db.iter_with_prefix(subject.column(), &subject.prefix()).filter_map(move |(key, _)| {
+			// An entry of a block removed in the meantime is skipped.
+			self.db.get(columns::ENTRIES, &key[prefix_len..])
+				.and_then(|value| value.map(|value| decode_entry(&value)).transpose())
+				.transpose()
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indexer::EntryKind;
+
+	fn entry(number: BlockNumber, hash: Hash, pallet: &str, accounts: Vec<AccountId>) -> Entry {
+		Entry {
+			block_number: number,
+			block_hash: hash,
+			kind: EntryKind::Event,
+			extrinsic_index: None,
+			event_index: Some(0),
+			pallet: pallet.into(),
+			name: "Event".into(),
+			accounts,
+			assets: vec![AssetRef::Nft([number as u8; 32].into())],
+			success: None,
+		}
+	}
+
+	fn collect(db: &IndexDb, subject: Subject) -> Vec<(BlockNumber, String)> {
+		db.entries(&subject)
+			.map(|entry| entry.map(|entry| (entry.block_number, entry.name)).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn entries_come_newest_first() {
+		let db = IndexDb::in_memory();
+		let alice = AccountId::from([1; 32]);
+		let mut second = entry(1, [1; 32].into(), "Balances", vec![alice.clone()]);
+		second.name = "Second".into();
+
+		db.insert(1, [1; 32].into(), &[entry(1, [1; 32].into(), "Balances", vec![alice.clone()]), second]).unwrap();
+		db.insert(2, [2; 32].into(), &[entry(2, [2; 32].into(), "Staking", vec![alice.clone()])]).unwrap();
+
+		assert_eq!(
+			collect(&db, Subject::Account(alice)),
+			vec![(2, "Event".into()), (1, "Second".into()), (1, "Event".into())],
+		);
+		assert_eq!(collect(&db, Subject::Pallet("Staking".into())), vec![(2, "Event".into())]);
+		assert_eq!(collect(&db, Subject::Pallet("Stak".into())), vec![]);
+		assert_eq!(collect(&db, Subject::Asset(AssetRef::Nft([1; 32].into()))).len(), 2);
+		assert_eq!(db.best().unwrap(), Some(2));
+	}
+
+	#[test]
+	fn removed_blocks_leave_no_entries() {
+		let db = IndexDb::in_memory();
+		let alice = AccountId::from([1; 32]);
+		let canonical = Hash::from([1; 32]);
+		let retracted = Hash::from([2; 32]);
+
+		db.insert(1, canonical, &[entry(1, canonical, "Balances", vec![alice.clone()])]).unwrap();
+		db.insert(1, retracted, &[entry(1, retracted, "Balances", vec![alice.clone()])]).unwrap();
+		assert_eq!(db.hashes_at(1).unwrap().len(), 2);
+
+		db.remove(1, retracted).unwrap();
+
+		assert_eq!(db.hashes_at(1).unwrap(), vec![canonical]);
+		assert_eq!(
+			db.entries(&Subject::Account(alice)).map(|entry| entry.unwrap().block_hash).collect::<Vec<_>>(),
+			vec![canonical],
+		);
+	}
+
+	#[test]
+	fn unsupported_versions_are_recorded_once() {
+		let db = IndexDb::in_memory();
+		let version = |spec_version, first_block| UnsupportedVersion {
+			spec_name: "gama".into(),
+			spec_version,
+			first_block,
+		};
+
+		db.add_unsupported_version(version(3, 1)).unwrap();
+		db.add_unsupported_version(version(3, 2)).unwrap();
+		db.add_unsupported_version(version(6, 9)).unwrap();
+
+		assert_eq!(db.unsupported_versions().unwrap(), vec![version(3, 1), version(6, 9)]);
+	}
+
+	#[test]
+	fn reindexing_replaces_the_entries_of_a_block() {
+		let db = IndexDb::in_memory();
+		let alice = AccountId::from([1; 32]);
+		let bob = AccountId::from([2; 32]);
+		let hash = Hash::from([1; 32]);
+
+		db.insert(1, hash, &[entry(1, hash, "Balances", vec![alice.clone()])]).unwrap();
+		db.insert(1, hash, &[entry(1, hash, "Balances", vec![bob.clone()])]).unwrap();
+
+		assert_eq!(collect(&db, Subject::Account(alice)), vec![]);
+		assert_eq!(collect(&db, Subject::Account(bob)), vec![(1, "Event".into())]);
+	}
+}
@@ -0,0 +1,389 @@
+//! An optional index of the extrinsics and events of the chain, by account, pallet and asset.
+//!
+//! The indexer follows block imports, decodes the extrinsics and `System::Events` of every block
+//! with the native runtime and stores one entry per extrinsic and per event in a key-value
+//! database next to the chain database. Blocks imported before the indexer was enabled are
+//! indexed in the background, as long as their state is available.
+//!
+//! Only blocks running the version of a native runtime can be decoded, and their names are read
+//! from the metadata at the block. The versions of the other blocks are logged and reported by
+//! `indexer_status`, their blocks are not indexed.
+//!
+//! Blocks of every fork are indexed as they are imported. The `indexer_*` RPCs only return
+//! entries of the canonical chain, and the entries of the forks are removed once a block at
+//! their height is finalized.
+
+mod db;
+pub mod rpc;
+
+pub use crate::decode::AssetRef;
+pub use db::{IndexDb, Subject};
+
+use crate::decode::{events_key, DecodedBlock, Names, RuntimeDecoder, Signer};
+use codec::{Decode, Encode};
+use futures::prelude::*;
+use node_primitives::{AccountId, AccountIndex, Balance, Block, BlockNumber, Hash};
+use sc_client_api::{BlockBackend, BlockchainEvents, StorageProvider};
+use serde::Serialize;
+use sp_api::{Core, Metadata, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{blake2_128, storage::StorageKey, twox_128};
+use sp_runtime::{generic::BlockId, traits::Header as _};
+use std::{collections::{hash_map, HashMap}, sync::Arc};
+
+/// Whether an entry is an extrinsic or an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EntryKind {
+	/// A call of the pallet, included in the block.
+	Extrinsic,
+	/// An event deposited by the pallet.
+	Event,
+}
+
+/// An indexed extrinsic or event.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+	/// Number of the block.
+	pub block_number: BlockNumber,
+	/// Hash of the block.
+	pub block_hash: Hash,
+	/// Whether this is an extrinsic or an event.
+	pub kind: EntryKind,
+	/// Index of the extrinsic in the block, or of the one which emitted the event.
+	pub extrinsic_index: Option<u32>,
+	/// Index of the event in the block.
+	pub event_index: Option<u32>,
+	/// Name of the pallet.
+	pub pallet: String,
+	/// Name of the call or event.
+	pub name: String,
+	/// The accounts involved: the signer and every account named by the events of an extrinsic,
+	/// or the accounts named by an event.
+	pub accounts: Vec<AccountId>,
+	/// The assets involved.
+	pub assets: Vec<AssetRef>,
+	/// Whether an extrinsic succeeded, `None` for events.
+	pub success: Option<bool>,
+}
+
+/// A runtime version no native runtime decodes, whose blocks are not indexed.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsupportedVersion {
+	/// `spec_name` of the runtime.
+	pub spec_name: String,
+	/// `spec_version` of the runtime.
+	pub spec_version: u32,
+	/// Number of the first block met running it.
+	pub first_block: BlockNumber,
+}
+
+/// The key of the account of an index in `Indices::Accounts`.
+fn index_key(index: AccountIndex) -> StorageKey {
+	let encoded = index.encode();
+	StorageKey([&twox_128(b"Indices")[..], &twox_128(b"Accounts"), &blake2_128(&encoded), &encoded].concat())
+}
+
+/// Turns a decoded block into entries, extrinsics first.
+fn entries(
+	names: &Names,
+	number: BlockNumber,
+	hash: Hash,
+	block: DecodedBlock,
+	resolve: impl Fn(AccountIndex) -> Option<AccountId>,
+) -> Vec<Entry> {
+	let extrinsics = block.extrinsics.into_iter().enumerate().map(|(index, extrinsic)| {
+		let index = index as u32;
+		let (pallet, name) = names.call(extrinsic.call);
+		let events = block.events.iter().filter(|event| event.extrinsic == Some(index));
+
+		let mut accounts = extrinsic.signer.and_then(|signer| match signer {
+			Signer::Id(account) => Some(account),
+			Signer::Index(index) => resolve(index),
+		}).into_iter().collect::<Vec<_>>();
+		let mut assets = Vec::new();
+		let mut success = None;
+		for event in events {
+			accounts.extend(event.accounts.iter().cloned());
+			for asset in &event.assets {
+				if !assets.contains(asset) {
+					assets.push(*asset);
+				}
+			}
+			success = event.outcome.or(success);
+		}
+		accounts.sort();
+		accounts.dedup();
+
+		Entry {
+			block_number: number,
+			block_hash: hash,
+			kind: EntryKind::Extrinsic,
+			extrinsic_index: Some(index),
+			event_index: None,
+			pallet,
+			name,
+			accounts,
+			assets,
+			success,
+		}
+	}).collect::<Vec<_>>();
+
+	let events = block.events.into_iter().enumerate().map(|(index, event)| {
+		let (pallet, name) = names.event(event.event);
+		Entry {
+			block_number: number,
+			block_hash: hash,
+			kind: EntryKind::Event,
+			extrinsic_index: event.extrinsic,
+			event_index: Some(index as u32),
+			pallet,
+			name,
+			accounts: event.accounts,
+			assets: event.assets,
+			success: None,
+		}
+	});
+
+	extrinsics.into_iter().chain(events).collect()
+}
+
+/// Indexes the blocks of a chain into an [`IndexDb`].
+pub struct Indexer {
+	db: Arc<IndexDb>,
+	/// Names of the calls and events, by `spec_name` and `spec_version`.
+	names: HashMap<(String, u32), Names>,
+}
+
+impl Indexer {
+	/// Creates an indexer decoding blocks with the native runtime of their version.
+	pub fn new(db: Arc<IndexDb>) -> Self {
+		Indexer { db, names: HashMap::new() }
+	}
+
+	/// Indexes the block `hash`, replacing whatever it was indexed with before.
+	fn index_block<C, B>(&mut self, client: &C, hash: Hash) -> Result<(), String> where
+		C: HeaderBackend<Block> + BlockBackend<Block> + StorageProvider<Block, B> + ProvideRuntimeApi<Block>,
+		C::Api: Metadata<Block>,
+		B: sc_client_api::Backend<Block>,
+	{
+		let id = BlockId::Hash(hash);
+		let number = client.number(hash)
+			.map_err(|e| e.to_string())?
+			.ok_or_else(|| format!("Unknown block {}", hash))?;
+
+		let api = client.runtime_api();
+		let version = api.version(&id)
+			.map_err(|e| format!("No runtime version for block #{} ({}): {:?}", number, hash, e))?;
+		let decoder = match RuntimeDecoder::for_version(&version) {
+			Some(decoder) => decoder,
+			None => return self.unsupported(number, version.spec_name.to_string(), version.spec_version),
+		};
+		let names = match self.names.entry((version.spec_name.to_string(), version.spec_version)) {
+			hash_map::Entry::Occupied(names) => names.into_mut(),
+			hash_map::Entry::Vacant(entry) => {
+				let metadata = api.metadata(&id)
+					.map_err(|e| format!("No metadata for block #{} ({}): {:?}", number, hash, e))?;
+				entry.insert(Names::from_metadata(&metadata)?)
+			},
+		};
+
+		let extrinsics = client.block_body(&id)
+			.map_err(|e| e.to_string())?
+			.ok_or_else(|| format!("No body for block #{} ({})", number, hash))?;
+		let events = client.storage(&id, &events_key())
+			.map_err(|e| format!("No state for block #{} ({}): {}", number, hash, e))?
+			// An empty `Vec`.
+			.map_or_else(|| vec![0], |events| events.0);
+
+		let block = (decoder.block)(&extrinsics, &events)
+			.map_err(|e| format!("Undecodable block #{} ({}): {}", number, hash, e))?;
+		let resolve = |index| client.storage(&id, &index_key(index)).ok()
+			.flatten()
+			.and_then(|account| <(AccountId, Balance, bool)>::decode(&mut &account.0[..]).ok())
+			.map(|(account, _, _)| account);
+
+		self.db.insert(number, hash, &entries(names, number, hash, block, resolve))
+			.map_err(|e| format!("Failed to index block #{}: {}", number, e))
+	}
+
+	/// Reports the block `number` running a version no native runtime decodes, logging the
+	/// version the first time it is met.
+	fn unsupported(&self, number: BlockNumber, spec_name: String, spec_version: u32) -> Result<(), String> {
+		let known = self.db.unsupported_versions().map_err(|e| e.to_string())?.into_iter()
+			.any(|known| known.spec_name == spec_name && known.spec_version == spec_version);
+		if !known {
+			log::warn!(
+				"📇 Block #{} runs {} spec_version {}, which no native runtime of this node decodes. \
+				Blocks of this version are not indexed.",
+				number, spec_name, spec_version,
+			);
+		}
+
+		self.db.add_unsupported_version(UnsupportedVersion { spec_name, spec_version, first_block: number })
+			.map_err(|e| format!("Failed to record the version of block #{}: {}", number, e))
+	}
+
+	/// Indexes the canonical blocks which are not final yet, or not indexed at all on the first
+	/// run.
+	fn catch_up<C, B>(&mut self, client: &C) -> Result<(), String> where
+		C: HeaderBackend<Block> + BlockBackend<Block> + StorageProvider<Block, B> + ProvideRuntimeApi<Block>,
+		C::Api: Metadata<Block>,
+		B: sc_client_api::Backend<Block>,
+	{
+		let first = self.db.pruned().map_err(|e| e.to_string())?.map_or(1, |pruned| pruned + 1);
+		let best = client.info().best_number;
+		if first <= best {
+			log::info!("📇 Indexing blocks #{} to #{}", first, best);
+		}
+
+		let mut missing_state = false;
+		for number in first..=best {
+			let hash = match client.hash(number).map_err(|e| e.to_string())? {
+				Some(hash) => hash,
+				None => break,
+			};
+			if let Err(e) = self.index_block(client, hash) {
+				if !missing_state {
+					log::warn!(
+						"📇 {}. Blocks without state are not indexed, run an archive node \
+						(`--pruning archive`) to index the whole chain.",
+						e,
+					);
+				}
+				missing_state = true;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Removes the entries of the blocks that are not ancestors of the finalized `number`.
+	fn prune_forks<C: HeaderBackend<Block>>(&self, client: &C, number: BlockNumber) -> Result<(), String> {
+		let first = self.db.pruned().map_err(|e| e.to_string())?.map_or(1, |pruned| pruned + 1);
+		for number in first..=number {
+			let canonical = client.hash(number).map_err(|e| e.to_string())?;
+			for hash in self.db.hashes_at(number).map_err(|e| e.to_string())? {
+				if Some(hash) != canonical {
+					log::debug!("📇 Removing the entries of retracted block #{} ({})", number, hash);
+					self.db.remove(number, hash).map_err(|e| e.to_string())?;
+				}
+			}
+		}
+
+		self.db.set_pruned(number).map_err(|e| e.to_string())
+	}
+
+	/// Runs the indexer, first catching up with the chain, then following its imports.
+	pub async fn run<C, B>(mut self, client: Arc<C>) where
+		C: HeaderBackend<Block> + BlockBackend<Block> + StorageProvider<Block, B> + BlockchainEvents<Block>
+			+ ProvideRuntimeApi<Block>,
+		C::Api: Metadata<Block>,
+		B: sc_client_api::Backend<Block>,
+	{
+		enum Notification {
+			Import(Hash),
+			Finality(BlockNumber),
+		}
+
+		// Subscribe first, so that no block imported while catching up is missed.
+		let imports = client.import_notification_stream()
+			.map(|notification| Notification::Import(notification.hash));
+		let finality = client.finality_notification_stream()
+			.map(|notification| Notification::Finality(*notification.header.number()));
+		let mut notifications = futures::stream::select(imports, finality);
+
+		let caught_up = self.catch_up(&*client)
+			.and_then(|_| self.prune_forks(&*client, client.info().finalized_number));
+		if let Err(e) = caught_up {
+			log::error!("📇 Indexer stopped: {}", e);
+			return;
+		}
+
+		while let Some(notification) = notifications.next().await {
+			let result = match notification {
+				Notification::Import(hash) => self.index_block(&*client, hash),
+				Notification::Finality(number) => self.prune_forks(&*client, number),
+			};
+			if let Err(e) = result {
+				log::warn!("📇 {}", e);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::decode::{DecodedEvent, DecodedExtrinsic, GAMA};
+
+	fn names() -> Names {
+		Names::from_metadata(&(GAMA.metadata)()).unwrap()
+	}
+
+	#[test]
+	fn extrinsics_collect_the_accounts_and_assets_of_their_events() {
+		let alice = AccountId::from([1; 32]);
+		let bob = AccountId::from([2; 32]);
+		let charlie = AccountId::from([3; 32]);
+		let event = |extrinsic, accounts: Vec<AccountId>, assets, outcome| DecodedEvent {
+			event: (0, 0),
+			fields: vec![],
+			extrinsic: Some(extrinsic),
+			accounts,
+			assets,
+			outcome,
+		};
+		let block = DecodedBlock {
+			extrinsics: vec![
+				DecodedExtrinsic { call: (0, 0), signer: None },
+				DecodedExtrinsic { call: (0, 1), signer: Some(Signer::Index(7)) },
+			],
+			events: vec![
+				event(0, vec![], vec![], Some(true)),
+				event(1, vec![bob.clone(), charlie.clone()], vec![AssetRef::Fungible(3)], None),
+				event(1, vec![bob.clone()], vec![AssetRef::Fungible(3)], None),
+				event(1, vec![], vec![], Some(false)),
+			],
+		};
+		let resolve = |index| if index == 7 { Some(alice.clone()) } else { None };
+
+		let entries = entries(&names(), 5, [9; 32].into(), block, resolve);
+
+		assert_eq!(entries.len(), 6);
+		assert_eq!(entries[0].accounts, vec![]);
+		assert_eq!(entries[0].success, Some(true));
+		assert_eq!(entries[1].kind, EntryKind::Extrinsic);
+		assert_eq!(entries[1].accounts, vec![alice, bob.clone(), charlie.clone()]);
+		assert_eq!(entries[1].assets, vec![AssetRef::Fungible(3)]);
+		assert_eq!(entries[1].success, Some(false));
+		assert_eq!(entries[3].kind, EntryKind::Event);
+		assert_eq!(entries[3].extrinsic_index, Some(1));
+		assert_eq!(entries[3].event_index, Some(1));
+		assert_eq!(entries[3].accounts, vec![bob, charlie]);
+	}
+
+	#[test]
+	fn entries_serialize_with_named_assets() {
+		let entry = Entry {
+			block_number: 5,
+			block_hash: Default::default(),
+			kind: EntryKind::Event,
+			extrinsic_index: Some(1),
+			event_index: Some(2),
+			pallet: "FungibleAssets".into(),
+			name: "Issued".into(),
+			accounts: vec![],
+			assets: vec![AssetRef::Fungible(3)],
+			success: None,
+		};
+
+		let json = serde_json::to_value(&entry).unwrap();
+
+		assert_eq!(json["kind"], "event");
+		assert_eq!(json["blockNumber"], 5);
+		assert_eq!(json["assets"], serde_json::json!([{ "fungible": 3 }]));
+	}
+}
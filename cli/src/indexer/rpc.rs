@@ -0,0 +1,165 @@
+//! RPC queries of the index.
+
+use super::{AssetRef, Entry, IndexDb, Subject, UnsupportedVersion};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_primitives::{AccountId, Block, BlockNumber};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use std::sync::Arc;
+
+/// Number of entries returned when a page sets no limit.
+const DEFAULT_LIMIT: u32 = 100;
+/// Maximum number of entries of a page.
+const MAX_LIMIT: u32 = 500;
+
+/// A page of a query.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct Page {
+	/// Number of entries skipped, the `nextOffset` of the previous page.
+	pub offset: u32,
+	/// Maximum number of entries returned, at most 500.
+	pub limit: u32,
+}
+
+impl Default for Page {
+	fn default() -> Self {
+		Page { offset: 0, limit: DEFAULT_LIMIT }
+	}
+}
+
+/// Entries of the canonical chain, newest first.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entries {
+	/// The entries of the page.
+	pub entries: Vec<Entry>,
+	/// Offset of the next page, `None` on the last page.
+	pub next_offset: Option<u32>,
+}
+
+/// Progress of the indexer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+	/// Number of the highest block indexed.
+	pub best: Option<BlockNumber>,
+	/// Number of the last finalized block whose forks were removed from the index.
+	pub finalized: Option<BlockNumber>,
+	/// Runtime versions met which no native runtime of the node decodes, whose blocks are not
+	/// indexed.
+	pub unsupported_versions: Vec<UnsupportedVersion>,
+}
+
+/// Queries of the extrinsics and events of the chain.
+///
+/// Pages are taken from the newest entry. Entries of blocks which are not in the canonical
+/// chain, as seen by the node, are left out.
+#[rpc]
+pub trait IndexerApi {
+	/// Extrinsics and events involving an account.
+	#[rpc(name = "indexer_accountHistory")]
+	fn account_history(&self, account: AccountId, page: Option<Page>) -> Result<Entries>;
+
+	/// Extrinsics and events of a pallet, such as `AssetNFT`.
+	#[rpc(name = "indexer_palletHistory")]
+	fn pallet_history(&self, pallet: String, page: Option<Page>) -> Result<Entries>;
+
+	/// Extrinsics and events involving an asset, such as `{ "nft": "0x…" }`.
+	#[rpc(name = "indexer_assetHistory")]
+	fn asset_history(&self, asset: AssetRef, page: Option<Page>) -> Result<Entries>;
+
+	/// Progress of the indexer.
+	#[rpc(name = "indexer_status")]
+	fn status(&self) -> Result<Status>;
+}
+
+/// A struct that implements the [`IndexerApi`].
+pub struct Indexer<C> {
+	client: Arc<C>,
+	db: Arc<IndexDb>,
+}
+
+impl<C> Indexer<C> {
+	/// Create new `Indexer` querying `db`, filtered by the chain of `client`.
+	pub fn new(client: Arc<C>, db: Arc<IndexDb>) -> Self {
+		Indexer { client, db }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The page is too large.
+	InvalidPage,
+	/// The database or the chain could not be read.
+	StorageError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::InvalidPage => 1,
+			Error::StorageError => 2,
+		}
+	}
+}
+
+fn storage_error(e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::StorageError.into()),
+		message: "Unable to query the index.".into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C: HeaderBackend<Block>> Indexer<C> {
+	fn query(&self, subject: Subject, page: Option<Page>) -> Result<Entries> {
+		let page = page.unwrap_or_default();
+		if page.limit > MAX_LIMIT {
+			return Err(RpcError {
+				code: ErrorCode::ServerError(Error::InvalidPage.into()),
+				message: format!("Pages hold at most {} entries.", MAX_LIMIT),
+				data: None,
+			});
+		}
+
+		let mut entries = Vec::new();
+		let mut canonical = self.db.entries(&subject).filter(|entry| match entry {
+			Ok(entry) => self.client.hash(entry.block_number).ok().flatten() == Some(entry.block_hash),
+			Err(_) => true,
+		}).skip(page.offset as usize);
+
+		// One more entry tells whether there is a next page.
+		for entry in canonical.by_ref().take(page.limit as usize) {
+			entries.push(entry.map_err(storage_error)?);
+		}
+		let next_offset = canonical.next().map(|_| page.offset + entries.len() as u32);
+
+		Ok(Entries { entries, next_offset })
+	}
+}
+
+impl<C> IndexerApi for Indexer<C> where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+{
+	fn account_history(&self, account: AccountId, page: Option<Page>) -> Result<Entries> {
+		self.query(Subject::Account(account), page)
+	}
+
+	fn pallet_history(&self, pallet: String, page: Option<Page>) -> Result<Entries> {
+		self.query(Subject::Pallet(pallet), page)
+	}
+
+	fn asset_history(&self, asset: AssetRef, page: Option<Page>) -> Result<Entries> {
+		self.query(Subject::Asset(asset), page)
+	}
+
+	fn status(&self) -> Result<Status> {
+		Ok(Status {
+			best: self.db.best().map_err(storage_error)?,
+			finalized: self.db.pruned().map_err(storage_error)?,
+			unsupported_versions: self.db.unsupported_versions().map_err(storage_error)?,
+		})
+	}
+}
@@ -0,0 +1,298 @@
+//! Provisioning of the session keys of a validator.
+//!
+//! The four session keys are derived from a single secret, inserted into the keystore the node
+//! signs with, and can be checked against the keys registered on chain in `Session::NextKeys`.
+//! The keystore can also be encrypted in place, for nodes started with `--keystore-encrypted`.
+
+use crate::{keystore::encrypted, EncryptKeysCmd, ProvisionKeysCmd};
+use codec::{Decode, Encode};
+use grandpa_primitives::AuthorityId as GrandpaId;
+use node_primitives::{AccountId, Block};
+use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
+use sc_cli::{CliConfiguration, Error, KeystoreParams, Result, SharedParams};
+use sc_client_api::StorageProvider;
+use sc_keystore::{KeyStorePtr, Store};
+use sc_service::{config::KeystoreConfig, Configuration, TFullBackend};
+use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_babe::AuthorityId as BabeId;
+use sp_core::{
+	crypto::{key_types, KeyTypeId, Ss58Codec},
+	hexdisplay::HexDisplay,
+	storage::StorageKey,
+	traits::BareCryptoStore,
+	twox_128, twox_64, Pair, Public,
+};
+use sp_runtime::generic::BlockId;
+
+/// The session keys, shared by the runtimes of all networks.
+type SessionKeys = gama_runtime::SessionKeys;
+
+impl CliConfiguration for ProvisionKeysCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn keystore_params(&self) -> Option<&KeystoreParams> {
+		Some(&self.keystore_params)
+	}
+}
+
+impl CliConfiguration for EncryptKeysCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn keystore_params(&self) -> Option<&KeystoreParams> {
+		Some(&self.keystore_params)
+	}
+}
+
+/// The name of a session key, as a field of `SessionKeys`.
+fn key_name(key_type: KeyTypeId) -> &'static str {
+	match key_type {
+		key_types::GRANDPA => "grandpa",
+		key_types::BABE => "babe",
+		key_types::IM_ONLINE => "im_online",
+		key_types::AUTHORITY_DISCOVERY => "authority_discovery",
+		_ => "unknown",
+	}
+}
+
+/// The secret URI of the session key of `key_type`: `suri` with a hard junction named after it.
+fn key_suri(suri: &str, key_type: KeyTypeId) -> String {
+	format!("{}//{}", suri, key_name(key_type))
+}
+
+/// The session key of `key_type` derived from `suri`, and the hex seed of its pair.
+fn derive<TPublic: Public>(suri: &str, key_type: KeyTypeId) -> Result<(<TPublic::Pair as Pair>::Public, String)> {
+	let (pair, seed) = TPublic::Pair::from_string_with_seed(&key_suri(suri, key_type), None)
+		.map_err(|_| Error::Input("Invalid secret URI".into()))?;
+	// Soft junctions leave the derived pair without a seed.
+	let seed = seed.ok_or_else(|| Error::Input("The secret URI must only have hard junctions".into()))?;
+	Ok((pair.public(), format!("0x{}", HexDisplay::from(&seed.as_ref()))))
+}
+
+/// Derives the session keys of `suri`, with the hex seeds of their pairs by key type.
+fn derive_with_seeds(suri: &str) -> Result<(SessionKeys, Vec<(KeyTypeId, String)>)> {
+	let (grandpa, grandpa_seed) = derive::<GrandpaId>(suri, key_types::GRANDPA)?;
+	let (babe, babe_seed) = derive::<BabeId>(suri, key_types::BABE)?;
+	let (im_online, im_online_seed) = derive::<ImOnlineId>(suri, key_types::IM_ONLINE)?;
+	let (authority_discovery, authority_discovery_seed) =
+		derive::<AuthorityDiscoveryId>(suri, key_types::AUTHORITY_DISCOVERY)?;
+
+	let keys = SessionKeys { grandpa, babe, im_online, authority_discovery };
+	let seeds = vec![
+		(key_types::GRANDPA, grandpa_seed),
+		(key_types::BABE, babe_seed),
+		(key_types::IM_ONLINE, im_online_seed),
+		(key_types::AUTHORITY_DISCOVERY, authority_discovery_seed),
+	];
+	Ok((keys, seeds))
+}
+
+/// Derives the session keys of `suri`.
+pub fn derive_session_keys(suri: &str) -> Result<SessionKeys> {
+	derive_with_seeds(suri).map(|(keys, _)| keys)
+}
+
+/// Derives the session keys of `suri` and inserts each with `insert`, along the seed of its pair
+/// so the key files never hold `suri` itself.
+pub(crate) fn provision(
+	suri: &str,
+	mut insert: impl FnMut(KeyTypeId, &str, &[u8]) -> std::result::Result<(), String>,
+) -> Result<SessionKeys> {
+	let (keys, seeds) = derive_with_seeds(suri)?;
+	for ((public, key_type), (_, seed)) in keys.clone().into_raw_public_keys().into_iter().zip(seeds) {
+		insert(key_type, &seed, &public)
+			.map_err(|e| format!("Failed to insert the {} key: {}", key_name(key_type), e))?;
+	}
+	Ok(keys)
+}
+
+/// The session keys of `keys` missing from `keystore`, by name.
+fn missing_keys(keystore: &KeyStorePtr, keys: &SessionKeys) -> Vec<&'static str> {
+	let keystore = keystore.read();
+	keys.clone().into_raw_public_keys().into_iter()
+		.filter(|(public, key_type)| !keystore.has_keys(&[(public.clone(), *key_type)]))
+		.map(|(_, key_type)| key_name(key_type))
+		.collect()
+}
+
+/// The storage key of the session keys of `stash` in `Session::NextKeys`.
+fn next_keys_key(stash: &AccountId) -> StorageKey {
+	let stash = stash.encode();
+	StorageKey([&twox_128(b"Session")[..], &twox_128(b"NextKeys"), &twox_64(&stash), &stash].concat())
+}
+
+fn print_keys(keys: &SessionKeys) {
+	println!("Session keys: 0x{}", HexDisplay::from(&keys.encode()));
+	for (public, key_type) in keys.clone().into_raw_public_keys() {
+		println!("  {}: 0x{}", key_name(key_type), HexDisplay::from(&public));
+	}
+}
+
+impl ProvisionKeysCmd {
+	/// Inserts the session keys into the keystore of `config`, and checks them against the
+	/// chain of `client` when verifying.
+	pub fn run<C>(&self, config: &Configuration, client: Option<&C>) -> Result<()> where
+		C: HeaderBackend<Block> + StorageProvider<Block, TFullBackend<Block>>,
+	{
+		let (path, password) = match &config.keystore {
+			KeystoreConfig::Path { path, password } => (path, password),
+			KeystoreConfig::InMemory => return Err("The keystore must be on disk".into()),
+		};
+		// The password encrypts the files of an encrypted keystore.
+		let encryption = match password {
+			Some(password) if self.keystore_encrypted => Some(password.as_str()),
+			None if self.keystore_encrypted => return Err("The encrypted keystore needs a password".into()),
+			_ => None,
+		};
+		let open = || -> Result<KeyStorePtr> {
+			match encryption {
+				Some(password) => encrypted::load(path, password).map_err(|e| e.to_string().into()),
+				None => Store::open(path.clone(), password.clone())
+					.map_err(|e| format!("Failed to open the keystore at {}: {:?}", path.display(), e).into()),
+			}
+		};
+
+		let provisioned = match &self.suri {
+			Some(suri) => {
+				let keys = match encryption {
+					Some(password) => provision(suri, |key_type, seed, public| {
+						encrypted::insert(path, password, key_type, seed, public).map_err(|e| e.to_string())
+					})?,
+					None => {
+						let keystore = open()?;
+						let mut keystore = keystore.write();
+						provision(suri, |key_type, seed, public| {
+							keystore.insert_unknown(key_type, seed, public).map_err(|_| "cannot write to the keystore".into())
+						})?
+					},
+				};
+				print_keys(&keys);
+				println!("Register them with `session.setKeys(<keys>, 0x00)` from the controller account.");
+				Some(keys)
+			},
+			None if self.verify.is_none() =>
+				return Err("Pass `--suri` to provision keys or `--verify` to check them".into()),
+			None => None,
+		};
+
+		let stash = match &self.verify {
+			Some(stash) => AccountId::from_ss58check(stash)
+				.map_err(|_| Error::Input(format!("`{}` is not an address", stash)))?,
+			None => return Ok(()),
+		};
+		let client = client.ok_or("Verifying needs the chain database")?;
+		let best = client.info().best_hash;
+		let registered = client.storage(&BlockId::Hash(best), &next_keys_key(&stash))?
+			.ok_or_else(|| format!("{} has no session keys in Session::NextKeys", stash))?;
+		let registered = SessionKeys::decode(&mut &registered.0[..])
+			.map_err(|e| format!("Invalid session keys in Session::NextKeys: {}", e))?;
+
+		println!("Registered for {} at block {}:", stash, best);
+		print_keys(&registered);
+
+		if provisioned.as_ref().map_or(false, |keys| keys != &registered) {
+			return Err("The keys derived from `--suri` are not the registered ones".into());
+		}
+		match missing_keys(&open()?, &registered).as_slice() {
+			[] => {
+				println!("The keystore holds every registered key.");
+				Ok(())
+			},
+			missing => Err(format!("The keystore lacks the {} keys", missing.join(", ")).into()),
+		}
+	}
+}
+
+impl EncryptKeysCmd {
+	/// Encrypts the key files of the keystore of `config` with its password.
+	pub fn run(&self, config: &Configuration) -> Result<()> {
+		let (path, password) = match &config.keystore {
+			KeystoreConfig::Path { path, password: Some(password) } => (path, password),
+			KeystoreConfig::Path { password: None, .. } =>
+				return Err("Pass the password to encrypt with, see `--password-interactive`".into()),
+			KeystoreConfig::InMemory => return Err("The keystore must be on disk".into()),
+		};
+
+		let encrypted = encrypted::encrypt_dir(path, password.as_str()).map_err(|e| e.to_string())?;
+		println!("Encrypted {} key files in {}.", encrypted, path.display());
+		println!("Start the node with `--keystore-encrypted` and the same password to use them.");
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const PHRASE: &str = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+	#[test]
+	fn keys_are_derived_per_key_type() {
+		let keys = derive_session_keys(PHRASE).unwrap();
+
+		assert_eq!(keys, derive_session_keys(PHRASE).unwrap());
+		assert_eq!(
+			keys.babe,
+			sp_core::sr25519::Pair::from_string(&format!("{}//babe", PHRASE), None).unwrap().public().into(),
+		);
+		assert_ne!(keys.babe.encode(), keys.im_online.encode());
+		assert_ne!(keys.im_online.encode(), keys.authority_discovery.encode());
+		assert!(derive_session_keys("not a secret").is_err());
+	}
+
+	#[test]
+	fn provisioned_keys_are_found_in_the_keystore() {
+		let dir = tempfile::tempdir().unwrap();
+		let keystore = Store::open(dir.path(), None).unwrap();
+
+		let other = derive_session_keys(&format!("{}//other", PHRASE)).unwrap();
+		assert_eq!(missing_keys(&keystore, &other).len(), 4);
+
+		let keys = provision(PHRASE, |key_type, suri, public| {
+			keystore.write().insert_unknown(key_type, suri, public).map_err(|_| "Failed".to_string())
+		}).unwrap();
+		assert!(missing_keys(&keystore, &keys).is_empty());
+		assert_eq!(missing_keys(&keystore, &other), vec!["grandpa", "babe", "im_online", "authority_discovery"]);
+
+		// The keys are stored on disk.
+		let reopened = Store::open(dir.path(), None).unwrap();
+		assert!(missing_keys(&reopened, &keys).is_empty());
+	}
+
+	#[test]
+	fn key_files_hold_the_seeds_of_the_derived_pairs() {
+		let dir = tempfile::tempdir().unwrap();
+		let keystore = Store::open(dir.path(), None).unwrap();
+		let keys = provision(PHRASE, |key_type, seed, public| {
+			keystore.write().insert_unknown(key_type, seed, public).map_err(|_| "Failed".to_string())
+		}).unwrap();
+
+		for entry in std::fs::read_dir(dir.path()).unwrap() {
+			let content = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+			assert!(content.starts_with("\"0x"));
+			assert!(!content.contains("bottom"));
+		}
+
+		let babe: sp_core::sr25519::Public = keys.babe.into();
+		let pair = keystore.read().key_pair_by_type::<sp_core::sr25519::Pair>(&babe, key_types::BABE).unwrap();
+		assert_eq!(pair.public(), babe);
+		assert!(derive_session_keys(&format!("{}/soft", PHRASE)).is_err());
+	}
+
+	#[test]
+	fn provisioned_keys_can_be_encrypted() {
+		let dir = tempfile::tempdir().unwrap();
+
+		let keys = provision(PHRASE, |key_type, suri, public| {
+			encrypted::insert(dir.path(), "password", key_type, suri, public).map_err(|e| e.to_string())
+		}).unwrap();
+
+		let keystore = encrypted::load(dir.path(), "password").unwrap();
+		assert!(missing_keys(&keystore, &keys).is_empty());
+		assert_eq!(missing_keys(&Store::open(dir.path(), None).unwrap(), &keys).len(), 4);
+	}
+}
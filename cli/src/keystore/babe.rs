@@ -0,0 +1,401 @@
+//! BABE block authoring signing through any keystore.
+//!
+//! The BABE worker of Substrate 2.0 only signs with the Substrate keystore, so nodes with a
+//! remote signer author their blocks with this worker instead. It claims slots and seals blocks
+//! as the one of Substrate does, asking the keystore for the VRF signatures of the primary slots
+//! and the signature of the seal.
+
+use codec::Encode;
+use futures::TryFutureExt;
+use node_primitives::Block;
+use parking_lot::Mutex;
+use sc_client_api::{backend::AuxStore, BlockchainEvents, ProvideUncles};
+use sc_consensus_babe::{BabeIntermediate, BabeParams, Epoch, INTERMEDIATE_KEY};
+use sc_consensus_epochs::{descendent_query, SharedEpochChanges, ViableEpochDescriptor};
+use sc_consensus_slots::{SimpleSlotWorker, SlotCompatible, SlotInfo, SlotWorker, StorageChanges};
+use sp_api::{NumberFor, ProvideRuntimeApi};
+use sp_blockchain::{HeaderBackend, HeaderMetadata, ProvideCache};
+use sp_consensus::{
+	BlockImport, BlockImportParams, BlockOrigin, CanAuthorWith, Environment, Error as ConsensusError,
+	Proposer, SelectChain, SyncOracle,
+};
+use sp_consensus_babe::{
+	digests::{CompatibleDigestItem, PreDigest, PrimaryPreDigest, SecondaryPlainPreDigest, SecondaryVRFPreDigest},
+	inherents::BabeInherentData,
+	AuthorityId, AuthoritySignature, BabeApi, BabeAuthorityWeight, BabeGenesisConfiguration, BABE_ENGINE_ID,
+	BABE_VRF_PREFIX,
+};
+use sp_core::{
+	blake2_256,
+	crypto::{CryptoTypePublicPair, KeyTypeId, Public},
+	sr25519,
+	traits::{BareCryptoStore, BareCryptoStorePtr},
+	vrf::{make_transcript, VRFTranscriptData, VRFTranscriptValue},
+	U256,
+};
+use sp_consensus_vrf::schnorrkel::{VRFOutput, VRFProof};
+use sp_inherents::InherentData;
+use sp_runtime::{traits::{Block as BlockT, Header as _}, DigestItem};
+use sp_timestamp::TimestampInherentData;
+use std::{any::Any, borrow::Cow, convert::TryFrom, pin::Pin, sync::Arc, time::Duration};
+
+type Hash = <Block as BlockT>::Hash;
+type Header = <Block as BlockT>::Header;
+
+/// The key type of BABE keys.
+const BABE: KeyTypeId = sp_core::crypto::key_types::BABE;
+
+/// The VRF transcript of `slot_number` in the epoch `epoch_index` with `randomness`.
+fn transcript_data(randomness: &[u8; 32], slot_number: u64, epoch_index: u64) -> VRFTranscriptData {
+	VRFTranscriptData {
+		label: &BABE_ENGINE_ID,
+		items: vec![
+			("slot number", VRFTranscriptValue::U64(slot_number)),
+			("current epoch", VRFTranscriptValue::U64(epoch_index)),
+			("chain randomness", VRFTranscriptValue::Bytes(randomness.to_vec())),
+		],
+	}
+}
+
+/// The threshold a VRF output must be below to claim a primary slot, for the authority of
+/// `weight` in `authorities`.
+fn primary_threshold(c: (u64, u64), authorities: &[(AuthorityId, BabeAuthorityWeight)], weight: u64) -> u128 {
+	let c = c.0 as f64 / c.1 as f64;
+	let theta = weight as f64 / authorities.iter().map(|(_, weight)| weight).sum::<u64>() as f64;
+	// Scaling by a power of two is exact, so this floors `p * 2^128` like Substrate does.
+	((1f64 - (1f64 - c).powf(theta)) * 2f64.powi(128)) as u128
+}
+
+/// The authority expected to author the secondary slot `slot_number`.
+fn secondary_slot_author(
+	slot_number: u64,
+	authorities: &[(AuthorityId, BabeAuthorityWeight)],
+	randomness: [u8; 32],
+) -> Option<&AuthorityId> {
+	if authorities.is_empty() {
+		return None;
+	}
+	let rand = U256::from((randomness, slot_number).using_encoded(blake2_256));
+	let index = rand % U256::from(authorities.len());
+	authorities.get(index.as_u32() as usize).map(|(authority, _)| authority)
+}
+
+/// Makes the VRF signature of `slot_number` with `authority`, `None` if the keystore fails to.
+fn vrf_sign(
+	keystore: &BareCryptoStorePtr,
+	authority: &AuthorityId,
+	epoch: &Epoch,
+	slot_number: u64,
+) -> Option<(sp_core::vrf::VRFSignature, schnorrkel::vrf::VRFInOut)> {
+	let public: &sr25519::Public = authority.as_ref();
+	let data = transcript_data(&epoch.randomness, slot_number, epoch.epoch_index);
+	let signature = keystore.read().sr25519_vrf_sign(BABE, public, data.clone())
+		.map_err(|e| log::debug!(target: "babe", "VRF signature of slot {} failed: {:?}", slot_number, e))
+		.ok()?;
+	let public = schnorrkel::PublicKey::from_bytes(&public.0).ok()?;
+	let inout = signature.output.attach_input_hash(&public, make_transcript(data)).ok()?;
+	Some((signature, inout))
+}
+
+/// Claims `slot_number` of `epoch` with the BABE keys of `keystore`, as a primary slot if a VRF
+/// output is below the threshold, else as a secondary one if the epoch allows them.
+fn claim_slot(slot_number: u64, epoch: &Epoch, keystore: &BareCryptoStorePtr) -> Option<(PreDigest, AuthorityId)> {
+	let held = keystore.read().sr25519_public_keys(BABE).into_iter().map(AuthorityId::from).collect::<Vec<_>>();
+	let keys = epoch.authorities.iter().enumerate()
+		.filter(|(_, (authority, _))| held.contains(authority))
+		.collect::<Vec<_>>();
+	if keys.is_empty() {
+		return None;
+	}
+
+	for (index, (authority, weight)) in &keys {
+		let threshold = primary_threshold(epoch.config.c, &epoch.authorities, *weight);
+		let (signature, inout) = match vrf_sign(keystore, authority, epoch, slot_number) {
+			Some(signed) => signed,
+			None => continue,
+		};
+		if u128::from_le_bytes(inout.make_bytes::<[u8; 16]>(BABE_VRF_PREFIX)) < threshold {
+			let digest = PreDigest::Primary(PrimaryPreDigest {
+				slot_number,
+				vrf_output: VRFOutput(signature.output),
+				vrf_proof: VRFProof(signature.proof),
+				authority_index: *index as u32,
+			});
+			return Some((digest, authority.clone()));
+		}
+	}
+
+	let allowed = &epoch.config.allowed_slots;
+	if !allowed.is_secondary_plain_slots_allowed() && !allowed.is_secondary_vrf_slots_allowed() {
+		return None;
+	}
+	let expected = secondary_slot_author(slot_number, &epoch.authorities, epoch.randomness)?;
+	let (index, (authority, _)) = keys.into_iter().find(|(_, (authority, _))| authority == expected)?;
+	let digest = if allowed.is_secondary_vrf_slots_allowed() {
+		let (signature, _) = vrf_sign(keystore, authority, epoch, slot_number)?;
+		PreDigest::SecondaryVRF(SecondaryVRFPreDigest {
+			slot_number,
+			vrf_output: VRFOutput(signature.output),
+			vrf_proof: VRFProof(signature.proof),
+			authority_index: index as u32,
+		})
+	} else {
+		PreDigest::SecondaryPlain(SecondaryPlainPreDigest { slot_number, authority_index: index as u32 })
+	};
+	Some((digest, authority.clone()))
+}
+
+/// Reads the timestamp and slot number from the inherent data, as BABE does.
+struct TimeSource;
+
+impl SlotCompatible for TimeSource {
+	fn extract_timestamp_and_slot(&self, data: &InherentData) -> Result<(u64, u64, Duration), ConsensusError> {
+		let timestamp = data.timestamp_inherent_data().map_err(ConsensusError::InherentData)?;
+		let slot_number = data.babe_inherent_data().map_err(ConsensusError::InherentData)?;
+		Ok((timestamp, slot_number, Duration::default()))
+	}
+}
+
+struct BabeSlotWorker<C, E, I, SO> {
+	client: Arc<C>,
+	block_import: Arc<Mutex<I>>,
+	env: E,
+	sync_oracle: SO,
+	force_authoring: bool,
+	keystore: BareCryptoStorePtr,
+	epoch_changes: SharedEpochChanges<Block, Epoch>,
+	config: BabeGenesisConfiguration,
+}
+
+impl<C, E, I, Error, SO> SimpleSlotWorker<Block> for BabeSlotWorker<C, E, I, SO> where
+	C: ProvideRuntimeApi<Block> + ProvideCache<Block> + HeaderBackend<Block>,
+	C: HeaderMetadata<Block, Error = sp_blockchain::Error>,
+	C::Api: BabeApi<Block>,
+	E: Environment<Block, Error = Error>,
+	E::Proposer: Proposer<Block, Error = Error, Transaction = sp_api::TransactionFor<C, Block>>,
+	I: BlockImport<Block, Transaction = sp_api::TransactionFor<C, Block>> + Send + Sync + 'static,
+	SO: SyncOracle + Send + Clone,
+	Error: std::error::Error + Send + From<ConsensusError> + From<I::Error> + 'static,
+{
+	type EpochData = ViableEpochDescriptor<Hash, NumberFor<Block>, Epoch>;
+	type Claim = (PreDigest, AuthorityId);
+	type SyncOracle = SO;
+	type CreateProposer = Pin<Box<
+		dyn std::future::Future<Output = Result<E::Proposer, ConsensusError>> + Send + 'static
+	>>;
+	type Proposer = E::Proposer;
+	type BlockImport = I;
+
+	fn logging_target(&self) -> &'static str {
+		"babe"
+	}
+
+	fn block_import(&self) -> Arc<Mutex<Self::BlockImport>> {
+		self.block_import.clone()
+	}
+
+	fn epoch_data(&self, parent: &Header, slot_number: u64) -> Result<Self::EpochData, ConsensusError> {
+		self.epoch_changes.lock().epoch_descriptor_for_child_of(
+			descendent_query(&*self.client),
+			&parent.hash(),
+			*parent.number(),
+			slot_number,
+		)
+			.map_err(|e| ConsensusError::ChainLookup(format!("{:?}", e)))?
+			.ok_or(ConsensusError::InvalidAuthoritiesSet)
+	}
+
+	fn authorities_len(&self, epoch_descriptor: &Self::EpochData) -> Option<usize> {
+		self.epoch_changes.lock()
+			.viable_epoch(epoch_descriptor, |slot| Epoch::genesis(&self.config, slot))
+			.map(|epoch| epoch.as_ref().authorities.len())
+	}
+
+	fn claim_slot(&self, _parent: &Header, slot_number: u64, epoch_descriptor: &Self::EpochData) -> Option<Self::Claim> {
+		let epoch = self.epoch_changes.lock()
+			.viable_epoch(epoch_descriptor, |slot| Epoch::genesis(&self.config, slot))?
+			.as_ref()
+			.clone();
+		claim_slot(slot_number, &epoch, &self.keystore)
+	}
+
+	fn pre_digest_data(&self, _slot_number: u64, claim: &Self::Claim) -> Vec<DigestItem<Hash>> {
+		vec![<DigestItem<Hash> as CompatibleDigestItem>::babe_pre_digest(claim.0.clone())]
+	}
+
+	fn block_import_params(&self) -> Box<dyn Fn(
+		Header,
+		&Hash,
+		Vec<<Block as BlockT>::Extrinsic>,
+		StorageChanges<I::Transaction, Block>,
+		Self::Claim,
+		Self::EpochData,
+	) -> Result<BlockImportParams<Block, I::Transaction>, ConsensusError> + Send + 'static> {
+		let keystore = self.keystore.clone();
+		Box::new(move |header, header_hash, body, storage_changes, (_, public), epoch_descriptor| {
+			// The seal signs the hash of the header without it.
+			let public = public.to_raw_vec();
+			let signature = keystore.read()
+				.sign_with(BABE, &CryptoTypePublicPair(sr25519::CRYPTO_ID, public.clone()), header_hash.as_ref())
+				.map_err(|e| ConsensusError::CannotSign(public.clone(), format!("{:?}", e)))?;
+			let signature = AuthoritySignature::try_from(signature.clone())
+				.map_err(|_| ConsensusError::InvalidSignature(signature, public))?;
+
+			let mut import_block = BlockImportParams::new(BlockOrigin::Own, header);
+			import_block.post_digests.push(<DigestItem<Hash> as CompatibleDigestItem>::babe_seal(signature));
+			import_block.body = Some(body);
+			import_block.storage_changes = Some(storage_changes);
+			import_block.intermediates.insert(
+				Cow::from(INTERMEDIATE_KEY),
+				Box::new(BabeIntermediate::<Block> { epoch_descriptor }) as Box<dyn Any>,
+			);
+			Ok(import_block)
+		})
+	}
+
+	fn force_authoring(&self) -> bool {
+		self.force_authoring
+	}
+
+	fn sync_oracle(&mut self) -> &mut Self::SyncOracle {
+		&mut self.sync_oracle
+	}
+
+	fn proposer(&mut self, block: &Header) -> Self::CreateProposer {
+		Box::pin(self.env.init(block).map_err(|e| ConsensusError::ClientImport(format!("{:?}", e))))
+	}
+}
+
+impl<C, E, I, Error, SO> SlotWorker<Block> for BabeSlotWorker<C, E, I, SO> where
+	C: ProvideRuntimeApi<Block> + ProvideCache<Block> + HeaderBackend<Block>,
+	C: HeaderMetadata<Block, Error = sp_blockchain::Error> + Send + Sync,
+	C::Api: BabeApi<Block>,
+	E: Environment<Block, Error = Error> + Send + Sync,
+	E::Proposer: Proposer<Block, Error = Error, Transaction = sp_api::TransactionFor<C, Block>>,
+	I: BlockImport<Block, Transaction = sp_api::TransactionFor<C, Block>> + Send + Sync + 'static,
+	SO: SyncOracle + Send + Sync + Clone,
+	Error: std::error::Error + Send + From<ConsensusError> + From<I::Error> + 'static,
+{
+	type OnSlot = Pin<Box<dyn std::future::Future<Output = Result<(), ConsensusError>> + Send>>;
+
+	fn on_slot(&mut self, chain_head: Header, slot_info: SlotInfo) -> Self::OnSlot {
+		<Self as SimpleSlotWorker<Block>>::on_slot(self, chain_head, slot_info)
+	}
+}
+
+/// Starts authoring blocks with the parameters of `sc_consensus_babe::start_babe`, signing with
+/// `keystore` rather than with the Substrate keystore of `params`.
+pub fn start_babe<C, SC, E, I, SO, CAW, Error>(
+	keystore: BareCryptoStorePtr,
+	params: BabeParams<Block, C, E, I, SO, SC, CAW>,
+) -> Result<impl std::future::Future<Output = ()>, ConsensusError> where
+	C: ProvideRuntimeApi<Block> + ProvideCache<Block> + ProvideUncles<Block> + BlockchainEvents<Block>,
+	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = sp_blockchain::Error> + AuxStore,
+	C: Send + Sync + 'static,
+	C::Api: BabeApi<Block, Error = sp_blockchain::Error>,
+	SC: SelectChain<Block> + 'static,
+	E: Environment<Block, Error = Error> + Send + Sync + 'static,
+	E::Proposer: Proposer<Block, Error = Error, Transaction = sp_api::TransactionFor<C, Block>>,
+	I: BlockImport<Block, Error = ConsensusError, Transaction = sp_api::TransactionFor<C, Block>>,
+	I: Send + Sync + 'static,
+	Error: std::error::Error + Send + From<ConsensusError> + From<I::Error> + 'static,
+	SO: SyncOracle + Send + Sync + Clone + 'static,
+	CAW: CanAuthorWith<Block> + Send + 'static,
+{
+	let BabeParams {
+		keystore: _,
+		client,
+		select_chain,
+		env,
+		block_import,
+		sync_oracle,
+		inherent_data_providers,
+		force_authoring,
+		babe_link,
+		can_author_with,
+	} = params;
+
+	// The BABE inherent data provider is registered by the import queue, the uncles one here as
+	// `sc_consensus_babe::start_babe` does.
+	sc_consensus_uncles::register_uncles_inherent_data_provider(
+		client.clone(),
+		select_chain.clone(),
+		&inherent_data_providers,
+	)?;
+
+	let slot_duration = sc_consensus_slots::SlotDuration::get_or_compute(&*client, |api, at| api.configuration(at))
+		.map_err(|e| ConsensusError::ChainLookup(e.to_string()))?;
+
+	let worker = BabeSlotWorker {
+		client,
+		block_import: Arc::new(Mutex::new(block_import)),
+		env,
+		sync_oracle: sync_oracle.clone(),
+		force_authoring,
+		keystore,
+		epoch_changes: babe_link.epoch_changes().clone(),
+		config: (**babe_link.config()).clone(),
+	};
+
+	log::info!(target: "babe", "👶 Starting BABE Authorship worker signing through the remote signer");
+	Ok(sc_consensus_slots::start_slot_worker(
+		slot_duration,
+		select_chain,
+		worker,
+		sync_oracle,
+		inherent_data_providers,
+		TimeSource,
+		can_author_with,
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_consensus_babe::AllowedSlots;
+	use sp_core::crypto::Pair;
+
+	fn epoch(authorities: Vec<AuthorityId>, allowed_slots: AllowedSlots) -> Epoch {
+		Epoch {
+			epoch_index: 0,
+			start_slot: 0,
+			duration: 100,
+			authorities: authorities.into_iter().map(|authority| (authority, 1)).collect(),
+			randomness: [0; 32],
+			config: sp_consensus_babe::BabeEpochConfiguration { c: (1, 4), allowed_slots },
+		}
+	}
+
+	#[test]
+	fn slots_are_claimed_with_the_keys_of_the_keystore() {
+		let keystore = sc_keystore::Store::new_in_memory();
+		let alice = AuthorityId::from(keystore.write().sr25519_generate_new(BABE, Some("//Alice")).unwrap());
+		let bob = AuthorityId::from(sr25519::Pair::from_string("//Bob", None).unwrap().public());
+		let keystore: BareCryptoStorePtr = keystore;
+
+		let epoch = epoch(vec![alice.clone(), bob], AllowedSlots::PrimaryAndSecondaryPlainSlots);
+		for slot in 0..20 {
+			let claim = claim_slot(slot, &epoch, &keystore);
+			if let Some((digest, authority)) = &claim {
+				assert_eq!(authority, &alice);
+				assert_eq!(digest.authority_index(), 0);
+			}
+			// The secondary slots of Alice are always claimed, the others only as primary ones.
+			let secondary_author = secondary_slot_author(slot, &epoch.authorities, epoch.randomness);
+			if secondary_author == Some(&alice) {
+				assert!(claim.is_some());
+			}
+			if let Some((PreDigest::SecondaryPlain(_), _)) = &claim {
+				assert_eq!(secondary_author, Some(&alice));
+			}
+		}
+	}
+
+	#[test]
+	fn primary_threshold_grows_with_the_weight() {
+		let authorities = vec![(sr25519::Public::from_raw([1; 32]).into(), 1), (sr25519::Public::from_raw([2; 32]).into(), 3)];
+
+		assert!(primary_threshold((1, 4), &authorities, 1) < primary_threshold((1, 4), &authorities, 3));
+		assert_eq!(primary_threshold((1, 1), &authorities[..1], 1), u128::MAX);
+	}
+}
@@ -0,0 +1,303 @@
+//! Password-encrypted key files.
+//!
+//! The keystore directory keeps the layout of the Substrate keystore, one file per key named
+//! after its key type and public key, but each file holds the secret URI encrypted with
+//! ChaCha20-Poly1305 under a key derived from the password with PBKDF2-HMAC-SHA512. The keys
+//! are decrypted when the node starts and only kept in memory, and the keys the node is given
+//! while it runs are written through a [`Writer`].
+
+use chacha20poly1305::{aead::{Aead, NewAead}, ChaCha20Poly1305, Key, Nonce};
+use hmac::Hmac;
+use rand::RngCore;
+use sc_keystore::{KeyStorePtr, Store};
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+use sp_core::{bytes, crypto::{KeyTypeId, Protected}, ecdsa, ed25519, hexdisplay::HexDisplay, sr25519, Bytes, Pair};
+use std::{fs, io, path::{Path, PathBuf}};
+
+/// Version of the format of the encrypted files.
+const VERSION: u32 = 1;
+/// Length of the nonces of ChaCha20-Poly1305.
+const NONCE_LEN: usize = 12;
+/// PBKDF2 iterations deriving the encryption key from the password.
+const ROUNDS: u32 = 100_000;
+
+/// The content of an encrypted key file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EncryptedKey {
+	/// Version of the format.
+	pub version: u32,
+	/// Salt of the key derivation.
+	pub salt: Bytes,
+	/// Nonce of the encryption.
+	pub nonce: Bytes,
+	/// The encrypted secret URI, with its authentication tag.
+	pub ciphertext: Bytes,
+}
+
+/// Errors of the encrypted keystore.
+#[derive(Debug)]
+pub enum Error {
+	/// A file could not be read or written.
+	Io(io::Error),
+	/// A file is not a key file.
+	InvalidFile(PathBuf, String),
+	/// The password does not decrypt a key.
+	WrongPassword(PathBuf),
+	/// A secret does not derive the public key it is inserted with.
+	InvalidKey(String),
+}
+
+impl From<io::Error> for Error {
+	fn from(e: io::Error) -> Self {
+		Error::Io(e)
+	}
+}
+
+impl std::fmt::Display for Error {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Error::Io(e) => write!(f, "{}", e),
+			Error::InvalidFile(path, e) => write!(f, "Invalid key file {}: {}", path.display(), e),
+			Error::WrongPassword(path) => write!(f, "Wrong password for {}", path.display()),
+			Error::InvalidKey(e) => write!(f, "Invalid key: {}", e),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+fn cipher(password: &str, salt: &[u8]) -> ChaCha20Poly1305 {
+	let mut key = [0u8; 32];
+	pbkdf2::pbkdf2::<Hmac<Sha512>>(password.as_bytes(), salt, ROUNDS, &mut key);
+	ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+impl EncryptedKey {
+	/// Encrypts `suri` with `password`.
+	pub fn encrypt(suri: &str, password: &str) -> Self {
+		let mut salt = [0u8; 32];
+		let mut nonce = [0u8; NONCE_LEN];
+		rand::thread_rng().fill_bytes(&mut salt);
+		rand::thread_rng().fill_bytes(&mut nonce);
+
+		let ciphertext = cipher(password, &salt)
+			.encrypt(Nonce::from_slice(&nonce), suri.as_bytes())
+			.expect("Encrypting in memory does not fail; qed");
+
+		EncryptedKey {
+			version: VERSION,
+			salt: salt.to_vec().into(),
+			nonce: nonce.to_vec().into(),
+			ciphertext: ciphertext.into(),
+		}
+	}
+
+	/// Checks that the key is of the supported version and its nonce is well formed.
+	fn check(&self) -> Result<(), String> {
+		if self.version != VERSION {
+			return Err(format!("unsupported version {}, expected {}", self.version, VERSION));
+		}
+		if self.nonce.len() != NONCE_LEN {
+			return Err(format!("nonce of {} bytes, expected {}", self.nonce.len(), NONCE_LEN));
+		}
+		Ok(())
+	}
+
+	/// Decrypts the secret URI with `password`, `None` if it is not the right one.
+	///
+	/// Fails with [`Error::InvalidFile`] naming `path` if the key is malformed.
+	pub fn decrypt(&self, path: &Path, password: &str) -> Result<Option<String>, Error> {
+		self.check().map_err(|e| Error::InvalidFile(path.into(), e))?;
+		let suri = cipher(password, &self.salt)
+			.decrypt(Nonce::from_slice(&self.nonce), &self.ciphertext[..])
+			.ok();
+		Ok(suri.and_then(|suri| String::from_utf8(suri).ok()))
+	}
+}
+
+/// What a file of the keystore holds.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyFile {
+	/// A secret URI, as written by the Substrate keystore.
+	Plain(String),
+	Encrypted(EncryptedKey),
+}
+
+/// The path of the file of a key, as named by the Substrate keystore.
+fn key_file(dir: &Path, key_type: KeyTypeId, public: &[u8]) -> PathBuf {
+	dir.join(format!("{}{}", HexDisplay::from(&key_type.0), HexDisplay::from(&public)))
+}
+
+/// The key type and public key a file is named after.
+fn parse_name(path: &Path) -> Option<(KeyTypeId, Vec<u8>)> {
+	let name = bytes::from_hex(&format!("0x{}", path.file_name()?.to_str()?)).ok()?;
+	if name.len() <= 4 {
+		return None;
+	}
+	let mut key_type = [0u8; 4];
+	key_type.copy_from_slice(&name[..4]);
+	Some((KeyTypeId(key_type), name[4..].to_vec()))
+}
+
+/// The key files of `dir`, with their key type, public key and content.
+fn key_files(dir: &Path) -> Result<Vec<(PathBuf, KeyTypeId, Vec<u8>, KeyFile)>, Error> {
+	let mut files = Vec::new();
+	if !dir.exists() {
+		return Ok(files);
+	}
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+		let (key_type, public) = match parse_name(&path) {
+			Some(name) if path.is_file() => name,
+			_ => continue,
+		};
+		let file = serde_json::from_slice(&fs::read(&path)?)
+			.map_err(|e| Error::InvalidFile(path.clone(), e.to_string()))?;
+		files.push((path, key_type, public, file));
+	}
+	Ok(files)
+}
+
+/// Adds the key of `suri` to a keystore held in memory, with the crypto of `public`.
+fn insert_in_memory(keystore: &mut Store, key_type: KeyTypeId, suri: &str, public: &[u8]) -> Result<(), String> {
+	fn matches<P: Pair>(suri: &str, public: &[u8]) -> bool {
+		P::from_string(suri, None).map_or(false, |pair| pair.public().as_ref() == public)
+	}
+
+	let inserted = if matches::<sr25519::Pair>(suri, public) {
+		keystore.insert_ephemeral_from_seed_by_type::<sr25519::Pair>(suri, key_type).map(drop)
+	} else if matches::<ed25519::Pair>(suri, public) {
+		keystore.insert_ephemeral_from_seed_by_type::<ed25519::Pair>(suri, key_type).map(drop)
+	} else if matches::<ecdsa::Pair>(suri, public) {
+		keystore.insert_ephemeral_from_seed_by_type::<ecdsa::Pair>(suri, key_type).map(drop)
+	} else {
+		return Err("the secret does not derive the public key the file is named after".into());
+	};
+	inserted.map_err(|e| format!("{:?}", e))
+}
+
+/// Writes an encrypted key file for `suri` into `dir`.
+pub fn insert(dir: &Path, password: &str, key_type: KeyTypeId, suri: &str, public: &[u8]) -> Result<(), Error> {
+	fs::create_dir_all(dir)?;
+	let path = key_file(dir, key_type, public);
+	let temporary = path.with_extension("tmp");
+	fs::write(&temporary, serde_json::to_vec(&EncryptedKey::encrypt(suri, password)).expect("Serializes; qed"))?;
+	fs::rename(&temporary, &path)?;
+	Ok(())
+}
+
+/// Writes keys both as encrypted files of a keystore directory and into the keystore in memory
+/// the directory was loaded into, so they survive a restart.
+#[derive(Clone)]
+pub struct Writer {
+	dir: PathBuf,
+	password: Protected<String>,
+	keystore: KeyStorePtr,
+}
+
+impl Writer {
+	/// Creates a writer into `dir` and `keystore`, the one [`load`] returned for `dir`.
+	pub fn new(dir: PathBuf, password: Protected<String>, keystore: KeyStorePtr) -> Self {
+		Writer { dir, password, keystore }
+	}
+
+	/// Adds the key of `suri` to the keystore in memory and writes its encrypted file.
+	pub fn insert(&self, key_type: KeyTypeId, suri: &str, public: &[u8]) -> Result<(), Error> {
+		insert_in_memory(&mut self.keystore.write(), key_type, suri, public).map_err(Error::InvalidKey)?;
+		insert(&self.dir, self.password.as_str(), key_type, suri, public)
+	}
+}
+
+/// Encrypts the plain key files of `dir` in place, returning how many were encrypted.
+pub fn encrypt_dir(dir: &Path, password: &str) -> Result<usize, Error> {
+	let mut encrypted = 0;
+	for (_, key_type, public, file) in key_files(dir)? {
+		if let KeyFile::Plain(suri) = file {
+			insert(dir, password, key_type, &suri, &public)?;
+			encrypted += 1;
+		}
+	}
+	Ok(encrypted)
+}
+
+/// Decrypts the key files of `dir` into a keystore held in memory.
+///
+/// Plain key files are left out, so that no key is used unencrypted by mistake.
+pub fn load(dir: &Path, password: &str) -> Result<KeyStorePtr, Error> {
+	let keystore = Store::new_in_memory();
+	for (path, key_type, public, file) in key_files(dir)? {
+		match file {
+			KeyFile::Plain(_) => log::warn!(
+				"Skipping the unencrypted key file {}, encrypt it with `keys encrypt`",
+				path.display(),
+			),
+			KeyFile::Encrypted(key) => {
+				let suri = key.decrypt(&path, password)?.ok_or_else(|| Error::WrongPassword(path.clone()))?;
+				insert_in_memory(&mut keystore.write(), key_type, &suri, &public)
+					.map_err(|e| Error::InvalidFile(path.clone(), e))?;
+			},
+		}
+	}
+	Ok(keystore)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{crypto::key_types::GRANDPA, traits::BareCryptoStore};
+
+	#[test]
+	fn keys_are_decrypted_with_the_password_only() {
+		let key = EncryptedKey::encrypt("//Alice", "password");
+		let path = Path::new("key");
+
+		assert_eq!(key.decrypt(path, "password").unwrap(), Some("//Alice".into()));
+		assert_eq!(key.decrypt(path, "wrong").unwrap(), None);
+		assert_ne!(EncryptedKey::encrypt("//Alice", "password").ciphertext, key.ciphertext);
+	}
+
+	#[test]
+	fn malformed_keys_are_invalid_files() {
+		let key = EncryptedKey::encrypt("//Alice", "password");
+		let path = Path::new("key");
+
+		let short_nonce = EncryptedKey { nonce: key.nonce.0[..8].to_vec().into(), ..key.clone() };
+		assert!(matches!(short_nonce.decrypt(path, "password"), Err(Error::InvalidFile(..))));
+		let next_version = EncryptedKey { version: VERSION + 1, ..key };
+		assert!(matches!(next_version.decrypt(path, "password"), Err(Error::InvalidFile(..))));
+	}
+
+	#[test]
+	fn plain_keystores_are_encrypted_in_place() {
+		let dir = tempfile::tempdir().unwrap();
+		let public = ed25519::Pair::from_string("//Alice", None).unwrap().public();
+		Store::open(dir.path(), None).unwrap().write().insert_unknown(GRANDPA, "//Alice", &public.0).unwrap();
+
+		assert_eq!(encrypt_dir(dir.path(), "password").unwrap(), 1);
+		assert_eq!(encrypt_dir(dir.path(), "password").unwrap(), 0);
+		let file = fs::read_to_string(key_file(dir.path(), GRANDPA, &public.0)).unwrap();
+		assert!(!file.contains("Alice"));
+
+		let keystore = load(dir.path(), "password").unwrap();
+		assert_eq!(keystore.read().ed25519_public_keys(GRANDPA), vec![public]);
+		assert!(matches!(load(dir.path(), "wrong"), Err(Error::WrongPassword(_))));
+	}
+
+	#[test]
+	fn written_keys_are_kept_on_restart() {
+		let dir = tempfile::tempdir().unwrap();
+		let keystore = load(dir.path(), "password").unwrap();
+		let writer = Writer::new(dir.path().into(), "password".to_string().into(), keystore.clone());
+		let public = ed25519::Pair::from_string("//Alice", None).unwrap().public();
+
+		assert!(matches!(writer.insert(GRANDPA, "//Bob", &public.0), Err(Error::InvalidKey(_))));
+		writer.insert(GRANDPA, "//Alice", &public.0).unwrap();
+		assert_eq!(keystore.read().ed25519_public_keys(GRANDPA), vec![public]);
+
+		let reloaded = load(dir.path(), "password").unwrap();
+		assert_eq!(reloaded.read().ed25519_public_keys(GRANDPA), vec![public]);
+	}
+}
@@ -0,0 +1,55 @@
+//! Where the node keeps its keys and signs with them.
+//!
+//! The keystore is either the plain Substrate one or the [`encrypted`] one. With a remote
+//! signer, BABE, GRANDPA and ImOnline keys are held by a separate process the node asks to sign
+//! over a Unix socket, see [`remote`], and blocks are authored by the worker of [`babe`].
+
+pub mod babe;
+pub mod encrypted;
+pub mod remote;
+
+use crate::SigningParams;
+use parking_lot::RwLock;
+use sc_keystore::{KeyStorePtr, Store};
+use sc_service::config::KeystoreConfig;
+use sp_core::{crypto::key_types, traits::BareCryptoStorePtr};
+use std::sync::Arc;
+
+/// Opens the keystore of `config`, decrypting it if it is encrypted.
+pub fn open(config: &KeystoreConfig, params: &SigningParams) -> Result<KeyStorePtr, String> {
+	let keystore = match config {
+		KeystoreConfig::Path { path, password } if params.keystore_encrypted => {
+			let password = password.as_ref()
+				.ok_or("The encrypted keystore needs a password, see `--password-interactive`")?;
+			encrypted::load(path, password.as_str())
+				.map_err(|e| format!("Failed to open the encrypted keystore at {}: {}", path.display(), e))?
+		},
+		KeystoreConfig::Path { path, password } => Store::open(path.clone(), password.clone())
+			.map_err(|e| format!("Failed to open the keystore at {}: {:?}", path.display(), e))?,
+		KeystoreConfig::InMemory if params.keystore_encrypted =>
+			return Err("The encrypted keystore must be on disk".into()),
+		KeystoreConfig::InMemory => Store::new_in_memory(),
+	};
+	Ok(keystore)
+}
+
+/// Writes the keys given to the node while it runs into the encrypted keystore of `config`, which
+/// was opened into `keystore`. `None` unless the keystore is encrypted.
+pub fn writer(config: &KeystoreConfig, params: &SigningParams, keystore: KeyStorePtr) -> Option<encrypted::Writer> {
+	match config {
+		KeystoreConfig::Path { path, password: Some(password) } if params.keystore_encrypted =>
+			Some(encrypted::Writer::new(path.clone(), password.clone(), keystore)),
+		_ => None,
+	}
+}
+
+/// The keystore BABE, GRANDPA and ImOnline sign with: `local`, or the remote signer if any.
+pub fn signing(local: KeyStorePtr, params: &SigningParams) -> BareCryptoStorePtr {
+	match &params.remote_signer {
+		Some(socket) => {
+			let delegated = vec![key_types::BABE, key_types::GRANDPA, key_types::IM_ONLINE];
+			Arc::new(RwLock::new(remote::RemoteKeystore::new(local, socket.clone(), delegated)))
+		},
+		None => local,
+	}
+}
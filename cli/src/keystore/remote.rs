@@ -0,0 +1,525 @@
+//! Signing through a separate local process over a Unix socket.
+//!
+//! The node writes one JSON request per line and the signer answers each with one JSON line:
+//!
+//! | Request | Response |
+//! |---------|----------|
+//! | `{"method": "publicKeys", "keyType": "gran"}` | `{"publicKeys": [{"cryptoId": "ed25", "public": "0x…"}]}` |
+//! | `{"method": "sign", "keyType": "gran", "cryptoId": "ed25", "public": "0x…", "message": "0x…"}` | `{"signature": "0x…"}` |
+//! | `{"method": "vrfSign", "keyType": "babe", "public": "0x…", "transcript": {"label": "BABE", "items": [["slot number", {"u64": 1}]]}}` | `{"vrfSignature": {"output": "0x…", "proof": "0x…"}}` |
+//!
+//! Any request may be answered with `{"error": "…"}`. Key types and crypto ids are their four
+//! characters, such as `imon` and `sr25`. VRF signatures are only made of BABE transcripts.
+
+use sc_keystore::KeyStorePtr;
+use serde::{Deserialize, Serialize};
+use sp_core::{
+	crypto::{CryptoTypeId, CryptoTypePublicPair, KeyTypeId},
+	ecdsa, ed25519, sr25519,
+	traits::{BareCryptoStore, BareCryptoStoreError},
+	vrf::{VRFSignature, VRFTranscriptData, VRFTranscriptValue},
+	Bytes,
+};
+use std::{
+	convert::TryFrom,
+	io::{self, BufRead, BufReader, Write},
+	os::unix::{fs::{DirBuilderExt, PermissionsExt}, net::{UnixListener, UnixStream}},
+	path::{Path, PathBuf},
+	time::Duration,
+};
+
+/// How long the node waits for an answer of the signer.
+const TIMEOUT: Duration = Duration::from_secs(2);
+/// The label of the VRF transcripts of BABE.
+const BABE_LABEL: &[u8] = b"BABE";
+/// The items of the VRF transcripts of BABE.
+const BABE_ITEMS: &[&str] = &["slot number", "current epoch", "chain randomness"];
+
+/// A request of the node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "camelCase")]
+pub enum Request {
+	/// The public keys of a key type.
+	#[serde(rename_all = "camelCase")]
+	PublicKeys {
+		/// The key type.
+		key_type: String,
+	},
+	/// Signs a message with a key.
+	#[serde(rename_all = "camelCase")]
+	Sign {
+		/// The key type.
+		key_type: String,
+		/// The crypto of the key.
+		crypto_id: String,
+		/// The public key.
+		public: Bytes,
+		/// The message to sign.
+		message: Bytes,
+	},
+	/// Makes a VRF signature of a transcript with an sr25519 key.
+	#[serde(rename_all = "camelCase")]
+	VrfSign {
+		/// The key type.
+		key_type: String,
+		/// The public key.
+		public: Bytes,
+		/// The transcript to sign.
+		transcript: Transcript,
+	},
+}
+
+/// The transcript of a VRF signature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transcript {
+	/// The label of the transcript.
+	pub label: String,
+	/// The named items of the transcript, in order.
+	pub items: Vec<(String, TranscriptValue)>,
+}
+
+/// An item of a VRF transcript.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TranscriptValue {
+	/// Bytes appended as a message.
+	Bytes(Bytes),
+	/// A number appended as a `u64`.
+	U64(u64),
+}
+
+impl From<VRFTranscriptData> for Transcript {
+	fn from(data: VRFTranscriptData) -> Self {
+		Transcript {
+			label: String::from_utf8_lossy(data.label).into_owned(),
+			items: data.items.into_iter().map(|(name, value)| (name.into(), match value {
+				VRFTranscriptValue::Bytes(bytes) => TranscriptValue::Bytes(bytes.into()),
+				VRFTranscriptValue::U64(number) => TranscriptValue::U64(number),
+			})).collect(),
+		}
+	}
+}
+
+impl Transcript {
+	/// The transcript data to sign, only for the transcripts of BABE whose label and item names
+	/// are known statically.
+	fn into_babe_data(self) -> Result<VRFTranscriptData, String> {
+		if self.label.as_bytes() != BABE_LABEL {
+			return Err(format!("Only BABE transcripts are signed, not `{}`", self.label));
+		}
+		let items = self.items.into_iter().map(|(name, value)| {
+			let name = BABE_ITEMS.iter().copied().find(|item| *item == name)
+				.ok_or_else(|| format!("Unknown transcript item `{}`", name))?;
+			Ok((name, match value {
+				TranscriptValue::Bytes(bytes) => VRFTranscriptValue::Bytes(bytes.0),
+				TranscriptValue::U64(number) => VRFTranscriptValue::U64(number),
+			}))
+		}).collect::<Result<_, String>>()?;
+		Ok(VRFTranscriptData { label: BABE_LABEL, items })
+	}
+}
+
+/// A public key held by the signer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKey {
+	/// The crypto of the key.
+	pub crypto_id: String,
+	/// The public key.
+	pub public: Bytes,
+}
+
+/// An answer of the signer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Response {
+	/// The public keys of the requested key type.
+	PublicKeys(Vec<PublicKey>),
+	/// The signature of the message.
+	Signature(Bytes),
+	/// The VRF signature of the transcript.
+	VrfSignature {
+		/// The VRF output.
+		output: Bytes,
+		/// The proof of the output.
+		proof: Bytes,
+	},
+	/// The request failed.
+	Error(String),
+}
+
+fn id_to_string(id: &[u8; 4]) -> String {
+	String::from_utf8_lossy(id).into_owned()
+}
+
+fn id_from_str(id: &str) -> Option<[u8; 4]> {
+	<[u8; 4]>::try_from(id.as_bytes()).ok()
+}
+
+/// Keys of the delegated key types are used through the signer, and the others through the
+/// local keystore.
+pub struct RemoteKeystore {
+	local: KeyStorePtr,
+	socket: PathBuf,
+	delegated: Vec<KeyTypeId>,
+}
+
+impl RemoteKeystore {
+	/// Creates a keystore delegating the keys of `delegated` to the signer listening on `socket`.
+	pub fn new(local: KeyStorePtr, socket: PathBuf, delegated: Vec<KeyTypeId>) -> Self {
+		RemoteKeystore { local, socket, delegated }
+	}
+
+	fn is_delegated(&self, id: KeyTypeId) -> bool {
+		self.delegated.contains(&id)
+	}
+
+	fn request(&self, request: &Request) -> Result<Response, BareCryptoStoreError> {
+		let call = || -> io::Result<Response> {
+			let mut stream = UnixStream::connect(&self.socket)?;
+			stream.set_read_timeout(Some(TIMEOUT))?;
+			stream.set_write_timeout(Some(TIMEOUT))?;
+
+			let mut line = serde_json::to_vec(request)?;
+			line.push(b'\n');
+			stream.write_all(&line)?;
+
+			let mut answer = String::new();
+			BufReader::new(stream).read_line(&mut answer)?;
+			Ok(serde_json::from_str(&answer)?)
+		};
+
+		match call() {
+			Ok(Response::Error(e)) => Err(BareCryptoStoreError::Other(e)),
+			Ok(response) => Ok(response),
+			Err(e) => {
+				log::warn!("Remote signer at {} unavailable: {}", self.socket.display(), e);
+				Err(BareCryptoStoreError::Unavailable)
+			},
+		}
+	}
+
+	fn remote_keys(&self, id: KeyTypeId) -> Result<Vec<CryptoTypePublicPair>, BareCryptoStoreError> {
+		match self.request(&Request::PublicKeys { key_type: id_to_string(&id.0) })? {
+			Response::PublicKeys(keys) => Ok(keys.into_iter()
+				.filter_map(|key| Some(CryptoTypePublicPair(CryptoTypeId(id_from_str(&key.crypto_id)?), key.public.0)))
+				.collect()),
+			response => Err(BareCryptoStoreError::Other(format!("Unexpected answer {:?}", response))),
+		}
+	}
+
+	fn remote_public_keys<P: for<'a> TryFrom<&'a [u8]>>(&self, id: KeyTypeId, crypto_id: CryptoTypeId) -> Vec<P> {
+		self.remote_keys(id).unwrap_or_default().into_iter()
+			.filter(|key| key.0 == crypto_id)
+			.filter_map(|key| P::try_from(&key.1[..]).ok())
+			.collect()
+	}
+
+	fn not_generated(id: KeyTypeId) -> BareCryptoStoreError {
+		BareCryptoStoreError::Other(format!("Keys of type {} are generated by the remote signer", id_to_string(&id.0)))
+	}
+}
+
+impl BareCryptoStore for RemoteKeystore {
+	fn keys(&self, id: KeyTypeId) -> Result<Vec<CryptoTypePublicPair>, BareCryptoStoreError> {
+		if self.is_delegated(id) {
+			self.remote_keys(id)
+		} else {
+			self.local.read().keys(id)
+		}
+	}
+
+	fn sr25519_public_keys(&self, id: KeyTypeId) -> Vec<sr25519::Public> {
+		if self.is_delegated(id) {
+			self.remote_public_keys(id, sr25519::CRYPTO_ID)
+		} else {
+			self.local.read().sr25519_public_keys(id)
+		}
+	}
+
+	fn sr25519_generate_new(&mut self, id: KeyTypeId, seed: Option<&str>) -> Result<sr25519::Public, BareCryptoStoreError> {
+		if self.is_delegated(id) {
+			Err(Self::not_generated(id))
+		} else {
+			self.local.write().sr25519_generate_new(id, seed)
+		}
+	}
+
+	fn ed25519_public_keys(&self, id: KeyTypeId) -> Vec<ed25519::Public> {
+		if self.is_delegated(id) {
+			self.remote_public_keys(id, ed25519::CRYPTO_ID)
+		} else {
+			self.local.read().ed25519_public_keys(id)
+		}
+	}
+
+	fn ed25519_generate_new(&mut self, id: KeyTypeId, seed: Option<&str>) -> Result<ed25519::Public, BareCryptoStoreError> {
+		if self.is_delegated(id) {
+			Err(Self::not_generated(id))
+		} else {
+			self.local.write().ed25519_generate_new(id, seed)
+		}
+	}
+
+	fn ecdsa_public_keys(&self, id: KeyTypeId) -> Vec<ecdsa::Public> {
+		if self.is_delegated(id) {
+			self.remote_public_keys(id, ecdsa::CRYPTO_ID)
+		} else {
+			self.local.read().ecdsa_public_keys(id)
+		}
+	}
+
+	fn ecdsa_generate_new(&mut self, id: KeyTypeId, seed: Option<&str>) -> Result<ecdsa::Public, BareCryptoStoreError> {
+		if self.is_delegated(id) {
+			Err(Self::not_generated(id))
+		} else {
+			self.local.write().ecdsa_generate_new(id, seed)
+		}
+	}
+
+	fn insert_unknown(&mut self, key_type: KeyTypeId, suri: &str, public: &[u8]) -> Result<(), ()> {
+		if self.is_delegated(key_type) {
+			Err(())
+		} else {
+			self.local.write().insert_unknown(key_type, suri, public)
+		}
+	}
+
+	fn password(&self) -> Option<&str> {
+		None
+	}
+
+	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
+		public_keys.iter().all(|(public, id)| if self.is_delegated(*id) {
+			self.remote_keys(*id).map_or(false, |keys| keys.iter().any(|key| &key.1 == public))
+		} else {
+			self.local.read().has_keys(&[(public.clone(), *id)])
+		})
+	}
+
+	fn supported_keys(
+		&self,
+		id: KeyTypeId,
+		keys: Vec<CryptoTypePublicPair>,
+	) -> Result<Vec<CryptoTypePublicPair>, BareCryptoStoreError> {
+		if self.is_delegated(id) {
+			let held = self.remote_keys(id)?;
+			Ok(keys.into_iter().filter(|key| held.contains(key)).collect())
+		} else {
+			self.local.read().supported_keys(id, keys)
+		}
+	}
+
+	fn sign_with(&self, id: KeyTypeId, key: &CryptoTypePublicPair, msg: &[u8]) -> Result<Vec<u8>, BareCryptoStoreError> {
+		if !self.is_delegated(id) {
+			return self.local.read().sign_with(id, key, msg);
+		}
+
+		let request = Request::Sign {
+			key_type: id_to_string(&id.0),
+			crypto_id: id_to_string(&(key.0).0),
+			public: key.1.clone().into(),
+			message: msg.to_vec().into(),
+		};
+		match self.request(&request)? {
+			Response::Signature(signature) => Ok(signature.0),
+			response => Err(BareCryptoStoreError::Other(format!("Unexpected answer {:?}", response))),
+		}
+	}
+
+	fn sr25519_vrf_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		transcript_data: VRFTranscriptData,
+	) -> Result<VRFSignature, BareCryptoStoreError> {
+		if !self.is_delegated(key_type) {
+			return self.local.read().sr25519_vrf_sign(key_type, public, transcript_data);
+		}
+
+		let request = Request::VrfSign {
+			key_type: id_to_string(&key_type.0),
+			public: public.0.to_vec().into(),
+			transcript: transcript_data.into(),
+		};
+		match self.request(&request)? {
+			Response::VrfSignature { output, proof } => {
+				let invalid = |e| BareCryptoStoreError::Other(format!("Invalid VRF signature: {:?}", e));
+				Ok(VRFSignature {
+					output: schnorrkel::vrf::VRFOutput::from_bytes(&output).map_err(invalid)?,
+					proof: schnorrkel::vrf::VRFProof::from_bytes(&proof).map_err(invalid)?,
+				})
+			},
+			response => Err(BareCryptoStoreError::Other(format!("Unexpected answer {:?}", response))),
+		}
+	}
+}
+
+/// Answers a request with the keys of `keystore`.
+fn answer(keystore: &KeyStorePtr, request: Request) -> Response {
+	let key_type = |id: &str| id_from_str(id).map(KeyTypeId).ok_or_else(|| format!("Invalid key type `{}`", id));
+	let result = match request {
+		Request::PublicKeys { key_type: id } => key_type(&id).and_then(|id| {
+			keystore.read().keys(id).map_err(|e| format!("{:?}", e))
+		}).map(|keys| Response::PublicKeys(keys.into_iter().map(|key| PublicKey {
+			crypto_id: id_to_string(&(key.0).0),
+			public: key.1.into(),
+		}).collect())),
+		Request::Sign { key_type: id, crypto_id, public, message } => key_type(&id).and_then(|id| {
+			let crypto_id = id_from_str(&crypto_id).ok_or_else(|| format!("Invalid crypto id `{}`", crypto_id))?;
+			keystore.read().sign_with(id, &CryptoTypePublicPair(CryptoTypeId(crypto_id), public.0), &message)
+				.map_err(|e| format!("{:?}", e))
+		}).map(|signature| Response::Signature(signature.into())),
+		Request::VrfSign { key_type: id, public, transcript } => key_type(&id).and_then(|id| {
+			let public = sr25519::Public::try_from(&public.0[..])
+				.map_err(|_| "Invalid sr25519 public key".to_string())?;
+			keystore.read().sr25519_vrf_sign(id, &public, transcript.into_babe_data()?)
+				.map_err(|e| format!("{:?}", e))
+		}).map(|signature| Response::VrfSignature {
+			output: signature.output.to_bytes().to_vec().into(),
+			proof: signature.proof.to_bytes().to_vec().into(),
+		}),
+	};
+
+	result.unwrap_or_else(Response::Error)
+}
+
+fn serve_connection(keystore: &KeyStorePtr, stream: UnixStream) -> io::Result<()> {
+	let mut writer = stream.try_clone()?;
+	for line in BufReader::new(stream).lines() {
+		let response = match serde_json::from_str(&line?) {
+			Ok(request) => answer(keystore, request),
+			Err(e) => Response::Error(format!("Invalid request: {}", e)),
+		};
+		let mut line = serde_json::to_vec(&response)?;
+		line.push(b'\n');
+		writer.write_all(&line)?;
+	}
+	Ok(())
+}
+
+/// Binds `socket` inside a directory only the owner may enter, and moves it to its path once it
+/// is restricted to the owner, so that no other user can connect in between.
+fn bind_private(socket: &Path) -> io::Result<UnixListener> {
+	let name = socket.file_name()
+		.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "No socket file name"))?;
+	let dir = socket.with_file_name(format!(".{}.bind", name.to_string_lossy()));
+	std::fs::DirBuilder::new().mode(0o700).create(&dir)?;
+
+	let bound = dir.join(name);
+	let result = UnixListener::bind(&bound).and_then(|listener| {
+		std::fs::set_permissions(&bound, std::fs::Permissions::from_mode(0o600))?;
+		std::fs::rename(&bound, socket)?;
+		Ok(listener)
+	});
+	let _ = std::fs::remove_file(&bound);
+	std::fs::remove_dir(&dir)?;
+	result
+}
+
+/// Answers the requests of nodes on `socket` with the keys of `keystore`, one thread per
+/// connection. Only the owner of the process may connect.
+pub fn serve(socket: &Path, keystore: KeyStorePtr) -> io::Result<()> {
+	if socket.exists() {
+		std::fs::remove_file(socket)?;
+	}
+	let listener = bind_private(socket)?;
+
+	for stream in listener.incoming() {
+		let stream = stream?;
+		let keystore = keystore.clone();
+		std::thread::spawn(move || {
+			if let Err(e) = serve_connection(&keystore, stream) {
+				log::debug!("Connection of the signer closed: {}", e);
+			}
+		});
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_keystore::Store;
+	use sp_core::{crypto::key_types::{BABE, GRANDPA, IM_ONLINE}, Pair};
+
+	#[test]
+	fn sockets_are_bound_private() {
+		let dir = tempfile::tempdir().unwrap();
+		let socket = dir.path().join("signer.sock");
+
+		let _listener = bind_private(&socket).unwrap();
+		let mode = std::fs::metadata(&socket).unwrap().permissions().mode();
+		assert_eq!(mode & 0o777, 0o600);
+		assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+	}
+
+	#[test]
+	fn delegated_keys_are_signed_with_by_the_signer() {
+		let dir = tempfile::tempdir().unwrap();
+		let socket = dir.path().join("signer.sock");
+
+		let signer = Store::new_in_memory();
+		let grandpa = signer.write().ed25519_generate_new(GRANDPA, Some("//Alice")).unwrap();
+		let im_online = signer.write().sr25519_generate_new(IM_ONLINE, Some("//Alice")).unwrap();
+		std::thread::spawn({
+			let socket = socket.clone();
+			move || serve(&socket, signer)
+		});
+		while !socket.exists() {
+			std::thread::sleep(Duration::from_millis(10));
+		}
+
+		let local = Store::new_in_memory();
+		let babe = local.write().sr25519_generate_new(BABE, Some("//Alice")).unwrap();
+		let keystore = RemoteKeystore::new(local, socket, vec![GRANDPA, IM_ONLINE]);
+
+		assert_eq!(keystore.ed25519_public_keys(GRANDPA), vec![grandpa]);
+		assert_eq!(keystore.sr25519_public_keys(IM_ONLINE), vec![im_online]);
+		assert_eq!(keystore.sr25519_public_keys(BABE), vec![babe]);
+		assert!(keystore.has_keys(&[(grandpa.0.to_vec(), GRANDPA), (babe.0.to_vec(), BABE)]));
+		assert!(!keystore.has_keys(&[(babe.0.to_vec(), GRANDPA)]));
+
+		let signature = keystore.sign_with(GRANDPA, &grandpa.into(), b"message").unwrap();
+		let signature = ed25519::Signature::try_from(&signature[..]).unwrap();
+		assert!(ed25519::Pair::verify(&signature, b"message", &grandpa));
+
+		let error = keystore.sign_with(GRANDPA, &babe.into(), b"message").unwrap_err();
+		assert!(matches!(error, BareCryptoStoreError::Other(_)));
+	}
+
+	#[test]
+	fn babe_transcripts_are_signed_by_the_signer() {
+		let dir = tempfile::tempdir().unwrap();
+		let socket = dir.path().join("signer.sock");
+
+		let signer = Store::new_in_memory();
+		let babe = signer.write().sr25519_generate_new(BABE, Some("//Alice")).unwrap();
+		std::thread::spawn({
+			let socket = socket.clone();
+			let signer = signer.clone();
+			move || serve(&socket, signer)
+		});
+		while !socket.exists() {
+			std::thread::sleep(Duration::from_millis(10));
+		}
+
+		let keystore = RemoteKeystore::new(Store::new_in_memory(), socket, vec![BABE]);
+		let transcript = || VRFTranscriptData {
+			label: BABE_LABEL,
+			items: vec![
+				("slot number", VRFTranscriptValue::U64(1)),
+				("current epoch", VRFTranscriptValue::U64(0)),
+				("chain randomness", VRFTranscriptValue::Bytes(vec![0; 32])),
+			],
+		};
+
+		let remote = keystore.sr25519_vrf_sign(BABE, &babe, transcript()).unwrap();
+		let local = signer.read().sr25519_vrf_sign(BABE, &babe, transcript()).unwrap();
+		assert_eq!(remote.output, local.output);
+
+		let other = VRFTranscriptData { label: b"other", items: vec![] };
+		assert!(matches!(keystore.sr25519_vrf_sign(BABE, &babe, other), Err(BareCryptoStoreError::Other(_))));
+	}
+}
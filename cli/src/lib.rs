@@ -37,11 +37,34 @@ mod service;
 #[cfg(feature = "browser")]
 mod browser;
 #[cfg(feature = "cli")]
+mod build_genesis;
+#[cfg(feature = "cli")]
 mod cli;
 #[cfg(feature = "cli")]
 mod command;
 #[cfg(feature = "cli")]
+mod decode;
+#[cfg(feature = "cli")]
+mod fork_off;
+#[cfg(feature = "cli")]
+mod health;
+#[cfg(feature = "cli")]
+mod indexer;
+#[cfg(feature = "cli")]
+mod keys;
+#[cfg(feature = "cli")]
+pub mod keystore;
+#[cfg(feature = "cli")]
+mod light_rpc;
+#[cfg(feature = "cli")]
+mod metrics;
+#[cfg(feature = "cli")]
 mod rpc;
+#[cfg(feature = "cli")]
+mod sealing;
+#[cfg(feature = "cli")]
+mod try_runtime_upgrade;
+mod voting_rule;
 
 #[cfg(feature = "browser")]
 pub use browser::*;
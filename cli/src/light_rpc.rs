@@ -0,0 +1,450 @@
+//! Node specific RPCs of light clients.
+//!
+//! A light client holds no state to run the runtime on, so these RPCs have a full node execute
+//! the runtime API call with [`Fetcher::remote_call`], which checks the execution proof it gets
+//! back against the state root of the block. They have the names, parameters and results of the
+//! RPCs of full nodes, so applications do not need to know which kind of node they talk to.
+
+use codec::{Decode, Encode};
+use futures::{future, Future, FutureExt, TryFutureExt};
+use jsonrpc_core::{Error as RpcError, ErrorCode};
+use jsonrpc_derive::rpc;
+use node_primitives::{AccountId, AssetId, Balance, Block, BlockNumber, Hash};
+use pallet_contracts_primitives::{
+	ContractAccessError, ContractExecResult, GetStorageResult, RentProjection, RentProjectionResult,
+};
+use pallet_contracts_rpc::RpcContractExecResult;
+use pallet_transaction_payment::RuntimeDispatchInfo;
+use sc_client_api::light::{future_header, Fetcher, RemoteBlockchain, RemoteCallRequest};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::{Error as ClientError, HeaderBackend};
+use sp_core::{Bytes, H256, U256};
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, OpaqueExtrinsic};
+use std::sync::Arc;
+
+type FutureResult<T> = jsonrpc_core::BoxFuture<T>;
+
+/// The maximum gas limit of `contracts_call`, as on full nodes: five seconds of execution.
+const MAX_GAS_LIMIT: u64 = 5 * 1_000_000_000_000;
+
+/// A contract call, as given to `contracts_call`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CallRequest {
+	/// The account calling the contract.
+	pub origin: AccountId,
+	/// The contract.
+	pub dest: AccountId,
+	/// The value transferred to the contract.
+	pub value: NumberOrHex,
+	/// The gas the call may use, at most five seconds of execution.
+	pub gas_limit: NumberOrHex,
+	/// The input of the call.
+	pub input_data: Bytes,
+}
+
+/// Fees of extrinsics, as `TransactionPaymentApi` of full nodes.
+#[rpc]
+pub trait LightTransactionPaymentApi {
+	/// The weight, class and fee of an encoded extrinsic.
+	#[rpc(name = "payment_queryInfo")]
+	fn query_info(&self, encoded_xt: Bytes, at: Option<Hash>) -> FutureResult<RuntimeDispatchInfo<Balance>>;
+}
+
+/// Read only queries of contracts, as `ContractsApi` of full nodes.
+#[rpc]
+pub trait LightContractsApi {
+	/// Executes a call to a contract without recording it on chain.
+	#[rpc(name = "contracts_call")]
+	fn call(&self, call_request: CallRequest, at: Option<Hash>) -> FutureResult<RpcContractExecResult>;
+
+	/// The value under `key` in the storage of a contract.
+	#[rpc(name = "contracts_getStorage")]
+	fn get_storage(&self, address: AccountId, key: H256, at: Option<Hash>) -> FutureResult<Option<Bytes>>;
+
+	/// The block at which a contract is evicted, `null` if it has enough rent to stay.
+	#[rpc(name = "contracts_rentProjection")]
+	fn rent_projection(&self, address: AccountId, at: Option<Hash>) -> FutureResult<Option<BlockNumber>>;
+}
+
+/// Queries of fungible assets, as `FungibleAssetsApi` of full nodes.
+#[rpc]
+pub trait LightFungibleAssetsApi {
+	/// The balance of `who` in `asset_id`.
+	#[rpc(name = "fungibleAssets_balance")]
+	fn balance(&self, asset_id: AssetId, who: AccountId, at: Option<Hash>) -> FutureResult<NumberOrHex>;
+
+	/// The total supply of `asset_id`, `null` if it does not exist.
+	#[rpc(name = "fungibleAssets_totalSupply")]
+	fn total_supply(&self, asset_id: AssetId, at: Option<Hash>) -> FutureResult<Option<NumberOrHex>>;
+}
+
+/// A struct that implements the light client APIs by calling the runtime of a full node.
+pub struct RemoteRuntime<C, F> {
+	client: Arc<C>,
+	remote_blockchain: Arc<dyn RemoteBlockchain<Block>>,
+	fetcher: Arc<F>,
+}
+
+impl<C, F> Clone for RemoteRuntime<C, F> {
+	fn clone(&self) -> Self {
+		RemoteRuntime {
+			client: self.client.clone(),
+			remote_blockchain: self.remote_blockchain.clone(),
+			fetcher: self.fetcher.clone(),
+		}
+	}
+}
+
+impl<C, F> RemoteRuntime<C, F> {
+	/// Create new `RemoteRuntime`, calling the runtime through `fetcher`.
+	pub fn new(client: Arc<C>, remote_blockchain: Arc<dyn RemoteBlockchain<Block>>, fetcher: Arc<F>) -> Self {
+		RemoteRuntime { client, remote_blockchain, fetcher }
+	}
+}
+
+/// Error type of these RPC apis, with the codes of the full node RPCs.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+	/// The contract does not exist.
+	ContractDoesntExist,
+	/// The contract is a tombstone.
+	ContractIsATombstone,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::ContractDoesntExist => 2,
+			Error::ContractIsATombstone => 3,
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: "Unable to call the runtime of a full node.".into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+fn contract_access_error(e: ContractAccessError) -> RpcError {
+	match e {
+		ContractAccessError::DoesntExist => RpcError {
+			code: ErrorCode::ServerError(Error::ContractDoesntExist.into()),
+			message: "The specified contract doesn't exist.".into(),
+			data: None,
+		},
+		ContractAccessError::IsTombstone => RpcError {
+			code: ErrorCode::ServerError(Error::ContractIsATombstone.into()),
+			message: "The contract is a tombstone and doesn't have any storage.".into(),
+			data: None,
+		},
+	}
+}
+
+fn invalid_params(message: String) -> RpcError {
+	RpcError { code: ErrorCode::InvalidParams, message, data: None }
+}
+
+/// Boxes a future into the futures 0.1 future of `jsonrpc_core`.
+fn into_rpc<T: Send + 'static>(
+	future: impl Future<Output = Result<T, RpcError>> + Send + 'static,
+) -> FutureResult<T> {
+	Box::new(future.boxed().compat())
+}
+
+impl<C, F> RemoteRuntime<C, F> where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+	F: Fetcher<Block> + 'static,
+{
+	/// Calls `method` of the runtime API at `at`, or at the best block, and decodes its result.
+	fn remote_call<R: Decode + Send + 'static>(
+		&self,
+		at: Option<Hash>,
+		method: &'static str,
+		call_data: Vec<u8>,
+	) -> impl Future<Output = Result<R, RpcError>> {
+		// If the block hash is not supplied assume the best block.
+		let block = at.unwrap_or_else(|| self.client.info().best_hash);
+		let header = future_header(&*self.remote_blockchain, &*self.fetcher, BlockId::Hash(block));
+		let fetcher = self.fetcher.clone();
+
+		async move {
+			let header = header.await?.ok_or_else(|| ClientError::UnknownBlock(format!("{}", block)))?;
+			let result = fetcher.remote_call(RemoteCallRequest {
+				block,
+				header,
+				method: method.into(),
+				call_data,
+				retry_count: None,
+			}).await?;
+			R::decode(&mut &result[..]).map_err(|e| ClientError::CallResultDecode(method, e))
+		}.map_err(runtime_error)
+	}
+}
+
+impl<C, F> LightTransactionPaymentApi for RemoteRuntime<C, F> where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+	F: Fetcher<Block> + 'static,
+{
+	fn query_info(&self, encoded_xt: Bytes, at: Option<Hash>) -> FutureResult<RuntimeDispatchInfo<Balance>> {
+		let encoded_len = encoded_xt.len() as u32;
+		let uxt = match OpaqueExtrinsic::decode(&mut &*encoded_xt) {
+			Ok(uxt) => uxt,
+			Err(e) => return into_rpc(future::err(invalid_params(format!("Unable to decode the extrinsic: {}", e)))),
+		};
+
+		into_rpc(self.remote_call(at, "TransactionPaymentApi_query_info", (uxt, encoded_len).encode()))
+	}
+}
+
+impl<C, F> LightContractsApi for RemoteRuntime<C, F> where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+	F: Fetcher<Block> + 'static,
+{
+	fn call(&self, call_request: CallRequest, at: Option<Hash>) -> FutureResult<RpcContractExecResult> {
+		let CallRequest { origin, dest, value, gas_limit, input_data } = call_request;
+
+		let value = value.into_u256();
+		if value > U256::from(Balance::max_value()) {
+			return into_rpc(future::err(invalid_params(format!("{} doesn't fit in a balance", value))));
+		}
+		let gas_limit = gas_limit.into_u256();
+		if gas_limit > U256::from(MAX_GAS_LIMIT) {
+			return into_rpc(future::err(invalid_params(format!(
+				"Requested gas limit is greater than maximum allowed: {} > {}",
+				gas_limit, MAX_GAS_LIMIT,
+			))));
+		}
+
+		let call_data = (origin, dest, value.low_u128(), gas_limit.low_u64(), input_data.to_vec()).encode();
+		into_rpc(self.remote_call::<ContractExecResult>(at, "ContractsApi_call", call_data)
+			.map_ok(RpcContractExecResult::from))
+	}
+
+	fn get_storage(&self, address: AccountId, key: H256, at: Option<Hash>) -> FutureResult<Option<Bytes>> {
+		let call_data = (address, key.to_fixed_bytes()).encode();
+		into_rpc(self.remote_call::<GetStorageResult>(at, "ContractsApi_get_storage", call_data)
+			.map(|result| result?.map(|value| value.map(Bytes)).map_err(contract_access_error)))
+	}
+
+	fn rent_projection(&self, address: AccountId, at: Option<Hash>) -> FutureResult<Option<BlockNumber>> {
+		let call_data = address.encode();
+		into_rpc(self.remote_call::<RentProjectionResult<BlockNumber>>(at, "ContractsApi_rent_projection", call_data)
+			.map(|result| match result?.map_err(contract_access_error)? {
+				RentProjection::EvictionAt(block_number) => Ok(Some(block_number)),
+				RentProjection::NoEviction => Ok(None),
+			}))
+	}
+}
+
+impl<C, F> LightFungibleAssetsApi for RemoteRuntime<C, F> where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+	F: Fetcher<Block> + 'static,
+{
+	fn balance(&self, asset_id: AssetId, who: AccountId, at: Option<Hash>) -> FutureResult<NumberOrHex> {
+		into_rpc(self.remote_call::<Balance>(at, "FungibleAssetsApi_balance", (asset_id, who).encode())
+			.map_ok(|balance| NumberOrHex::Hex(balance.into())))
+	}
+
+	fn total_supply(&self, asset_id: AssetId, at: Option<Hash>) -> FutureResult<Option<NumberOrHex>> {
+		into_rpc(self.remote_call::<Option<Balance>>(at, "FungibleAssetsApi_total_supply", asset_id.encode())
+			.map_ok(|supply| supply.map(|supply| NumberOrHex::Hex(supply.into()))))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chain_spec::{authority_keys_from_seed, gama::testnet_genesis};
+	use futures::future::{ready, Ready};
+	use jsonrpc_core::futures::Future as _;
+	use node_primitives::Header;
+	use sc_client_api::light::{
+		LocalOrRemote, RemoteBodyRequest, RemoteChangesRequest, RemoteHeaderRequest, RemoteReadChildRequest,
+		RemoteReadRequest,
+	};
+	use sc_executor::{NativeExecutor, WasmExecutionMethod};
+	use sp_blockchain::BlockStatus;
+	use sp_runtime::{traits::{BlakeTwo256, Header as _}, BuildStorage};
+	use sp_state_machine::{
+		backend::BackendRuntimeCode, Backend, ExecutionStrategy, InMemoryBackend, OverlayedChanges,
+		StateMachine,
+	};
+	use std::collections::HashMap;
+
+	/// A chain of its development genesis block, whose runtime calls are executed as a full node
+	/// answers them.
+	struct FullNode {
+		state: InMemoryBackend<BlakeTwo256>,
+		genesis: Header,
+		executor: NativeExecutor<node_executor::GamaExecutor>,
+	}
+
+	impl FullNode {
+		fn new() -> Arc<Self> {
+			let storage = testnet_genesis(vec![authority_keys_from_seed("Alice")], None, true)
+				.build_storage()
+				.unwrap();
+			let state = InMemoryBackend::from(storage);
+			let state_root = state.storage_root(std::iter::empty()).0;
+
+			Arc::new(FullNode {
+				genesis: Header::new(0, Default::default(), state_root, Default::default(), Default::default()),
+				state,
+				executor: NativeExecutor::new(WasmExecutionMethod::Interpreted, None, 8),
+			})
+		}
+	}
+
+	type Unsupported<T> = Ready<Result<T, ClientError>>;
+
+	fn unsupported<T>() -> Unsupported<T> {
+		ready(Err(ClientError::Msg("Not served by the test node".into())))
+	}
+
+	impl HeaderBackend<Block> for FullNode {
+		fn header(&self, id: BlockId<Block>) -> sp_blockchain::Result<Option<Header>> {
+			Ok(self.hash_of(id).map(|_| self.genesis.clone()))
+		}
+
+		fn info(&self) -> sp_blockchain::Info<Block> {
+			sp_blockchain::Info {
+				best_hash: self.genesis.hash(),
+				best_number: 0,
+				genesis_hash: self.genesis.hash(),
+				finalized_hash: self.genesis.hash(),
+				finalized_number: 0,
+				number_leaves: 1,
+			}
+		}
+
+		fn status(&self, id: BlockId<Block>) -> sp_blockchain::Result<BlockStatus> {
+			Ok(self.hash_of(id).map_or(BlockStatus::Unknown, |_| BlockStatus::InChain))
+		}
+
+		fn number(&self, hash: Hash) -> sp_blockchain::Result<Option<BlockNumber>> {
+			Ok(self.hash_of(BlockId::Hash(hash)).map(|_| 0))
+		}
+
+		fn hash(&self, number: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+			Ok(self.hash_of(BlockId::Number(number)))
+		}
+	}
+
+	impl FullNode {
+		fn hash_of(&self, id: BlockId<Block>) -> Option<Hash> {
+			match id {
+				BlockId::Hash(hash) if hash == self.genesis.hash() => Some(hash),
+				BlockId::Number(0) => Some(self.genesis.hash()),
+				_ => None,
+			}
+		}
+	}
+
+	impl RemoteBlockchain<Block> for FullNode {
+		fn header(&self, id: BlockId<Block>) -> sp_blockchain::Result<LocalOrRemote<Header, RemoteHeaderRequest<Header>>> {
+			Ok(self.hash_of(id).map_or(LocalOrRemote::Unknown, |_| LocalOrRemote::Local(self.genesis.clone())))
+		}
+	}
+
+	impl Fetcher<Block> for FullNode {
+		type RemoteHeaderResult = Unsupported<Header>;
+		type RemoteReadResult = Unsupported<HashMap<Vec<u8>, Option<Vec<u8>>>>;
+		type RemoteCallResult = Ready<Result<Vec<u8>, ClientError>>;
+		type RemoteChangesResult = Unsupported<Vec<(BlockNumber, u32)>>;
+		type RemoteBodyResult = Unsupported<Vec<OpaqueExtrinsic>>;
+
+		fn remote_header(&self, _: RemoteHeaderRequest<Header>) -> Self::RemoteHeaderResult {
+			unsupported()
+		}
+
+		fn remote_read(&self, _: RemoteReadRequest<Header>) -> Self::RemoteReadResult {
+			unsupported()
+		}
+
+		fn remote_read_child(&self, _: RemoteReadChildRequest<Header>) -> Self::RemoteReadResult {
+			unsupported()
+		}
+
+		fn remote_call(&self, request: RemoteCallRequest<Header>) -> Self::RemoteCallResult {
+			assert_eq!(request.header, self.genesis);
+
+			let code = BackendRuntimeCode::new(&self.state);
+			let result = code.runtime_code().map_err(|e| ClientError::Msg(e.into())).and_then(|runtime_code| {
+				StateMachine::<_, BlakeTwo256, BlockNumber, _>::new(
+					&self.state,
+					None,
+					&mut OverlayedChanges::default(),
+					&self.executor,
+					&request.method,
+					&request.call_data,
+					Default::default(),
+					&runtime_code,
+					sp_core::testing::TaskExecutor::new(),
+				)
+					.execute(ExecutionStrategy::NativeElseWasm)
+					.map_err(ClientError::Execution)
+			});
+
+			ready(result)
+		}
+
+		fn remote_changes(&self, _: RemoteChangesRequest<Header>) -> Self::RemoteChangesResult {
+			unsupported()
+		}
+
+		fn remote_body(&self, _: RemoteBodyRequest<Header>) -> Self::RemoteBodyResult {
+			unsupported()
+		}
+	}
+
+	fn light_client() -> RemoteRuntime<FullNode, FullNode> {
+		let node = FullNode::new();
+		RemoteRuntime::new(node.clone(), node.clone(), node)
+	}
+
+	#[test]
+	fn fungible_assets_are_queried_on_the_full_node() {
+		let rpc = light_client();
+		let alice = authority_keys_from_seed("Alice").0;
+
+		assert_eq!(rpc.balance(1_000, alice, None).wait().unwrap(), NumberOrHex::Hex(0.into()));
+		assert_eq!(rpc.total_supply(1_000, None).wait().unwrap(), None);
+	}
+
+	#[test]
+	fn fees_are_queried_on_the_full_node() {
+		let rpc = light_client();
+		let xt = gama_runtime::UncheckedExtrinsic::new_unsigned(
+			gama_runtime::Call::Timestamp(pallet_timestamp::Call::set(0)),
+		);
+
+		let info = rpc.query_info(xt.encode().into(), None).wait().unwrap();
+
+		assert!(info.weight > 0);
+		// Unsigned extrinsics pay no fee.
+		assert_eq!(info.partial_fee, 0);
+		assert_eq!(
+			rpc.query_info(vec![1, 2, 3].into(), None).wait().unwrap_err().code,
+			ErrorCode::InvalidParams,
+		);
+	}
+
+	#[test]
+	fn contract_errors_keep_their_codes() {
+		let rpc = light_client();
+		let alice = authority_keys_from_seed("Alice").0;
+
+		let error = rpc.get_storage(alice.clone(), H256::zero(), None).wait().unwrap_err();
+		assert_eq!(error.code, ErrorCode::ServerError(Error::ContractDoesntExist.into()));
+
+		let error = rpc.rent_projection(alice, Some(H256::repeat_byte(1))).wait().unwrap_err();
+		assert_eq!(error.code, ErrorCode::ServerError(Error::RuntimeError.into()));
+	}
+}
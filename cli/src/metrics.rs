@@ -0,0 +1,351 @@
+//! Aochain specific Prometheus metrics, registered next to the Substrate ones.
+//!
+//! The counters add up the events of every new best block the node imports while it runs, and
+//! the gauges follow the governance state of the best block. Blocks imported during a reorg are
+//! counted once they become best, so a retracted block keeps being counted.
+//!
+//! | Metric | Type | Labels |
+//! |--------|------|--------|
+//! | `aochain_nft_operations_total` | counter | `instance` (`asset`, `organization`), `operation` (`mint`, `burn`, `asset_transfer`, `token_transfer`) |
+//! | `aochain_transfers_total` | counter | |
+//! | `aochain_transfer_volume_tokens_total` | counter | |
+//! | `aochain_extrinsic_deposits_tokens_total` | counter | `destination` (`treasury`, `author`) |
+//! | `aochain_democracy_referenda_pending` | gauge | |
+//! | `aochain_democracy_public_proposals` | gauge | |
+//! | `aochain_council_proposals` | gauge | |
+//!
+//! The runtime deposits no event for fees, so the deposit counters are a heuristic for them: they
+//! add up the `Treasury::Deposit` and the `Balances::Deposit` to the block author of the
+//! extrinsics, which is where the fee split pays the shares. Other deposits of an extrinsic, such
+//! as the slashes of democracy proposals and identities going to the treasury, are counted too,
+//! and the burnt share leaves no trace and is not counted.
+
+use codec::{Compact, Decode};
+use futures::prelude::*;
+use node_primitives::{AccountId, Balance, Block, Hash, Header};
+use prometheus_endpoint::{
+	register, Counter, CounterVec, F64, Gauge, Opts, PrometheusError, Registry, U64,
+};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sp_consensus_babe::digests::CompatibleDigestItem;
+use sp_core::{storage::StorageKey, twox_128};
+use sp_runtime::{generic::BlockId, traits::Header as _};
+use std::sync::Arc;
+
+/// What the events of a block count for.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Activity {
+	/// NFT operations, as their `instance` and `operation` labels.
+	pub nft: Vec<(&'static str, &'static str)>,
+	/// Amounts of the native token transfers.
+	pub transfers: Vec<Balance>,
+	/// Deposits of extrinsics to the treasury and the block author, mostly fees and tips.
+	pub deposits: (Balance, Balance),
+}
+
+/// Reads the [`Activity`] of the blocks of one network.
+#[derive(Clone, Copy)]
+pub struct ActivityDecoder {
+	/// Units of the native token in one token.
+	pub token: Balance,
+	/// Reads the [`Activity`] of a block from its encoded `System::Events` and its author, if known.
+	pub activity: fn(&[u8], Option<&AccountId>) -> Result<Activity, codec::Error>,
+}
+
+/// The `operation` label of an event of an NFT pallet instance.
+fn nft_operation<Balance, I>(
+	event: &pallet_assets_nft::RawEvent<Hash, AccountId, Balance, I>,
+) -> Option<&'static str> {
+	use pallet_assets_nft::RawEvent::*;
+
+	match event {
+		Minted(..) => Some("mint"),
+		Burned(..) => Some("burn"),
+		AssetTransferred(..) => Some("asset_transfer"),
+		TokenTransferred(..) => Some("token_transfer"),
+		_ => None,
+	}
+}
+
+macro_rules! activity_decoder {
+	($name:ident, $module:ident, $runtime:ident, $doc:expr) => {
+		#[doc = $doc]
+		pub const $name: ActivityDecoder = ActivityDecoder {
+			token: $runtime::constants::currency::DOLLARS,
+			activity: $module::activity,
+		};
+
+		mod $module {
+			use super::*;
+			use $runtime::Event;
+
+			pub fn activity(events: &[u8], author: Option<&AccountId>) -> Result<Activity, codec::Error> {
+				type EventRecord = frame_system::EventRecord<Event, Hash>;
+
+				let mut activity = Activity::default();
+				for record in Vec::<EventRecord>::decode(&mut &events[..])? {
+					// Fees are paid once their extrinsic is dispatched.
+					let in_extrinsic = matches!(record.phase, frame_system::Phase::ApplyExtrinsic(_));
+					match record.event {
+						Event::pallet_assets_nft_Instance1(event) => activity.nft.extend(
+							nft_operation(&event).map(|operation| ("organization", operation))
+						),
+						Event::pallet_assets_nft_Instance2(event) => activity.nft.extend(
+							nft_operation(&event).map(|operation| ("asset", operation))
+						),
+						Event::pallet_balances(pallet_balances::RawEvent::Transfer(_, _, amount)) =>
+							activity.transfers.push(amount),
+						Event::pallet_treasury(pallet_treasury::RawEvent::Deposit(amount)) if in_extrinsic =>
+							activity.deposits.0 = activity.deposits.0.saturating_add(amount),
+						Event::pallet_balances(pallet_balances::RawEvent::Deposit(who, amount))
+							if in_extrinsic && Some(&who) == author =>
+							activity.deposits.1 = activity.deposits.1.saturating_add(amount),
+						_ => {},
+					}
+				}
+
+				Ok(activity)
+			}
+		}
+	}
+}
+
+activity_decoder!(GAMA, gama, gama_runtime, "Reads the activity of gama blocks.");
+activity_decoder!(COLOMBO, colombo, colombo_runtime, "Reads the activity of colombo blocks.");
+
+/// The governance state of a block.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Governance {
+	referenda_pending: u32,
+	public_proposals: u32,
+	council_proposals: u32,
+}
+
+fn storage_key(prefix: &str, item: &str) -> StorageKey {
+	StorageKey([twox_128(prefix.as_bytes()), twox_128(item.as_bytes())].concat())
+}
+
+impl Governance {
+	fn read<C, B>(client: &C, id: &BlockId<Block>) -> sp_blockchain::Result<Self> where
+		C: StorageProvider<Block, B>,
+		B: sc_client_api::Backend<Block>,
+	{
+		// Values default to zero, and vectors are read up to their length.
+		let read_length = |prefix, item| client.storage(id, &storage_key(prefix, item)).map(|value| {
+			value.and_then(|value| <Compact<u32>>::decode(&mut &value.0[..]).ok().map(|length| length.0))
+				.unwrap_or_default()
+		});
+		let read_u32 = |prefix, item| client.storage(id, &storage_key(prefix, item)).map(|value| {
+			value.and_then(|value| u32::decode(&mut &value.0[..]).ok()).unwrap_or_default()
+		});
+
+		// Referenda are baked, ended or cancelled, in order.
+		let referendum_count = read_u32("Democracy", "ReferendumCount")?;
+		let lowest_unbaked = read_u32("Democracy", "LowestUnbaked")?;
+
+		Ok(Governance {
+			referenda_pending: referendum_count.saturating_sub(lowest_unbaked),
+			public_proposals: read_length("Democracy", "PublicProps")?,
+			// The council is the first instance of the collective pallet.
+			council_proposals: read_length("Instance1Collective", "Proposals")?,
+		})
+	}
+}
+
+/// The account of the author of a BABE block, the validator of the session at its authority index.
+fn block_author<C, B>(client: &C, id: &BlockId<Block>, header: &Header) -> Option<AccountId> where
+	C: StorageProvider<Block, B>,
+	B: sc_client_api::Backend<Block>,
+{
+	let index = header.digest().logs().iter()
+		.find_map(|log| log.as_babe_pre_digest())?
+		.authority_index();
+	// BABE authorities are the session keys of the validators, in the same order.
+	let validators = client.storage(id, &storage_key("Session", "Validators")).ok()??;
+	Vec::<AccountId>::decode(&mut &validators.0[..]).ok()?
+		.into_iter()
+		.nth(index as usize)
+}
+
+/// The Aochain specific metrics.
+#[derive(Clone)]
+pub struct Metrics {
+	nft_operations: CounterVec<U64>,
+	transfers: Counter<U64>,
+	transfer_volume: Counter<F64>,
+	deposits: CounterVec<F64>,
+	referenda_pending: Gauge<U64>,
+	public_proposals: Gauge<U64>,
+	council_proposals: Gauge<U64>,
+}
+
+impl Metrics {
+	/// Registers the metrics on `registry`.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Metrics {
+			nft_operations: register(
+				CounterVec::new(
+					Opts::new("aochain_nft_operations_total", "NFT mints, burns and transfers"),
+					&["instance", "operation"],
+				)?,
+				registry,
+			)?,
+			transfers: register(
+				Counter::new("aochain_transfers_total", "Transfers of the native token")?,
+				registry,
+			)?,
+			transfer_volume: register(
+				Counter::new(
+					"aochain_transfer_volume_tokens_total",
+					"Tokens moved by transfers of the native token",
+				)?,
+				registry,
+			)?,
+			deposits: register(
+				CounterVec::new(
+					Opts::new(
+						"aochain_extrinsic_deposits_tokens_total",
+						"Deposits of extrinsics to the treasury and the block author, in tokens: fees, tips and slashes",
+					),
+					&["destination"],
+				)?,
+				registry,
+			)?,
+			referenda_pending: register(
+				Gauge::new("aochain_democracy_referenda_pending", "Democracy referenda not yet baked")?,
+				registry,
+			)?,
+			public_proposals: register(
+				Gauge::new("aochain_democracy_public_proposals", "Public democracy proposals")?,
+				registry,
+			)?,
+			council_proposals: register(
+				Gauge::new("aochain_council_proposals", "Open council motions")?,
+				registry,
+			)?,
+		})
+	}
+
+	/// Counts `activity`, with amounts in tokens of `token` units.
+	fn record(&self, activity: &Activity, token: Balance) {
+		let tokens = |amount: Balance| amount as f64 / token as f64;
+		for (instance, operation) in &activity.nft {
+			self.nft_operations.with_label_values(&[*instance, *operation]).inc();
+		}
+		for amount in &activity.transfers {
+			self.transfers.inc();
+			self.transfer_volume.inc_by(tokens(*amount));
+		}
+
+		let (treasury, author) = activity.deposits;
+		for (destination, amount) in &[("treasury", treasury), ("author", author)] {
+			self.deposits.with_label_values(&[*destination]).inc_by(tokens(*amount));
+		}
+	}
+
+	fn set_governance(&self, governance: Governance) {
+		self.referenda_pending.set(governance.referenda_pending.into());
+		self.public_proposals.set(governance.public_proposals.into());
+		self.council_proposals.set(governance.council_proposals.into());
+	}
+
+	/// Updates the metrics on every new best block imported by `client`.
+	pub async fn run<C, B>(self, decoder: ActivityDecoder, client: Arc<C>) where
+		C: BlockchainEvents<Block> + StorageProvider<Block, B>,
+		B: sc_client_api::Backend<Block>,
+	{
+		let events_key = crate::decode::events_key();
+		let mut imports = client.import_notification_stream();
+
+		while let Some(notification) = imports.next().await {
+			if !notification.is_new_best {
+				continue;
+			}
+
+			let id = BlockId::Hash(notification.hash);
+			let author = block_author(&*client, &id, &notification.header);
+			let activity = client.storage(&id, &events_key)
+				.map_err(|e| e.to_string())
+				.and_then(|events| events.map_or_else(
+					|| Ok(Activity::default()),
+					|events| (decoder.activity)(&events.0, author.as_ref()).map_err(|e| e.to_string()),
+				));
+			match activity {
+				Ok(activity) => self.record(&activity, decoder.token),
+				Err(e) => log::debug!("No metrics for block {}: {}", notification.hash, e),
+			}
+
+			match Governance::read(&*client, &id) {
+				Ok(governance) => self.set_governance(governance),
+				Err(e) => log::debug!("No governance metrics for block {}: {}", notification.hash, e),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+
+	fn record(event: gama_runtime::Event) -> frame_system::EventRecord<gama_runtime::Event, Hash> {
+		frame_system::EventRecord { phase: frame_system::Phase::ApplyExtrinsic(1), event, topics: vec![] }
+	}
+
+	#[test]
+	fn events_are_counted() {
+		let alice = AccountId::from([1; 32]);
+		let bob = AccountId::from([2; 32]);
+		let author = AccountId::from([3; 32]);
+		let events = vec![
+			record(gama_runtime::Event::pallet_assets_nft_Instance2(
+				pallet_assets_nft::RawEvent::Minted(Default::default(), alice.clone(), 1, [0; 32], None)
+			)),
+			record(gama_runtime::Event::pallet_assets_nft_Instance1(
+				pallet_assets_nft::RawEvent::AssetTransferred(Default::default(), alice.clone(), bob.clone(), None)
+			)),
+			record(gama_runtime::Event::pallet_balances(
+				pallet_balances::RawEvent::Transfer(alice, bob.clone(), 5)
+			)),
+			record(gama_runtime::Event::pallet_treasury(pallet_treasury::RawEvent::Deposit(8))),
+			record(gama_runtime::Event::pallet_balances(pallet_balances::RawEvent::Deposit(author.clone(), 2))),
+			record(gama_runtime::Event::pallet_treasury(pallet_treasury::RawEvent::Deposit(4))),
+			record(gama_runtime::Event::pallet_balances(pallet_balances::RawEvent::Deposit(author.clone(), 1))),
+			// Not the author, such as a staking reward.
+			record(gama_runtime::Event::pallet_balances(pallet_balances::RawEvent::Deposit(bob, 7))),
+			// Outside of an extrinsic, such as a slash.
+			frame_system::EventRecord {
+				phase: frame_system::Phase::Finalization,
+				event: gama_runtime::Event::pallet_treasury(pallet_treasury::RawEvent::Deposit(9)),
+				topics: vec![],
+			},
+		];
+
+		assert_eq!((GAMA.activity)(&events.encode(), Some(&author)).unwrap(), Activity {
+			nft: vec![("asset", "mint"), ("organization", "asset_transfer")],
+			transfers: vec![5],
+			deposits: (12, 3),
+		});
+		assert_eq!((GAMA.activity)(&events.encode(), None).unwrap().deposits, (12, 0));
+	}
+
+	#[test]
+	fn activity_updates_the_registered_metrics() {
+		let registry = Registry::new();
+		let metrics = Metrics::register(&registry).unwrap();
+
+		metrics.record(&Activity {
+			nft: vec![("asset", "mint"), ("asset", "mint")],
+			transfers: vec![3 * gama_runtime::constants::currency::DOLLARS],
+			deposits: (gama_runtime::constants::currency::DOLLARS, 0),
+		}, GAMA.token);
+		metrics.set_governance(Governance { referenda_pending: 2, public_proposals: 1, council_proposals: 4 });
+
+		assert_eq!(metrics.nft_operations.with_label_values(&["asset", "mint"]).get(), 2);
+		assert_eq!(metrics.transfers.get(), 1);
+		assert_eq!(metrics.transfer_volume.get(), 3.0);
+		assert_eq!(metrics.deposits.with_label_values(&["treasury"]).get(), 1.0);
+		assert_eq!(metrics.council_proposals.get(), 4);
+		assert!(registry.gather().iter().any(|family| family.get_name() == "aochain_democracy_referenda_pending"));
+	}
+}
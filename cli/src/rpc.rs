@@ -0,0 +1,661 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2019-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A collection of node-specific RPC methods.
+//!
+//! Since `substrate` core functionality makes no assumptions
+//! about the modules used inside the runtime, so do
+//! RPC methods defined in `sc-rpc` crate.
+//! It means that `client/rpc` can't have any methods that
+//! need some strong assumptions about the particular runtime.
+//!
+//! The RPCs available in this crate however can make some assumptions
+//! about how the runtime is constructed and what FRAME pallets
+//! are part of it. Therefore all node-runtime-specific RPCs can
+//! be placed here or imported from corresponding FRAME RPC definitions.
+
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, convert::TryFrom, marker::PhantomData, sync::Arc};
+
+use codec::Encode;
+use crate::decode::{events_key, fields_to_json, AssetRef, DecodedEvent, Names, RuntimeDecoder};
+use crate::keystore::encrypted;
+use futures::{future, stream, StreamExt, TryStreamExt};
+use jsonrpc_core::futures::{future::Future as Future01, sink::Sink as Sink01, stream::Stream as Stream01};
+use jsonrpc_derive::rpc;
+use jsonrpc_pubsub::{manager::SubscriptionManager, typed::Subscriber, SubscriptionId};
+use node_primitives::{Block, BlockNumber, AccountId, AssetId, Index, Balance, Hash};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sc_consensus_babe::{Config, Epoch};
+use sc_consensus_babe_rpc::BabeRpcHandler;
+use sc_consensus_epochs::SharedEpochChanges;
+use grandpa::{
+    SharedVoterState, SharedAuthoritySet, FinalityProofProvider, GrandpaJustificationStream
+};
+use sc_finality_grandpa_rpc::GrandpaRpcHandler;
+use sc_keystore::KeyStorePtr;
+pub use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::{Error as BlockChainError, HeaderMetadata, HeaderBackend};
+use sp_consensus::SelectChain;
+use sp_consensus_babe::BabeApi;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Header as _};
+use sc_rpc::SubscriptionTaskExecutor;
+use sp_transaction_pool::TransactionPool;
+use sc_consensus_manual_seal::{EngineCommand, rpc::{ManualSeal, ManualSealApi}};
+use serde::{Deserialize, Serialize};
+
+/// Light client extra dependencies.
+pub struct LightDeps<C, F, P> {
+    /// The client instance to use.
+    pub client: Arc<C>,
+    /// Transaction pool instance.
+    pub pool: Arc<P>,
+    /// Remote access to the blockchain (async).
+    pub remote_blockchain: Arc<dyn sc_client_api::light::RemoteBlockchain<Block>>,
+    /// Fetcher instance.
+    pub fetcher: Arc<F>,
+}
+
+/// Extra dependencies for BABE.
+pub struct BabeDeps {
+    /// BABE protocol config.
+    pub babe_config: Config,
+    /// BABE pending epoch changes.
+    pub shared_epoch_changes: SharedEpochChanges<Block, Epoch>,
+    /// The keystore that manages the keys of the node.
+    pub keystore: KeyStorePtr,
+}
+
+/// Extra dependencies for GRANDPA
+pub struct GrandpaDeps<B> {
+    /// Voting round info.
+    pub shared_voter_state: SharedVoterState,
+    /// Authority set info.
+    pub shared_authority_set: SharedAuthoritySet<Hash, BlockNumber>,
+    /// Receives notifications about justification events from Grandpa.
+    pub justification_stream: GrandpaJustificationStream<Block>,
+    /// Executor to drive the subscription manager in the Grandpa RPC handler.
+    pub subscription_executor: SubscriptionTaskExecutor,
+    /// Finality proof provider.
+    pub finality_provider: Arc<FinalityProofProvider<B, Block>>,
+}
+
+/// Full client dependencies.
+pub struct FullDeps<C, P, SC, B> {
+    /// The client instance to use.
+    pub client: Arc<C>,
+    /// Transaction pool instance.
+    pub pool: Arc<P>,
+    /// The SelectChain Strategy
+    pub select_chain: SC,
+    /// Whether to deny unsafe calls
+    pub deny_unsafe: DenyUnsafe,
+    /// BABE specific dependencies.
+    pub babe: BabeDeps,
+    /// GRANDPA specific dependencies.
+    pub grandpa: GrandpaDeps<B>,
+    /// Decodes the events pushed by `chain_subscribeEvents`.
+    pub decoder: RuntimeDecoder,
+    /// Writes the keys of `author_insertKey` and `author_rotateKeys` into the encrypted keystore,
+    /// if the node has one.
+    pub encrypted_keystore: Option<encrypted::Writer>,
+}
+
+/// Dependencies of a full node sealing its blocks with manual seal.
+pub struct SealingDeps<C, P> {
+    /// The client instance to use.
+    pub client: Arc<C>,
+    /// Transaction pool instance.
+    pub pool: Arc<P>,
+    /// Whether to deny unsafe calls
+    pub deny_unsafe: DenyUnsafe,
+    /// Sends the commands of the `engine_*` RPCs to the sealing task.
+    pub command_sink: futures::channel::mpsc::Sender<EngineCommand<Hash>>,
+    /// Decodes the events pushed by `chain_subscribeEvents`.
+    pub decoder: RuntimeDecoder,
+    /// Executor to drive the subscriptions of `chain_subscribeEvents`.
+    pub subscription_executor: SubscriptionTaskExecutor,
+    /// Writes the keys of `author_insertKey` and `author_rotateKeys` into the encrypted keystore,
+    /// if the node has one.
+    pub encrypted_keystore: Option<encrypted::Writer>,
+}
+
+/// A IO handler that uses all Full RPC extensions.
+pub type IoHandler = jsonrpc_core::IoHandler<sc_rpc::Metadata>;
+
+/// Instantiate all Full RPC extensions.
+pub fn create_full<C, P, SC, B>(
+    deps: FullDeps<C, P, SC, B>,
+) -> jsonrpc_core::IoHandler<sc_rpc_api::Metadata> where
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block> + HeaderMetadata<Block, Error=BlockChainError> + 'static,
+    C: BlockchainEvents<Block> + StorageProvider<Block, B>,
+    C: Send + Sync + 'static,
+    C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
+    C::Api: pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_fungible_assets_rpc::FungibleAssetsRuntimeApi<Block, AssetId, AccountId, Balance>,
+    C::Api: BabeApi<Block>,
+    C::Api: BlockBuilder<Block>,
+    P: TransactionPool + 'static,
+    SC: SelectChain<Block> +'static,
+    B: sc_client_api::Backend<Block> + Send + Sync + 'static,
+    B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashFor<Block>>,
+{
+    let mut io = jsonrpc_core::IoHandler::default();
+    let FullDeps {
+        client,
+        pool,
+        select_chain,
+        deny_unsafe,
+        babe,
+        grandpa,
+        decoder,
+        encrypted_keystore,
+    } = deps;
+
+    let BabeDeps {
+        keystore,
+        babe_config,
+        shared_epoch_changes,
+    } = babe;
+    let GrandpaDeps {
+        shared_voter_state,
+        shared_authority_set,
+        justification_stream,
+        subscription_executor,
+        finality_provider,
+    } = grandpa;
+
+    extend_with_runtime_rpcs(&mut io, client.clone(), pool, deny_unsafe);
+    extend_with_encrypted_keys(&mut io, encrypted_keystore, deny_unsafe);
+    io.extend_with(
+        EventsApi::to_delegate(Events::new(client.clone(), decoder, subscription_executor.clone()))
+    );
+    io.extend_with(
+        sc_consensus_babe_rpc::BabeApi::to_delegate(
+            BabeRpcHandler::new(
+                client,
+                shared_epoch_changes,
+                keystore,
+                babe_config,
+                select_chain,
+                deny_unsafe,
+            ),
+        )
+    );
+    io.extend_with(
+        sc_finality_grandpa_rpc::GrandpaApi::to_delegate(
+            GrandpaRpcHandler::new(
+                shared_authority_set,
+                shared_voter_state,
+                justification_stream,
+                subscription_executor,
+                finality_provider,
+            )
+        )
+    );
+
+    io
+}
+
+/// Instantiate the RPC extensions of a full node sealing its blocks with manual seal.
+pub fn create_sealing<C, P, B>(
+    deps: SealingDeps<C, P>,
+) -> jsonrpc_core::IoHandler<sc_rpc_api::Metadata> where
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block> + HeaderMetadata<Block, Error=BlockChainError> + 'static,
+    C: BlockchainEvents<Block> + StorageProvider<Block, B>,
+    C: Send + Sync + 'static,
+    C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
+    C::Api: pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_fungible_assets_rpc::FungibleAssetsRuntimeApi<Block, AssetId, AccountId, Balance>,
+    C::Api: BlockBuilder<Block>,
+    P: TransactionPool + 'static,
+    B: sc_client_api::Backend<Block> + Send + Sync + 'static,
+{
+    let mut io = jsonrpc_core::IoHandler::default();
+    let SealingDeps {
+        client,
+        pool,
+        deny_unsafe,
+        command_sink,
+        decoder,
+        subscription_executor,
+        encrypted_keystore,
+    } = deps;
+
+    extend_with_runtime_rpcs(&mut io, client.clone(), pool, deny_unsafe);
+    extend_with_encrypted_keys(&mut io, encrypted_keystore, deny_unsafe);
+    io.extend_with(
+        EventsApi::to_delegate(Events::new(client, decoder, subscription_executor))
+    );
+    io.extend_with(
+        ManualSealApi::to_delegate(ManualSeal::new(command_sink))
+    );
+
+    io
+}
+
+/// Adds the RPCs of the runtime pallets, served by every full node.
+fn extend_with_runtime_rpcs<C, P>(
+    io: &mut jsonrpc_core::IoHandler<sc_rpc_api::Metadata>,
+    client: Arc<C>,
+    pool: Arc<P>,
+    deny_unsafe: DenyUnsafe,
+) where
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block> + HeaderMetadata<Block, Error=BlockChainError> + 'static,
+    C: Send + Sync + 'static,
+    C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
+    C::Api: pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_fungible_assets_rpc::FungibleAssetsRuntimeApi<Block, AssetId, AccountId, Balance>,
+    C::Api: BlockBuilder<Block>,
+    P: TransactionPool + 'static,
+{
+    use substrate_frame_rpc_system::{FullSystem, SystemApi};
+    use pallet_contracts_rpc::{Contracts, ContractsApi};
+    use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
+    use pallet_fungible_assets_rpc::{FungibleAssets, FungibleAssetsApi};
+
+    io.extend_with(
+        SystemApi::to_delegate(FullSystem::new(client.clone(), pool, deny_unsafe))
+    );
+    // Making synchronous calls in light client freezes the browser currently,
+    // more context: https://github.com/paritytech/substrate/pull/3480
+    // These RPCs should use an asynchronous caller instead.
+    io.extend_with(
+        ContractsApi::to_delegate(Contracts::new(client.clone()))
+    );
+    io.extend_with(
+        TransactionPaymentApi::to_delegate(TransactionPayment::new(client.clone()))
+    );
+    io.extend_with(
+        FungibleAssetsApi::to_delegate(FungibleAssets::<_, (Block, Balance)>::new(client.clone()))
+    );
+}
+
+/// Replaces `author_insertKey` and `author_rotateKeys` with [`EncryptedKeys`] when the keystore
+/// is encrypted. The RPCs of the node are merged after those of Substrate, so theirs are overridden.
+fn extend_with_encrypted_keys(
+    io: &mut jsonrpc_core::IoHandler<sc_rpc_api::Metadata>,
+    writer: Option<encrypted::Writer>,
+    deny_unsafe: DenyUnsafe,
+) {
+    if let Some(writer) = writer {
+        io.extend_with(EncryptedKeysApi::to_delegate(EncryptedKeys { writer, deny_unsafe }));
+    }
+}
+
+/// Instantiate all Light RPC extensions.
+pub fn create_light<C, P, M, F>(
+    deps: LightDeps<C, F, P>,
+) -> jsonrpc_core::IoHandler<M> where
+    C: sp_blockchain::HeaderBackend<Block>,
+    C: Send + Sync + 'static,
+    F: sc_client_api::light::Fetcher<Block> + 'static,
+    P: TransactionPool + 'static,
+    M: jsonrpc_core::Metadata + Default,
+{
+    use substrate_frame_rpc_system::{LightSystem, SystemApi};
+    use crate::light_rpc::{
+        LightContractsApi, LightFungibleAssetsApi, LightTransactionPaymentApi, RemoteRuntime,
+    };
+
+    let LightDeps {
+        client,
+        pool,
+        remote_blockchain,
+        fetcher
+    } = deps;
+    let mut io = jsonrpc_core::IoHandler::default();
+    let remote_runtime = RemoteRuntime::new(client.clone(), remote_blockchain.clone(), fetcher.clone());
+    io.extend_with(
+        SystemApi::<Hash, AccountId, Index>::to_delegate(LightSystem::new(client, remote_blockchain, fetcher, pool))
+    );
+    io.extend_with(
+        LightContractsApi::to_delegate(remote_runtime.clone())
+    );
+    io.extend_with(
+        LightTransactionPaymentApi::to_delegate(remote_runtime.clone())
+    );
+    io.extend_with(
+        LightFungibleAssetsApi::to_delegate(remote_runtime)
+    );
+
+    io
+}
+
+/// Which blocks `chain_subscribeEvents` follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventBlocks {
+    /// Every new best block.
+    Best,
+    /// Every finalized block, in order.
+    Finalized,
+}
+
+impl Default for EventBlocks {
+    fn default() -> Self {
+        EventBlocks::Best
+    }
+}
+
+/// Filters of `chain_subscribeEvents`. An event is pushed if it matches all of them.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct EventFilter {
+    /// Name of the pallet, such as `AssetNFT`.
+    pub pallet: Option<String>,
+    /// Name of the event, such as `Minted`.
+    pub event: Option<String>,
+    /// An account the event names.
+    pub account: Option<AccountId>,
+    /// An asset the event names.
+    pub asset: Option<AssetRef>,
+    /// Whether to follow the best or the finalized blocks, best by default.
+    pub blocks: EventBlocks,
+}
+
+impl EventFilter {
+    fn matches(&self, pallet: &str, name: &str, event: &DecodedEvent) -> bool {
+        self.pallet.as_ref().map_or(true, |filter| filter == pallet)
+            && self.event.as_ref().map_or(true, |filter| filter == name)
+            && self.account.as_ref().map_or(true, |filter| event.accounts.contains(filter))
+            && self.asset.as_ref().map_or(true, |filter| event.assets.contains(filter))
+    }
+}
+
+/// The key RPCs of `author` for an encrypted keystore, which Substrate would only keep in memory.
+#[rpc]
+pub trait EncryptedKeysApi {
+    /// Inserts a key into the keystore, encrypting its file.
+    #[rpc(name = "author_insertKey")]
+    fn insert_key(&self, key_type: String, suri: String, public: Bytes) -> jsonrpc_core::Result<()>;
+
+    /// Generates new session keys into the keystore, encrypting their files, and returns their
+    /// public keys.
+    #[rpc(name = "author_rotateKeys")]
+    fn rotate_keys(&self) -> jsonrpc_core::Result<Bytes>;
+}
+
+/// A struct that implements the [`EncryptedKeysApi`].
+pub struct EncryptedKeys {
+    writer: encrypted::Writer,
+    deny_unsafe: DenyUnsafe,
+}
+
+fn keys_error(message: String) -> jsonrpc_core::Error {
+    jsonrpc_core::Error {
+        code: jsonrpc_core::ErrorCode::InternalError,
+        message,
+        data: None,
+    }
+}
+
+impl EncryptedKeysApi for EncryptedKeys {
+    fn insert_key(&self, key_type: String, suri: String, public: Bytes) -> jsonrpc_core::Result<()> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let key_type = sp_core::crypto::KeyTypeId::try_from(key_type.as_str())
+            .map_err(|_| keys_error(format!("Invalid key type `{}`", key_type)))?;
+        self.writer.insert(key_type, &suri, &public).map_err(|e| keys_error(e.to_string()))
+    }
+
+    fn rotate_keys(&self) -> jsonrpc_core::Result<Bytes> {
+        self.deny_unsafe.check_if_safe()?;
+
+        // The session keys are derived from a fresh phrase, as `keys provision` does.
+        let (_, phrase, _) = <sp_core::sr25519::Pair as sp_core::Pair>::generate_with_phrase(None);
+        let keys = crate::keys::provision(&phrase, |key_type, seed, public| {
+            self.writer.insert(key_type, seed, public).map_err(|e| e.to_string())
+        }).map_err(|e| keys_error(e.to_string()))?;
+        Ok(keys.encode().into())
+    }
+}
+
+/// An event pushed by `chain_subscribeEvents`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockEvent {
+    /// Index of the event in the block.
+    pub index: u32,
+    /// Index of the extrinsic which emitted the event, `None` during initialization and
+    /// finalization of the block.
+    pub extrinsic_index: Option<u32>,
+    /// Name of the pallet.
+    pub pallet: String,
+    /// Name of the event.
+    pub event: String,
+    /// The accounts the event names.
+    pub accounts: Vec<AccountId>,
+    /// The assets the event names.
+    pub assets: Vec<AssetRef>,
+    /// The fields of the event, decoded to JSON with their types in the runtime metadata.
+    /// `None` if the node does not know one of the types.
+    pub fields: Option<Vec<serde_json::Value>>,
+    /// The SCALE encoded fields of the event.
+    pub encoded_fields: Bytes,
+}
+
+/// The events of a block matching the filters of a subscription.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockEvents {
+    /// Number of the block.
+    pub block_number: BlockNumber,
+    /// Hash of the block.
+    pub block_hash: Hash,
+    /// The matching events, in block order.
+    pub events: Vec<BlockEvent>,
+}
+
+/// Subscriptions to the events of the chain, decoded by the node.
+#[rpc]
+pub trait EventsApi {
+    /// RPC metadata
+    type Metadata;
+
+    /// Pushes the events matching `filter` for every best or finalized block having any.
+    #[pubsub(subscription = "chain_events", subscribe, name = "chain_subscribeEvents")]
+    fn subscribe_events(
+        &self,
+        metadata: Self::Metadata,
+        subscriber: Subscriber<BlockEvents>,
+        filter: Option<EventFilter>,
+    );
+
+    /// Cancels a subscription of `chain_subscribeEvents`.
+    #[pubsub(subscription = "chain_events", unsubscribe, name = "chain_unsubscribeEvents")]
+    fn unsubscribe_events(
+        &self,
+        metadata: Option<Self::Metadata>,
+        id: SubscriptionId,
+    ) -> jsonrpc_core::Result<bool>;
+}
+
+/// A struct that implements the [`EventsApi`].
+pub struct Events<C, B> {
+    client: Arc<C>,
+    decoder: RuntimeDecoder,
+    names: Arc<Names>,
+    manager: SubscriptionManager,
+    _backend: PhantomData<B>,
+}
+
+impl<C, B> Events<C, B> {
+    /// Create new `Events` decoding the events of `client` with `decoder`, for the blocks running
+    /// its native runtime.
+    pub fn new(client: Arc<C>, decoder: RuntimeDecoder, executor: SubscriptionTaskExecutor) -> Self {
+        let names = Names::from_metadata(&(decoder.metadata)()).unwrap_or_else(|e| {
+            log::warn!("Events are pushed without names: {}", e);
+            Names::default()
+        });
+
+        Events {
+            client,
+            decoder,
+            names: Arc::new(names),
+            manager: SubscriptionManager::new(Arc::new(executor)),
+            _backend: PhantomData,
+        }
+    }
+}
+
+/// The events of the block `hash` matching `filter`, `None` if there are none.
+///
+/// Only blocks running the native runtime of `decoder` are decoded, a warning is logged the first
+/// time a block of another version is skipped.
+fn block_events<C, B>(
+    client: &C,
+    decoder: RuntimeDecoder,
+    names: &Names,
+    filter: &EventFilter,
+    skipped_versions: &mut HashSet<(String, u32)>,
+    hash: Hash,
+) -> Option<BlockEvents> where
+    C: HeaderBackend<Block> + StorageProvider<Block, B> + ProvideRuntimeApi<Block>,
+    C::Api: sp_api::Core<Block>,
+    B: sc_client_api::Backend<Block>,
+{
+    let number = client.number(hash).ok()??;
+    let version = match client.runtime_api().version(&BlockId::Hash(hash)) {
+        Ok(version) => version,
+        Err(e) => {
+            log::warn!("No runtime version for block #{} ({}): {:?}", number, hash, e);
+            return None;
+        },
+    };
+    let decoder = match RuntimeDecoder::for_version(&version) {
+        Some(native) if native.spec_name == decoder.spec_name => native,
+        _ => {
+            if skipped_versions.insert((version.spec_name.to_string(), version.spec_version)) {
+                log::warn!(
+                    "No events are pushed for blocks of {} version {}, from #{} ({}) on: the node decodes version {} only",
+                    version.spec_name, version.spec_version, number, hash, decoder.spec_version,
+                );
+            }
+            return None;
+        },
+    };
+
+    let events = client.storage(&BlockId::Hash(hash), &events_key()).ok()??;
+    let block = match (decoder.block)(&[], &events.0) {
+        Ok(block) => block,
+        Err(e) => {
+            log::warn!("Undecodable events in block #{} ({}): {}", number, hash, e);
+            return None;
+        },
+    };
+
+    let events = block.events.into_iter().enumerate().filter_map(|(index, event)| {
+        let (pallet, name) = names.event(event.event);
+        if !filter.matches(&pallet, &name, &event) {
+            return None;
+        }
+
+        let fields = fields_to_json(&pallet, names.event_arguments(event.event), &event.fields);
+        Some(BlockEvent {
+            index: index as u32,
+            extrinsic_index: event.extrinsic,
+            pallet,
+            event: name,
+            accounts: event.accounts,
+            assets: event.assets,
+            fields,
+            encoded_fields: event.fields.into(),
+        })
+    }).collect::<Vec<_>>();
+
+    if events.is_empty() {
+        None
+    } else {
+        Some(BlockEvents { block_number: number, block_hash: hash, events })
+    }
+}
+
+impl<C, B> EventsApi for Events<C, B> where
+    C: HeaderBackend<Block> + BlockchainEvents<Block> + StorageProvider<Block, B>,
+    C: ProvideRuntimeApi<Block> + Send + Sync + 'static,
+    C::Api: sp_api::Core<Block>,
+    B: sc_client_api::Backend<Block> + Send + Sync + 'static,
+{
+    type Metadata = sc_rpc::Metadata;
+
+    fn subscribe_events(
+        &self,
+        _metadata: Self::Metadata,
+        subscriber: Subscriber<BlockEvents>,
+        filter: Option<EventFilter>,
+    ) {
+        let filter = filter.unwrap_or_default();
+        let hashes: Box<dyn futures::Stream<Item = Hash> + Send + Unpin> = match filter.blocks {
+            EventBlocks::Best => Box::new(
+                self.client.import_notification_stream()
+                    .filter(|notification| future::ready(notification.is_new_best))
+                    .map(|notification| notification.hash)
+            ),
+            EventBlocks::Finalized => {
+                let client = self.client.clone();
+                let mut last_finalized = client.info().finalized_number;
+                // A notification may stand for several blocks finalized at once.
+                Box::new(self.client.finality_notification_stream().flat_map(move |notification| {
+                    let number = *notification.header.number();
+                    let hashes = (last_finalized + 1..number)
+                        .filter_map(|number| client.hash(number).ok().flatten())
+                        .chain(std::iter::once(notification.hash))
+                        .collect::<Vec<_>>();
+                    last_finalized = number;
+                    stream::iter(hashes)
+                }))
+            },
+        };
+
+        let client = self.client.clone();
+        let decoder = self.decoder;
+        let names = self.names.clone();
+        let mut skipped_versions = HashSet::new();
+        let stream = hashes
+            .filter_map(move |hash| future::ready(
+                block_events(&*client, decoder, &names, &filter, &mut skipped_versions, hash)
+            ))
+            .map(|events| Ok::<_, ()>(events))
+            .compat();
+
+        self.manager.add(subscriber, |sink| {
+            let stream = stream.map(|events| Ok(events));
+            sink.sink_map_err(|e| log::warn!("Error sending notifications: {:?}", e))
+                .send_all(stream)
+                .map(|_| ())
+        });
+    }
+
+    fn unsubscribe_events(
+        &self,
+        _metadata: Option<Self::Metadata>,
+        id: SubscriptionId,
+    ) -> jsonrpc_core::Result<bool> {
+        Ok(self.manager.cancel(id))
+    }
+}
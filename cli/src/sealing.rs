@@ -0,0 +1,72 @@
+//! Inherent data of the blocks sealed with `--sealing`.
+//!
+//! The timestamp pallet requires blocks at least `MinimumPeriod` apart, half the slot duration,
+//! while manual seal may seal blocks back to back. Each sealed block is therefore timestamped a
+//! slot duration after the previous one, or at the wall clock time if that is later.
+
+use parking_lot::Mutex;
+use sp_inherents::{InherentData, InherentIdentifier, ProvideInherentData};
+use sp_timestamp::{InherentError, INHERENT_IDENTIFIER};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Provides the timestamps of sealed blocks, a slot duration apart at least.
+pub struct SealingTimestamp {
+	slot_duration: u64,
+	last: Mutex<Option<u64>>,
+}
+
+impl SealingTimestamp {
+	/// Creates a provider of timestamps `slot_duration` milliseconds apart at least.
+	pub fn new(slot_duration: u64) -> Self {
+		SealingTimestamp { slot_duration, last: Mutex::new(None) }
+	}
+
+	/// The timestamp of the next sealed block.
+	fn next(&self) -> u64 {
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |now| now.as_millis() as u64);
+		let mut last = self.last.lock();
+		let next = last.map_or(now, |last| now.max(last + self.slot_duration));
+		*last = Some(next);
+		next
+	}
+}
+
+impl ProvideInherentData for SealingTimestamp {
+	fn inherent_identifier(&self) -> &'static InherentIdentifier {
+		&INHERENT_IDENTIFIER
+	}
+
+	fn provide_inherent_data(&self, inherent_data: &mut InherentData) -> Result<(), sp_inherents::Error> {
+		inherent_data.put_data(INHERENT_IDENTIFIER, &self.next())
+	}
+
+	fn error_to_string(&self, error: &[u8]) -> Option<String> {
+		InherentError::try_from(&INHERENT_IDENTIFIER, error).map(|e| format!("{:?}", e))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::traits::OnFinalize;
+	use gama_runtime::{constants::time::SLOT_DURATION, Origin, Runtime, Timestamp};
+
+	#[test]
+	fn blocks_sealed_back_to_back_set_their_timestamps() {
+		let provider = SealingTimestamp::new(SLOT_DURATION);
+		let storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+		sp_io::TestExternalities::new(storage).execute_with(|| {
+			for number in 1..=2 {
+				let mut inherent_data = InherentData::new();
+				provider.provide_inherent_data(&mut inherent_data).unwrap();
+				let now = inherent_data.get_data::<u64>(&INHERENT_IDENTIFIER).unwrap().unwrap();
+
+				frame_system::Module::<Runtime>::set_block_number(number);
+				// Panics unless `now` is `MinimumPeriod` after the previous timestamp.
+				Timestamp::set(Origin::none(), now).unwrap();
+				Timestamp::on_finalize(number);
+			}
+		});
+	}
+}
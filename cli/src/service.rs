@@ -0,0 +1,846 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(unused_extern_crates)]
+
+//! Service implementation. Specialized wrapper over substrate service.
+
+use std::sync::Arc;
+use sc_consensus_babe;
+use grandpa::{self, FinalityProofProvider as GrandpaFinalityProofProvider};
+use node_primitives::{Block, AccountId, AssetId, Balance, BlockNumber, Index};
+use sc_service::{
+	config::{Role, Configuration}, error::{Error as ServiceError},
+	RpcHandlers, TaskManager,
+};
+use sp_inherents::InherentDataProviders;
+use sc_network::{Event, NetworkService};
+use sp_runtime::traits::{Block as BlockT, BlakeTwo256};
+use sp_api::ConstructRuntimeApi;
+use sc_executor::NativeExecutionDispatch;
+use futures::prelude::*;
+use sc_client_api::{ExecutorProvider, RemoteBackend};
+
+use crate::{
+	chain_spec::{GrandpaParameters, IdentifyVariant}, decode::RuntimeDecoder,
+	indexer::{self, IndexDb, Indexer}, rpc::{self as node_rpc}, voting_rule::BackOffBehindBest,
+	GrandpaParams, HealthParams, Sealing, SigningParams,
+};
+
+pub use node_executor::{GamaExecutor, ColomboExecutor};
+
+type FullClient<RuntimeApi, Executor> = sc_service::TFullClient<Block, RuntimeApi, Executor>;
+type FullBackend = sc_service::TFullBackend<Block>;
+type FullSelectChain = sc_consensus::LongestChain<FullBackend, Block>;
+type FullGrandpaBlockImport<RuntimeApi, Executor> =
+	grandpa::GrandpaBlockImport<FullBackend, Block, FullClient<RuntimeApi, Executor>, FullSelectChain>;
+type LightBackend = sc_service::TLightBackend<Block>;
+type LightClient<RuntimeApi, Executor> = sc_service::TLightClient<Block, RuntimeApi, Executor>;
+
+/// Milliseconds between two GRANDPA gossip rounds, unless configured.
+const GRANDPA_GOSSIP_DURATION: u64 = 333;
+/// Number of blocks between two GRANDPA justifications, unless configured.
+const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
+
+/// The runtime APIs the node relies on, implemented by the runtime of every network.
+pub trait RuntimeApiCollection:
+	sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
+	+ sp_api::ApiExt<Block, Error = sp_blockchain::Error>
+	+ sp_consensus_babe::BabeApi<Block>
+	+ grandpa_primitives::GrandpaApi<Block>
+	+ sp_block_builder::BlockBuilder<Block>
+	+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>
+	+ pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber>
+	+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+	+ pallet_fungible_assets_rpc::FungibleAssetsRuntimeApi<Block, AssetId, AccountId, Balance>
+	+ sp_api::Metadata<Block>
+	+ sp_offchain::OffchainWorkerApi<Block>
+	+ sp_session::SessionKeys<Block>
+	+ sp_authority_discovery::AuthorityDiscoveryApi<Block>
+where
+	<Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
+{}
+
+impl<Api> RuntimeApiCollection for Api
+where
+	Api: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
+		+ sp_api::ApiExt<Block, Error = sp_blockchain::Error>
+		+ sp_consensus_babe::BabeApi<Block>
+		+ grandpa_primitives::GrandpaApi<Block>
+		+ sp_block_builder::BlockBuilder<Block>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>
+		+ pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ pallet_fungible_assets_rpc::FungibleAssetsRuntimeApi<Block, AssetId, AccountId, Balance>
+		+ sp_api::Metadata<Block>
+		+ sp_offchain::OffchainWorkerApi<Block>
+		+ sp_session::SessionKeys<Block>
+		+ sp_authority_discovery::AuthorityDiscoveryApi<Block>,
+	<Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
+{}
+
+/// `sc_service::new_full_parts`, opening the keystore as set by `signing` and letting the
+/// runtime sign with the remote signer if any.
+fn new_full_parts<RuntimeApi, Executor>(config: &Configuration, signing: &SigningParams) -> Result<
+	(FullClient<RuntimeApi, Executor>, Arc<FullBackend>, sc_keystore::KeyStorePtr, TaskManager),
+	ServiceError,
+> where
+	Executor: NativeExecutionDispatch + 'static,
+{
+	let keystore = crate::keystore::open(&config.keystore, signing).map_err(ServiceError::Other)?;
+	let task_manager = TaskManager::new(config.task_executor.clone(), config.prometheus_registry())?;
+
+	let executor = sc_executor::NativeExecutor::<Executor>::new(
+		config.wasm_method,
+		config.default_heap_pages,
+		config.max_runtime_instances,
+	);
+
+	let chain_spec = &config.chain_spec;
+	let fork_blocks = sc_chain_spec::get_extension::<sc_client_api::ForkBlocks<Block>>(chain_spec.extensions())
+		.cloned()
+		.unwrap_or_default();
+	let bad_blocks = sc_chain_spec::get_extension::<sc_client_api::BadBlocks<Block>>(chain_spec.extensions())
+		.cloned()
+		.unwrap_or_default();
+
+	let db_config = sc_client_db::DatabaseSettings {
+		state_cache_size: config.state_cache_size,
+		state_cache_child_ratio: config.state_cache_child_ratio.map(|v| (v, 100)),
+		pruning: config.pruning.clone(),
+		source: config.database.clone(),
+	};
+
+	// ImOnline signs its heartbeats in the offchain worker, with the keystore of the extensions.
+	let extensions = sc_client_api::execution_extensions::ExecutionExtensions::new(
+		config.execution_strategies.clone(),
+		Some(crate::keystore::signing(keystore.clone(), signing)),
+	);
+
+	let (client, backend) = sc_service::new_client(
+		db_config,
+		executor,
+		chain_spec.as_storage_builder(),
+		fork_blocks,
+		bad_blocks,
+		extensions,
+		Box::new(task_manager.spawn_handle()),
+		config.prometheus_registry().cloned(),
+		sc_service::ClientConfig {
+			offchain_worker_enabled: config.offchain_worker.enabled,
+			offchain_indexing_api: config.offchain_worker.indexing_enabled,
+		},
+	)?;
+
+	Ok((client, backend, keystore, task_manager))
+}
+
+pub fn new_partial<RuntimeApi, Executor>(config: &Configuration, signing: &SigningParams) -> Result<sc_service::PartialComponents<
+	FullClient<RuntimeApi, Executor>, FullBackend, FullSelectChain,
+	sp_consensus::DefaultImportQueue<Block, FullClient<RuntimeApi, Executor>>,
+	sc_transaction_pool::FullPool<Block, FullClient<RuntimeApi, Executor>>,
+	(
+		impl Fn(
+			node_rpc::DenyUnsafe,
+			sc_rpc::SubscriptionTaskExecutor,
+		) -> node_rpc::IoHandler,
+		(
+			sc_consensus_babe::BabeBlockImport<
+				Block,
+				FullClient<RuntimeApi, Executor>,
+				FullGrandpaBlockImport<RuntimeApi, Executor>,
+			>,
+			grandpa::LinkHalf<Block, FullClient<RuntimeApi, Executor>, FullSelectChain>,
+			sc_consensus_babe::BabeLink<Block>,
+		),
+		(
+			grandpa::SharedVoterState,
+			Arc<GrandpaFinalityProofProvider<FullBackend, Block>>,
+		),
+	)
+>, ServiceError> where
+	RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi::RuntimeApi:
+		RuntimeApiCollection<StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>>,
+	Executor: NativeExecutionDispatch + 'static,
+{
+	let (client, backend, keystore, task_manager) =
+		new_full_parts::<RuntimeApi, Executor>(&config, signing)?;
+	let client = Arc::new(client);
+
+	let select_chain = sc_consensus::LongestChain::new(backend.clone());
+
+	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
+		config.transaction_pool.clone(),
+		config.prometheus_registry(),
+		task_manager.spawn_handle(),
+		client.clone(),
+	);
+
+	let (grandpa_block_import, grandpa_link) = grandpa::block_import(
+		client.clone(), &(client.clone() as Arc<_>), select_chain.clone(),
+	)?;
+	let justification_import = grandpa_block_import.clone();
+
+	let (block_import, babe_link) = sc_consensus_babe::block_import(
+		sc_consensus_babe::Config::get_or_compute(&*client)?,
+		grandpa_block_import,
+		client.clone(),
+	)?;
+
+	let inherent_data_providers = sp_inherents::InherentDataProviders::new();
+
+	let import_queue = sc_consensus_babe::import_queue(
+		babe_link.clone(),
+		block_import.clone(),
+		Some(Box::new(justification_import)),
+		None,
+		client.clone(),
+		select_chain.clone(),
+		inherent_data_providers.clone(),
+		&task_manager.spawn_handle(),
+		config.prometheus_registry(),
+		sp_consensus::CanAuthorWithNativeVersion::new(client.executor().clone()),
+	)?;
+
+	let import_setup = (block_import, grandpa_link, babe_link);
+
+	let (rpc_extensions_builder, rpc_setup) = {
+		let (_, grandpa_link, babe_link) = &import_setup;
+
+		let justification_stream = grandpa_link.justification_stream();
+		let shared_authority_set = grandpa_link.shared_authority_set().clone();
+		let shared_voter_state = grandpa::SharedVoterState::empty();
+		let finality_proof_provider =
+			GrandpaFinalityProofProvider::new_for_service(backend.clone(), client.clone());
+
+		let rpc_setup = (shared_voter_state.clone(), finality_proof_provider.clone());
+
+		let babe_config = babe_link.config().clone();
+		let shared_epoch_changes = babe_link.epoch_changes().clone();
+
+		let client = client.clone();
+		let pool = transaction_pool.clone();
+		let select_chain = select_chain.clone();
+		let encrypted_keystore = crate::keystore::writer(&config.keystore, signing, keystore.clone());
+		let keystore = keystore.clone();
+		let decoder = RuntimeDecoder::for_chain(&config.chain_spec);
+
+		let rpc_extensions_builder = move |deny_unsafe, subscription_executor| {
+			let deps = node_rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				select_chain: select_chain.clone(),
+				deny_unsafe,
+				babe: node_rpc::BabeDeps {
+					babe_config: babe_config.clone(),
+					shared_epoch_changes: shared_epoch_changes.clone(),
+					keystore: keystore.clone(),
+				},
+				grandpa: node_rpc::GrandpaDeps {
+					shared_voter_state: shared_voter_state.clone(),
+					shared_authority_set: shared_authority_set.clone(),
+					justification_stream: justification_stream.clone(),
+					subscription_executor,
+					finality_provider: finality_proof_provider.clone(),
+				},
+				decoder,
+				encrypted_keystore: encrypted_keystore.clone(),
+			};
+
+			node_rpc::create_full(deps)
+		};
+
+		(rpc_extensions_builder, rpc_setup)
+	};
+
+	Ok(sc_service::PartialComponents {
+		client, backend, task_manager, keystore, select_chain, import_queue, transaction_pool,
+		inherent_data_providers,
+		other: (rpc_extensions_builder, import_setup, rpc_setup)
+	})
+}
+
+pub struct NewFullBase<RuntimeApi, Executor> {
+	pub task_manager: TaskManager,
+	pub inherent_data_providers: InherentDataProviders,
+	pub client: Arc<FullClient<RuntimeApi, Executor>>,
+	pub network: Arc<NetworkService<Block, <Block as BlockT>::Hash>>,
+	pub network_status_sinks: sc_service::NetworkStatusSinks<Block>,
+	pub transaction_pool: Arc<sc_transaction_pool::FullPool<Block, FullClient<RuntimeApi, Executor>>>,
+}
+
+/// Options of a full node set on the command line, beyond the Substrate configuration.
+#[derive(Debug, Clone, Default)]
+pub struct FullOptions {
+	/// GRANDPA settings overriding the chain spec.
+	pub grandpa: GrandpaParams,
+	/// Whether to run the indexer and serve the `indexer_*` RPCs.
+	pub indexer: bool,
+	/// The health endpoint, served if it has a port.
+	pub health: HealthParams,
+	/// The keystore and the remote signer.
+	pub signing: SigningParams,
+}
+
+/// Opens the database of the indexer, next to the chain database.
+fn open_index_db(config: &Configuration) -> Result<Arc<IndexDb>, ServiceError> {
+	let path = config.database.path()
+		.and_then(|path| path.parent())
+		.ok_or_else(|| ServiceError::Other("The indexer needs an on-disk database".into()))?
+		.join("indexer");
+	let db = IndexDb::open(&path)
+		.map_err(|e| ServiceError::Other(format!("Failed to open the index at {}: {}", path.display(), e)))?;
+	Ok(Arc::new(db))
+}
+
+/// Creates a full service from the configuration.
+pub fn new_full_base<RuntimeApi, Executor>(
+	config: Configuration,
+	options: FullOptions,
+	with_startup_data: impl FnOnce(
+		&sc_consensus_babe::BabeBlockImport<
+			Block,
+			FullClient<RuntimeApi, Executor>,
+			FullGrandpaBlockImport<RuntimeApi, Executor>,
+		>,
+		&sc_consensus_babe::BabeLink<Block>,
+	)
+) -> Result<NewFullBase<RuntimeApi, Executor>, ServiceError> where
+	RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi::RuntimeApi:
+		RuntimeApiCollection<StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>>,
+	Executor: NativeExecutionDispatch + 'static,
+{
+	let sc_service::PartialComponents {
+		client, backend, mut task_manager, import_queue, keystore, select_chain, transaction_pool,
+		inherent_data_providers,
+		other: (rpc_extensions_builder, import_setup, rpc_setup),
+	} = new_partial::<RuntimeApi, Executor>(&config, &options.signing)?;
+
+	let (shared_voter_state, finality_proof_provider) = rpc_setup;
+	let FullOptions { grandpa: grandpa_params, indexer, health, signing } = options;
+
+	let index_db = if indexer { Some(open_index_db(&config)?) } else { None };
+	let rpc_extensions_builder = {
+		let client = client.clone();
+		let index_db = index_db.clone();
+
+		move |deny_unsafe, subscription_executor| {
+			let mut io = rpc_extensions_builder(deny_unsafe, subscription_executor);
+			if let Some(db) = &index_db {
+				use indexer::rpc::{Indexer as IndexerRpc, IndexerApi};
+
+				io.extend_with(
+					IndexerApi::to_delegate(IndexerRpc::new(client.clone(), db.clone()))
+				);
+			}
+			io
+		}
+	};
+
+	let (network, network_status_sinks, system_rpc_tx, network_starter) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue,
+			on_demand: None,
+			block_announce_validator_builder: None,
+			finality_proof_request_builder: None,
+			finality_proof_provider: Some(finality_proof_provider.clone()),
+		})?;
+
+	if config.offchain_worker.enabled {
+		sc_service::build_offchain_workers(
+			&config, backend.clone(), task_manager.spawn_handle(), client.clone(), network.clone(),
+		);
+	}
+
+	let role = config.role.clone();
+	let force_authoring = config.force_authoring;
+	let name = config.network.node_name.clone();
+	let enable_grandpa = !config.disable_grandpa;
+	let grandpa_parameters =
+		sc_chain_spec::get_extension::<GrandpaParameters>(config.chain_spec.extensions())
+			.cloned()
+			.unwrap_or_default();
+	let prometheus_registry = config.prometheus_registry().cloned();
+	let telemetry_connection_sinks = sc_service::TelemetryConnectionSinks::default();
+
+	if let Some(registry) = prometheus_registry.as_ref() {
+		let metrics = crate::metrics::Metrics::register(registry)
+			.map_err(|e| ServiceError::Other(format!("Failed to register the Aochain metrics: {}", e)))?;
+		let decoder = if config.chain_spec.is_colombo() { crate::metrics::COLOMBO } else { crate::metrics::GAMA };
+		task_manager.spawn_handle().spawn("aochain-metrics", metrics.run::<_, FullBackend>(decoder, client.clone()));
+	}
+
+	if let Some(db) = index_db {
+		let indexer = Indexer::new(db);
+		task_manager.spawn_handle().spawn_blocking("indexer", indexer.run::<_, FullBackend>(client.clone()));
+	}
+
+	if let Some(port) = health.port {
+		let endpoint = crate::health::Health::new(client.clone(), (&health).into());
+		task_manager.spawn_handle().spawn(
+			"health-endpoint",
+			endpoint.run(network_status_sinks.clone(), port, health.external),
+		);
+	}
+
+	sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+		config,
+		backend: backend.clone(),
+		client: client.clone(),
+		keystore: keystore.clone(),
+		network: network.clone(),
+		rpc_extensions_builder: Box::new(rpc_extensions_builder),
+		transaction_pool: transaction_pool.clone(),
+		task_manager: &mut task_manager,
+		on_demand: None,
+		remote_blockchain: None,
+		telemetry_connection_sinks: telemetry_connection_sinks.clone(),
+		network_status_sinks: network_status_sinks.clone(),
+		system_rpc_tx,
+	})?;
+
+	let (block_import, grandpa_link, babe_link) = import_setup;
+
+	(with_startup_data)(&block_import, &babe_link);
+
+	if let sc_service::config::Role::Authority { .. } = &role {
+		let proposer = sc_basic_authorship::ProposerFactory::new(
+			client.clone(),
+			transaction_pool.clone(),
+			prometheus_registry.as_ref(),
+		);
+
+		let can_author_with =
+			sp_consensus::CanAuthorWithNativeVersion::new(client.executor().clone());
+
+		let babe_config = sc_consensus_babe::BabeParams {
+			keystore: keystore.clone(),
+			client: client.clone(),
+			select_chain,
+			env: proposer,
+			block_import,
+			sync_oracle: network.clone(),
+			inherent_data_providers: inherent_data_providers.clone(),
+			force_authoring,
+			babe_link,
+			can_author_with,
+		};
+
+		// Blocks are sealed through the remote signer by the worker of the node.
+		if signing.remote_signer.is_some() {
+			let keystore = crate::keystore::signing(keystore.clone(), &signing);
+			let babe = crate::keystore::babe::start_babe(keystore, babe_config)?;
+			task_manager.spawn_essential_handle().spawn_blocking("babe-proposer", babe);
+		} else {
+			let babe = sc_consensus_babe::start_babe(babe_config)?;
+			task_manager.spawn_essential_handle().spawn_blocking("babe-proposer", babe);
+		}
+	}
+
+	// Spawn authority discovery module.
+	if matches!(role, Role::Authority{..} | Role::Sentry {..}) {
+		let (sentries, authority_discovery_role) = match role {
+			sc_service::config::Role::Authority { ref sentry_nodes } => (
+				sentry_nodes.clone(),
+				sc_authority_discovery::Role::Authority (
+					keystore.clone(),
+				),
+			),
+			sc_service::config::Role::Sentry {..} => (
+				vec![],
+				sc_authority_discovery::Role::Sentry,
+			),
+			_ => unreachable!("Due to outer matches! constraint; qed.")
+		};
+
+		let dht_event_stream = network.event_stream("authority-discovery")
+			.filter_map(|e| async move { match e {
+				Event::Dht(e) => Some(e),
+				_ => None,
+			}}).boxed();
+		let (authority_discovery_worker, _service) = sc_authority_discovery::new_worker_and_service(
+			client.clone(),
+			network.clone(),
+			sentries,
+			dht_event_stream,
+			authority_discovery_role,
+			prometheus_registry.clone(),
+		);
+
+		task_manager.spawn_handle().spawn("authority-discovery-worker", authority_discovery_worker);
+	}
+
+	// if the node isn't actively participating in consensus then it doesn't
+	// need a keystore, regardless of which protocol we use below.
+	let keystore = if role.is_authority() {
+		Some(crate::keystore::signing(keystore, &signing))
+	} else {
+		None
+	};
+
+	let observer = grandpa_params.observer && !role.is_authority();
+	if grandpa_params.observer && role.is_authority() {
+		log::warn!("Authorities run the full GRANDPA voter, ignoring --grandpa-observer");
+	}
+
+	let config = grandpa::Config {
+		gossip_duration: std::time::Duration::from_millis(
+			grandpa_params.gossip_duration
+				.or(grandpa_parameters.gossip_duration)
+				.unwrap_or(GRANDPA_GOSSIP_DURATION)
+		),
+		justification_period: grandpa_params.justification_period
+			.or(grandpa_parameters.justification_period)
+			.unwrap_or(GRANDPA_JUSTIFICATION_PERIOD),
+		name: Some(name),
+		observer_enabled: observer,
+		keystore,
+		is_authority: role.is_network_authority(),
+	};
+
+	if enable_grandpa && observer {
+		// the observer follows finality from the votes of the authorities without voting or
+		// gossiping its own rounds. It has not been tested extensively yet, and having most
+		// nodes in a network run it could lead to finality stalls, hence it is opt-in.
+		task_manager.spawn_essential_handle().spawn_blocking(
+			"grandpa-observer",
+			grandpa::run_grandpa_observer(config, grandpa_link, network.clone())?
+		);
+	} else if enable_grandpa {
+		// start the full GRANDPA voter
+		let voting_rule = match grandpa_params.vote_back_off.or(grandpa_parameters.vote_back_off) {
+			Some(back_off) => grandpa::VotingRulesBuilder::default()
+				.add(BackOffBehindBest(back_off))
+				.build(),
+			None => grandpa::VotingRulesBuilder::default().build(),
+		};
+
+		let grandpa_config = grandpa::GrandpaParams {
+			config,
+			link: grandpa_link,
+			network: network.clone(),
+			inherent_data_providers: inherent_data_providers.clone(),
+			telemetry_on_connect: Some(telemetry_connection_sinks.on_connect_stream()),
+			voting_rule,
+			prometheus_registry,
+			shared_voter_state,
+		};
+
+		// the GRANDPA voter task is considered infallible, i.e.
+		// if it fails we take down the service with it.
+		task_manager.spawn_essential_handle().spawn_blocking(
+			"grandpa-voter",
+			grandpa::run_grandpa_voter(grandpa_config)?
+		);
+	} else {
+		grandpa::setup_disabled_grandpa(
+			client.clone(),
+			&inherent_data_providers,
+			network.clone(),
+		)?;
+	}
+
+	network_starter.start_network();
+	Ok(NewFullBase {
+		task_manager, inherent_data_providers, client, network, network_status_sinks,
+		transaction_pool,
+	})
+}
+
+/// Builds a new service for a full client.
+pub fn new_full<RuntimeApi, Executor>(config: Configuration, options: FullOptions)
+-> Result<TaskManager, ServiceError> where
+	RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi::RuntimeApi:
+		RuntimeApiCollection<StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>>,
+	Executor: NativeExecutionDispatch + 'static,
+{
+	new_full_base::<RuntimeApi, Executor>(config, options, |_, _| ()).map(|NewFullBase { task_manager, .. }| {
+		task_manager
+	})
+}
+
+/// Builds a full service sealing its blocks with manual seal instead of BABE and GRANDPA.
+///
+/// Every mode serves `engine_createBlock` and `engine_finalizeBlock`. `Instant` also seals a
+/// block for every transaction entering the pool and `Interval` on a timer, both finalizing it.
+/// The keystore is opened as set by `signing`, like the one of a full node.
+pub fn new_sealing<RuntimeApi, Executor>(config: Configuration, sealing: Sealing, signing: SigningParams)
+-> Result<TaskManager, ServiceError> where
+	RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi::RuntimeApi:
+		RuntimeApiCollection<StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>>,
+	Executor: NativeExecutionDispatch + 'static,
+{
+	use sc_consensus_manual_seal::{EngineCommand, ManualSealParams};
+	use sp_transaction_pool::TransactionPool;
+
+	let (client, backend, keystore, mut task_manager) =
+		new_full_parts::<RuntimeApi, Executor>(&config, &signing)?;
+	let client = Arc::new(client);
+
+	let select_chain = sc_consensus::LongestChain::new(backend.clone());
+
+	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
+		config.transaction_pool.clone(),
+		config.prometheus_registry(),
+		task_manager.spawn_handle(),
+		client.clone(),
+	);
+
+	let import_queue = sc_consensus_manual_seal::import_queue(
+		Box::new(client.clone()),
+		&task_manager.spawn_handle(),
+		config.prometheus_registry(),
+	);
+
+	let (network, network_status_sinks, system_rpc_tx, network_starter) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue,
+			on_demand: None,
+			block_announce_validator_builder: None,
+			finality_proof_request_builder: None,
+			finality_proof_provider: None,
+		})?;
+
+	if config.offchain_worker.enabled {
+		sc_service::build_offchain_workers(
+			&config, backend.clone(), task_manager.spawn_handle(), client.clone(), network.clone(),
+		);
+	}
+
+	let (command_sink, rpc_commands) = futures::channel::mpsc::channel(1024);
+
+	let rpc_extensions_builder = {
+		let client = client.clone();
+		let pool = transaction_pool.clone();
+		let decoder = RuntimeDecoder::for_chain(&config.chain_spec);
+		let encrypted_keystore = crate::keystore::writer(&config.keystore, &signing, keystore.clone());
+
+		move |deny_unsafe, subscription_executor| {
+			node_rpc::create_sealing::<_, _, FullBackend>(node_rpc::SealingDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+				command_sink: command_sink.clone(),
+				decoder,
+				subscription_executor,
+				encrypted_keystore: encrypted_keystore.clone(),
+			})
+		}
+	};
+
+	let prometheus_registry = config.prometheus_registry().cloned();
+
+	sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+		config,
+		backend,
+		client: client.clone(),
+		keystore,
+		network,
+		rpc_extensions_builder: Box::new(rpc_extensions_builder),
+		transaction_pool: transaction_pool.clone(),
+		task_manager: &mut task_manager,
+		on_demand: None,
+		remote_blockchain: None,
+		telemetry_connection_sinks: sc_service::TelemetryConnectionSinks::default(),
+		network_status_sinks,
+		system_rpc_tx,
+	})?;
+
+	let seal_and_finalize = |create_empty| EngineCommand::SealNewBlock {
+		create_empty,
+		finalize: true,
+		parent_hash: None,
+		sender: None,
+	};
+
+	let commands_stream: Box<dyn Stream<Item = EngineCommand<_>> + Send + Unpin> = match sealing {
+		Sealing::Manual => Box::new(rpc_commands),
+		Sealing::Instant => {
+			let pool_commands = transaction_pool.import_notification_stream()
+				.map(move |_| seal_and_finalize(false));
+			Box::new(futures::stream::select(rpc_commands, pool_commands))
+		},
+		Sealing::Interval(millis) => {
+			let interval = std::time::Duration::from_millis(millis);
+			let timer_commands = futures::stream::unfold((), move |_| async move {
+				futures_timer::Delay::new(interval).await;
+				Some((seal_and_finalize(true), ()))
+			}).boxed();
+			Box::new(futures::stream::select(rpc_commands, timer_commands))
+		},
+	};
+
+	// Blocks sealed back to back are still a slot duration apart, as the runtime requires.
+	let slot_duration = sc_consensus_babe::Config::get_or_compute(&*client)?.slot_duration;
+	let inherent_data_providers = InherentDataProviders::new();
+	inherent_data_providers
+		.register_provider(crate::sealing::SealingTimestamp::new(slot_duration))
+		.map_err(Into::into)
+		.map_err(sp_consensus::error::Error::InherentData)?;
+
+	let proposer = sc_basic_authorship::ProposerFactory::new(
+		client.clone(),
+		transaction_pool.clone(),
+		prometheus_registry.as_ref(),
+	);
+
+	let authorship_future = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+		block_import: client.clone(),
+		env: proposer,
+		client,
+		pool: transaction_pool.pool().clone(),
+		commands_stream,
+		select_chain,
+		consensus_data_provider: None,
+		inherent_data_providers,
+	});
+
+	task_manager.spawn_essential_handle().spawn_blocking("manual-seal", authorship_future);
+
+	network_starter.start_network();
+	Ok(task_manager)
+}
+
+pub fn new_light_base<RuntimeApi, Executor>(config: Configuration) -> Result<(
+	TaskManager, RpcHandlers, Arc<LightClient<RuntimeApi, Executor>>,
+	Arc<NetworkService<Block, <Block as BlockT>::Hash>>,
+	Arc<sc_transaction_pool::LightPool<
+		Block,
+		LightClient<RuntimeApi, Executor>,
+		sc_network::config::OnDemand<Block>,
+	>>
+), ServiceError> where
+	RuntimeApi: ConstructRuntimeApi<Block, LightClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi::RuntimeApi:
+		RuntimeApiCollection<StateBackend = sc_client_api::StateBackendFor<LightBackend, Block>>,
+	Executor: NativeExecutionDispatch + 'static,
+{
+	let (client, backend, keystore, mut task_manager, on_demand) =
+		sc_service::new_light_parts::<Block, RuntimeApi, Executor>(&config)?;
+
+	let select_chain = sc_consensus::LongestChain::new(backend.clone());
+
+	let transaction_pool = Arc::new(sc_transaction_pool::BasicPool::new_light(
+		config.transaction_pool.clone(),
+		config.prometheus_registry(),
+		task_manager.spawn_handle(),
+		client.clone(),
+		on_demand.clone(),
+	));
+
+	let grandpa_block_import = grandpa::light_block_import(
+		client.clone(), backend.clone(), &(client.clone() as Arc<_>),
+		Arc::new(on_demand.checker().clone()),
+	)?;
+
+	let finality_proof_import = grandpa_block_import.clone();
+	let finality_proof_request_builder =
+		finality_proof_import.create_finality_proof_request_builder();
+
+	let (babe_block_import, babe_link) = sc_consensus_babe::block_import(
+		sc_consensus_babe::Config::get_or_compute(&*client)?,
+		grandpa_block_import,
+		client.clone(),
+	)?;
+
+	let inherent_data_providers = sp_inherents::InherentDataProviders::new();
+
+	let import_queue = sc_consensus_babe::import_queue(
+		babe_link,
+		babe_block_import,
+		None,
+		Some(Box::new(finality_proof_import)),
+		client.clone(),
+		select_chain.clone(),
+		inherent_data_providers.clone(),
+		&task_manager.spawn_handle(),
+		config.prometheus_registry(),
+		sp_consensus::NeverCanAuthor,
+	)?;
+
+	let finality_proof_provider =
+		GrandpaFinalityProofProvider::new_for_service(backend.clone(), client.clone());
+
+	let (network, network_status_sinks, system_rpc_tx, network_starter) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue,
+			on_demand: Some(on_demand.clone()),
+			block_announce_validator_builder: None,
+			finality_proof_request_builder: Some(finality_proof_request_builder),
+			finality_proof_provider: Some(finality_proof_provider),
+		})?;
+	network_starter.start_network();
+
+	if config.offchain_worker.enabled {
+		sc_service::build_offchain_workers(
+			&config, backend.clone(), task_manager.spawn_handle(), client.clone(), network.clone(),
+		);
+	}
+
+	let light_deps = node_rpc::LightDeps {
+		remote_blockchain: backend.remote_blockchain(),
+		fetcher: on_demand.clone(),
+		client: client.clone(),
+		pool: transaction_pool.clone(),
+	};
+
+	let rpc_extensions = node_rpc::create_light(light_deps);
+
+	let rpc_handlers =
+		sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+			on_demand: Some(on_demand),
+			remote_blockchain: Some(backend.remote_blockchain()),
+			rpc_extensions_builder: Box::new(sc_service::NoopRpcExtensionBuilder(rpc_extensions)),
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			config, keystore, backend, network_status_sinks, system_rpc_tx,
+			network: network.clone(),
+			telemetry_connection_sinks: sc_service::TelemetryConnectionSinks::default(),
+			task_manager: &mut task_manager,
+		})?;
+
+	Ok((task_manager, rpc_handlers, client, network, transaction_pool))
+}
+
+/// Builds a new service for a light client.
+pub fn new_light<RuntimeApi, Executor>(config: Configuration) -> Result<TaskManager, ServiceError> where
+	RuntimeApi: ConstructRuntimeApi<Block, LightClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi::RuntimeApi:
+		RuntimeApiCollection<StateBackend = sc_client_api::StateBackendFor<LightBackend, Block>>,
+	Executor: NativeExecutionDispatch + 'static,
+{
+	new_light_base::<RuntimeApi, Executor>(config).map(|(task_manager, _, _, _, _)| {
+		task_manager
+	})
+}
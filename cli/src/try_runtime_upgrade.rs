@@ -0,0 +1,430 @@
+//! Dry run of a runtime upgrade against the local chain state.
+//!
+//! The state of a block is copied into memory, from the database or from a file written by
+//! `export-state`, and the following blocks are executed twice: with the current runtime, which
+//! should reproduce the stored state roots, and with the candidate runtime in `:code`, which runs
+//! `on_runtime_upgrade` and the migrations when initializing the first block. Comparing both
+//! runs shows the weight of the upgrade, the calls it breaks and the storage it rewrites.
+//!
+//! Signed extrinsics commit to the spec and transaction versions of the runtime they were signed
+//! for, so the candidate runtime rejects them with a bad proof as soon as it bumps either. Then
+//! only the inherents and unsigned extrinsics of the stored blocks are executed, by both
+//! runtimes to keep the runs comparable, and the skipped extrinsics are reported. The stored
+//! state roots are not reproduced in that case.
+
+use crate::TryRuntimeUpgradeCmd;
+use codec::{Compact, Decode, Encode};
+use frame_support::weights::Weight;
+use node_primitives::{Block, BlockNumber, Hash, Header};
+use sc_cli::{CliConfiguration, Result, SharedParams};
+use sc_client_api::{BlockBackend, StorageProvider};
+use sc_executor::{NativeExecutionDispatch, NativeExecutor};
+use sc_service::{Configuration, TFullBackend};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	hexdisplay::HexDisplay,
+	storage::{well_known_keys, ChildInfo, Storage, StorageChild, StorageKey},
+	traits::SpawnNamed,
+	twox_128,
+};
+use sp_runtime::{
+	generic::BlockId, traits::{BlakeTwo256, Header as HeaderT}, ApplyExtrinsicResult,
+	BuildStorage, OpaqueExtrinsic,
+};
+use sp_version::RuntimeVersion;
+use sp_state_machine::{
+	backend::BackendRuntimeCode, Backend, ExecutionStrategy, InMemoryBackend, OverlayedChanges,
+	StateMachine,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+impl CliConfiguration for TryRuntimeUpgradeCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
+
+/// A block to execute on top of the loaded state.
+struct Target {
+	header: Header,
+	/// The stored extrinsics, or `None` to only include the inherents.
+	extrinsics: Option<Vec<OpaqueExtrinsic>>,
+	/// The state root the block was stored with.
+	stored_root: Option<Hash>,
+}
+
+/// What happened to a block in one run.
+struct BlockReport {
+	number: BlockNumber,
+	/// Weight registered by `initialize_block`, including `on_runtime_upgrade`.
+	initialize_weight: Weight,
+	/// Weight of the whole block, read before finalizing it.
+	weight: Weight,
+	/// Extrinsics that were invalid or whose dispatch failed.
+	failed_extrinsics: usize,
+	/// Signed extrinsics that were not executed.
+	skipped_extrinsics: usize,
+	state_root: Hash,
+}
+
+/// The outcome of executing the targets with one runtime.
+struct Run {
+	backend: InMemoryBackend<BlakeTwo256>,
+	overlay: OverlayedChanges,
+	blocks: Vec<BlockReport>,
+	/// The call that panicked or could not be executed, which stopped the run.
+	failure: Option<String>,
+}
+
+impl Run {
+	fn storage(&self, key: &[u8]) -> Option<Vec<u8>> {
+		match self.overlay.storage(key) {
+			Some(value) => value.map(<[u8]>::to_vec),
+			None => self.backend.storage(key).ok().flatten(),
+		}
+	}
+
+	fn block_weight(&self) -> Weight {
+		let key = [twox_128(b"System"), twox_128(b"BlockWeight")].concat();
+		self.storage(&key)
+			.and_then(|value| <(Weight, Weight)>::decode(&mut &value[..]).ok())
+			.map(|(normal, operational)| normal.saturating_add(operational))
+			.unwrap_or_default()
+	}
+}
+
+impl TryRuntimeUpgradeCmd {
+	/// Runs the dry run against the chain of `client`.
+	pub fn run<C, D>(
+		&self,
+		client: &C,
+		config: &Configuration,
+		max_block_weight: Weight,
+		spawn_handle: impl SpawnNamed + Clone + Send + 'static,
+	) -> Result<()> where
+		C: HeaderBackend<Block> + BlockBackend<Block> + StorageProvider<Block, TFullBackend<Block>>,
+		D: NativeExecutionDispatch + 'static,
+	{
+		let wasm = std::fs::read(&self.wasm)
+			.map_err(|e| format!("Failed to read {}: {}", self.wasm.display(), e))?;
+
+		let best = client.info().best_number;
+		let at = match (self.at, &self.state) {
+			(Some(at), _) => at,
+			(None, Some(_)) if self.blocks > 0 =>
+				return Err("`--at` is required to execute stored blocks after `--state`".into()),
+			(None, _) => best.saturating_sub(self.blocks),
+		};
+		if self.blocks > 0 && at.saturating_add(self.blocks) > best {
+			return Err(format!("The database only has blocks up to #{}", best).into());
+		}
+
+		let storage = match &self.state {
+			Some(path) => crate::chain_spec::gama::ChainSpec::from_json_file(path.clone())?
+				.build_storage()?,
+			None => load_state(client, &BlockId::Number(at))?,
+		};
+
+		let targets = if self.blocks == 0 {
+			let number = storage_number(&storage).unwrap_or(at);
+			vec![Target {
+				header: Header::new(
+					number + 1,
+					Default::default(),
+					Default::default(),
+					client.hash(number)?.unwrap_or_default(),
+					Default::default(),
+				),
+				extrinsics: None,
+				stored_root: None,
+			}]
+		} else {
+			(at + 1..=at + self.blocks).map(|number| {
+				let id = BlockId::Number(number);
+				let header = client.header(id)?
+					.ok_or_else(|| format!("Block #{} not found", number))?;
+				let extrinsics = client.block_body(&id)?
+					.ok_or_else(|| format!("Body of block #{} not found", number))?;
+				let stored_root = *header.state_root();
+				Ok(Target { header, extrinsics: Some(extrinsics), stored_root: Some(stored_root) })
+			}).collect::<Result<Vec<_>>>()?
+		};
+
+		let executor = NativeExecutor::<D>::new(
+			config.wasm_method,
+			config.default_heap_pages,
+			config.max_runtime_instances,
+		);
+
+		let mut candidate_storage = storage.clone();
+		candidate_storage.top.insert(well_known_keys::CODE.to_vec(), wasm);
+
+		let current_version = version(&storage, &executor, &spawn_handle)?;
+		let candidate_version = version(&candidate_storage, &executor, &spawn_handle)?;
+		let replay_signed = current_version.spec_version == candidate_version.spec_version &&
+			current_version.transaction_version == candidate_version.transaction_version;
+
+		let current = execute(storage, &targets, replay_signed, &executor, &spawn_handle);
+		let candidate = execute(candidate_storage, &targets, replay_signed, &executor, &spawn_handle);
+
+		println!(
+			"Applied {} to the state of block #{}: spec version {} -> {}, transaction version {} -> {}.",
+			self.wasm.display(),
+			at,
+			current_version.spec_version,
+			candidate_version.spec_version,
+			current_version.transaction_version,
+			candidate_version.transaction_version,
+		);
+		if !replay_signed && self.blocks > 0 {
+			println!(
+				"Signed extrinsics are skipped: they were signed for spec version {} and \
+				transaction version {}, which the candidate runtime rejects.",
+				current_version.spec_version,
+				current_version.transaction_version,
+			);
+		}
+		report(&targets, &current, &candidate, replay_signed, max_block_weight);
+
+		match candidate.failure {
+			Some(failure) => Err(format!("The candidate runtime failed: {}", failure).into()),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Copies the state at `id` from the database.
+fn load_state<C>(client: &C, id: &BlockId<Block>) -> Result<Storage> where
+	C: StorageProvider<Block, TFullBackend<Block>>,
+{
+	let prefix = well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX;
+	let mut storage = Storage::default();
+
+	for key in client.storage_keys(id, &StorageKey(Vec::new()))? {
+		if key.0.starts_with(prefix) {
+			// The root of a child trie is computed from its content.
+			let child_info = ChildInfo::new_default(&key.0[prefix.len()..]);
+			let mut data = BTreeMap::new();
+			for child_key in client.child_storage_keys(id, &child_info, &StorageKey(Vec::new()))? {
+				if let Some(value) = client.child_storage(id, &child_info, &child_key)? {
+					data.insert(child_key.0, value.0);
+				}
+			}
+			storage.children_default.insert(key.0[prefix.len()..].to_vec(), StorageChild { data, child_info });
+		} else if let Some(value) = client.storage(id, &key)? {
+			storage.top.insert(key.0, value.0);
+		}
+	}
+
+	Ok(storage)
+}
+
+/// The number of the last block executed on `storage`.
+fn storage_number(storage: &Storage) -> Option<BlockNumber> {
+	let key = [twox_128(b"System"), twox_128(b"Number")].concat();
+	storage.top.get(&key).and_then(|value| BlockNumber::decode(&mut &value[..]).ok())
+}
+
+/// The version of the runtime in the `:code` of `storage`.
+fn version<D: NativeExecutionDispatch + 'static>(
+	storage: &Storage,
+	executor: &NativeExecutor<D>,
+	spawn_handle: &(impl SpawnNamed + Clone + Send + 'static),
+) -> Result<RuntimeVersion> {
+	let backend = InMemoryBackend::<BlakeTwo256>::from(storage.clone());
+	let encoded = call(&backend, &mut Default::default(), executor, spawn_handle.clone(), "Core_version", &[])
+		.map_err(|e| format!("Core_version failed: {}", e))?;
+	Ok(RuntimeVersion::decode(&mut &encoded[..]).map_err(|e| format!("Invalid runtime version: {}", e))?)
+}
+
+/// Whether `extrinsic` is signed, as told by the version byte following its length.
+fn is_signed(extrinsic: &OpaqueExtrinsic) -> bool {
+	let encoded = extrinsic.encode();
+	let mut input = &encoded[..];
+	Compact::<u32>::decode(&mut input).is_ok() &&
+		input.first().map_or(false, |version| version & 0b1000_0000 != 0)
+}
+
+/// Executes `targets` on `storage` with the runtime in its `:code`, skipping signed extrinsics
+/// unless `replay_signed`.
+fn execute<D: NativeExecutionDispatch + 'static>(
+	storage: Storage,
+	targets: &[Target],
+	replay_signed: bool,
+	executor: &NativeExecutor<D>,
+	spawn_handle: &(impl SpawnNamed + Clone + Send + 'static),
+) -> Run {
+	let mut run = Run {
+		backend: InMemoryBackend::from(storage),
+		overlay: OverlayedChanges::default(),
+		blocks: Vec::new(),
+		failure: None,
+	};
+
+	for target in targets {
+		let number = *target.header.number();
+		let call_runtime = |run: &mut Run, method: &str, data: &[u8]| {
+			call(&run.backend, &mut run.overlay, executor, spawn_handle.clone(), method, data)
+				.map_err(|e| format!("block #{}: {} failed: {}", number, method, e))
+		};
+
+		let block = (|| -> std::result::Result<BlockReport, String> {
+			call_runtime(&mut run, "Core_initialize_block", &target.header.encode())?;
+			let initialize_weight = run.block_weight();
+
+			let extrinsics = match &target.extrinsics {
+				Some(extrinsics) => extrinsics.clone(),
+				None => {
+					let mut inherent_data = sp_inherents::InherentData::new();
+					// The timestamp pallet moves the time forward by at least its minimum period.
+					inherent_data.put_data(sp_timestamp::INHERENT_IDENTIFIER, &0u64)
+						.map_err(|e| format!("{:?}", e))?;
+					let encoded = call_runtime(&mut run, "BlockBuilder_inherent_extrinsics", &inherent_data.encode())?;
+					Vec::<OpaqueExtrinsic>::decode(&mut &encoded[..])
+						.map_err(|e| format!("block #{}: invalid inherents: {}", number, e))?
+				},
+			};
+
+			let mut failed_extrinsics = 0;
+			let mut skipped_extrinsics = 0;
+			for extrinsic in extrinsics {
+				if !replay_signed && is_signed(&extrinsic) {
+					skipped_extrinsics += 1;
+					continue;
+				}
+				let result = call_runtime(&mut run, "BlockBuilder_apply_extrinsic", &extrinsic.encode())?;
+				if !matches!(ApplyExtrinsicResult::decode(&mut &result[..]), Ok(Ok(Ok(())))) {
+					failed_extrinsics += 1;
+				}
+			}
+			let weight = run.block_weight();
+
+			let header = call_runtime(&mut run, "BlockBuilder_finalize_block", &[])?;
+			let header = Header::decode(&mut &header[..])
+				.map_err(|e| format!("block #{}: invalid header: {}", number, e))?;
+
+			Ok(BlockReport {
+				number,
+				initialize_weight,
+				weight,
+				failed_extrinsics,
+				skipped_extrinsics,
+				state_root: *header.state_root(),
+			})
+		})();
+
+		match block {
+			Ok(block) => run.blocks.push(block),
+			Err(failure) => {
+				run.failure = Some(failure);
+				break;
+			},
+		}
+	}
+
+	run
+}
+
+/// Calls `method` of the runtime in the `:code` of `backend`, always in WASM.
+fn call<D: NativeExecutionDispatch + 'static>(
+	backend: &InMemoryBackend<BlakeTwo256>,
+	overlay: &mut OverlayedChanges,
+	executor: &NativeExecutor<D>,
+	spawn_handle: impl SpawnNamed + Send + 'static,
+	method: &str,
+	data: &[u8],
+) -> std::result::Result<Vec<u8>, String> {
+	let code = BackendRuntimeCode::new(backend);
+	let runtime_code = code.runtime_code()?;
+
+	StateMachine::<_, BlakeTwo256, BlockNumber, _>::new(
+		backend,
+		None,
+		overlay,
+		executor,
+		method,
+		data,
+		Default::default(),
+		&runtime_code,
+		spawn_handle,
+	)
+		.execute(ExecutionStrategy::AlwaysWasm)
+		.map_err(|e| e.to_string())
+}
+
+fn percent(weight: Weight, max: Weight) -> f64 {
+	weight as f64 * 100.0 / max.max(1) as f64
+}
+
+/// Prints the weights and state roots of both runs, and the storage they disagree on.
+fn report(targets: &[Target], current: &Run, candidate: &Run, replay_signed: bool, max_block_weight: Weight) {
+	if let Some(block) = candidate.blocks.first() {
+		println!(
+			"Upgrade at block #{}: initialization weight {} ({:.1}% of the block limit)",
+			block.number,
+			block.initialize_weight,
+			percent(block.initialize_weight, max_block_weight),
+		);
+	}
+
+	for (index, target) in targets.iter().enumerate() {
+		let (current, candidate) = match (current.blocks.get(index), candidate.blocks.get(index)) {
+			(Some(current), Some(candidate)) => (current, candidate),
+			_ => break,
+		};
+
+		println!(
+			"Block #{}: weight {} ({:.1}%), {} failed extrinsics (current runtime: {})",
+			candidate.number,
+			candidate.weight,
+			percent(candidate.weight, max_block_weight),
+			candidate.failed_extrinsics,
+			current.failed_extrinsics,
+		);
+		if candidate.skipped_extrinsics > 0 {
+			println!("  {} signed extrinsics skipped", candidate.skipped_extrinsics);
+		}
+		if candidate.weight > max_block_weight {
+			println!("  exceeds the block weight limit {}", max_block_weight);
+		}
+		println!("  state root {:?} (current runtime: {:?})", candidate.state_root, current.state_root);
+		if let (Some(stored_root), true) = (target.stored_root, replay_signed) {
+			if stored_root != current.state_root {
+				println!(
+					"  the current runtime does not reproduce the stored state root {:?}, \
+					the state or the blocks were not loaded completely",
+					stored_root,
+				);
+			}
+		}
+	}
+
+	if let Some(failure) = &current.failure {
+		println!("The current runtime failed: {}", failure);
+	}
+	if let Some(failure) = &candidate.failure {
+		println!("The candidate runtime failed: {}", failure);
+	}
+
+	let keys = current.overlay.changes().chain(candidate.overlay.changes())
+		.map(|(key, _)| key)
+		.filter(|key| key.as_slice() != well_known_keys::CODE)
+		.collect::<BTreeSet<_>>();
+
+	// Keys are grouped by their first 32 bytes, `twox_128(pallet) ++ twox_128(item)`.
+	let mut changed = BTreeMap::<&[u8], usize>::new();
+	for key in keys {
+		if current.storage(key) != candidate.storage(key) {
+			*changed.entry(&key[..key.len().min(32)]).or_default() += 1;
+		}
+	}
+
+	if changed.is_empty() {
+		println!("The candidate runtime left the same storage as the current one.");
+	} else {
+		println!("Storage items that differ from the current runtime:");
+		for (prefix, count) in changed {
+			println!("  0x{}: {} keys", HexDisplay::from(&prefix), count);
+		}
+	}
+}
@@ -0,0 +1,58 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! GRANDPA voting rules of the node.
+
+use grandpa::VotingRule;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::{Block as BlockT, Header as HeaderT, NumberFor}};
+
+/// Keeps the vote at least a given number of blocks behind the best block, leaving BABE forks
+/// time to resolve before they are finalized.
+///
+/// Unlike the default two blocks of `BeforeBestBlockBy`, the back off is set by the chain spec or
+/// the command line. It never moves the vote below the last finalized block.
+#[derive(Clone)]
+pub struct BackOffBehindBest<N>(pub N);
+
+impl<Block, B> VotingRule<Block, B> for BackOffBehindBest<NumberFor<Block>> where
+	Block: BlockT,
+	B: HeaderBackend<Block>,
+{
+	fn restrict_vote(
+		&self,
+		backend: &B,
+		base: &Block::Header,
+		best_target: &Block::Header,
+		current_target: &Block::Header,
+	) -> Option<(Block::Hash, NumberFor<Block>)> {
+		let target_number = best_target.number().saturating_sub(self.0).max(*base.number());
+
+		// The current target is already far enough behind.
+		if target_number >= *current_target.number() {
+			return None;
+		}
+
+		let mut target = current_target.clone();
+		while *target.number() > target_number {
+			target = backend.header(BlockId::Hash(*target.parent_hash())).ok()??;
+		}
+
+		Some((target.hash(), *target.number()))
+	}
+}
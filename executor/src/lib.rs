@@ -21,11 +21,18 @@
 pub use sc_executor::NativeExecutor;
 use sc_executor::native_executor_instance;
 
-// Declare an instance of the native executor named `Executor`. Include the wasm binary as the
+// Declare an instance of the native executor for each network. Include the wasm binary as the
 // equivalent wasm code.
 native_executor_instance!(
-	pub Executor,
-	node_runtime::api::dispatch,
-	node_runtime::native_version,
+	pub GamaExecutor,
+	gama_runtime::api::dispatch,
+	gama_runtime::native_version,
+	frame_benchmarking::benchmarking::HostFunctions,
+);
+
+native_executor_instance!(
+	pub ColomboExecutor,
+	colombo_runtime::api::dispatch,
+	colombo_runtime::native_version,
 	frame_benchmarking::benchmarking::HostFunctions,
 );
@@ -0,0 +1,238 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::Encode;
+use frame_system::EventRecord;
+use gama_runtime::{
+	AssetNFT, Balances, Call, CheckedExtrinsic, Event, Indices, Runtime, System,
+	constants::currency::*,
+};
+use node_primitives::{Balance, Hash};
+use sp_core::NeverNativeValue;
+use sp_runtime::traits::{BlakeTwo256, Hash as HashT, StaticLookup};
+
+pub mod common;
+use self::common::*;
+
+/// A contract doing nothing when deployed or called.
+const CODE_NOOP: &str = r#"
+(module
+	(import "env" "memory" (memory 1 1))
+	(func (export "deploy"))
+	(func (export "call"))
+)
+"#;
+
+const TRANSFER: Balance = 69 * DOLLARS;
+const NFT_SUPPLY: u128 = 1_000;
+
+fn noop_code() -> (Vec<u8>, Hash) {
+	let code = wabt::wat2wasm(CODE_NOOP).unwrap();
+	let hash = BlakeTwo256::hash(&code);
+	(code, hash)
+}
+
+fn timestamp(time: u64) -> CheckedExtrinsic {
+	CheckedExtrinsic {
+		signed: None,
+		function: Call::Timestamp(pallet_timestamp::Call::set(time)),
+	}
+}
+
+/// Block #1 moves balance and mints an NFT, block #2 deploys and calls a contract.
+fn blocks() -> Vec<(Vec<u8>, Hash)> {
+	let mut t = new_test_ext();
+	let (code, code_hash) = noop_code();
+	let contract = contract_address(&code_hash, &charlie());
+
+	let block1 = construct_block(
+		&mut t,
+		1,
+		GENESIS_HASH.into(),
+		vec![
+			timestamp(42 * 1000),
+			CheckedExtrinsic {
+				signed: Some((alice(), signed_extra(0, 0))),
+				function: Call::Balances(pallet_balances::Call::transfer(Indices::unlookup(bob()), TRANSFER)),
+			},
+			CheckedExtrinsic {
+				signed: Some((bob(), signed_extra(0, 0))),
+				function: Call::AssetNFT(pallet_assets_nft::Call::mint(bob(), b"executor".to_vec(), NFT_SUPPLY)),
+			},
+		],
+	);
+
+	let block2 = construct_block(
+		&mut t,
+		2,
+		block1.1,
+		vec![
+			timestamp(52 * 1000),
+			CheckedExtrinsic {
+				signed: Some((charlie(), signed_extra(0, 0))),
+				function: Call::Contracts(pallet_contracts::Call::put_code(code)),
+			},
+			CheckedExtrinsic {
+				signed: Some((charlie(), signed_extra(1, 0))),
+				function: Call::Contracts(
+					pallet_contracts::Call::instantiate(1 * DOLLARS, 500_000_000, code_hash, Vec::new())
+				),
+			},
+			CheckedExtrinsic {
+				signed: Some((charlie(), signed_extra(2, 0))),
+				function: Call::Contracts(
+					pallet_contracts::Call::call(Indices::unlookup(contract), 10, 500_000_000, vec![0x00])
+				),
+			},
+		],
+	);
+
+	vec![block1, block2]
+}
+
+/// What executing a block left behind, compared between execution modes.
+#[derive(Debug, PartialEq)]
+struct Outcome {
+	state_root: Hash,
+	free_balances: Vec<Balance>,
+	events: Vec<EventRecord<Event, Hash>>,
+}
+
+/// Executes `blocks` on a fresh genesis, natively or in WASM.
+fn execute_blocks(blocks: &[(Vec<u8>, Hash)], use_native: bool) -> Vec<Outcome> {
+	let mut t = new_test_ext();
+
+	blocks.iter().map(|(block, _)| {
+		executor_call::<NeverNativeValue, fn() -> _>(
+			&mut t,
+			"Core_execute_block",
+			block,
+			use_native,
+			None,
+		).unwrap();
+
+		t.execute_with(|| Outcome {
+			state_root: Hash::from_slice(&sp_io::storage::root()),
+			free_balances: vec![alice(), bob(), charlie()].iter().map(|who| Balances::free_balance(who)).collect(),
+			events: System::events(),
+		})
+	}).collect()
+}
+
+fn has_event(outcome: &Outcome, matches: impl Fn(&Event) -> bool) -> bool {
+	outcome.events.iter().any(|record| matches(&record.event))
+}
+
+#[test]
+fn native_and_wasm_execution_agree() {
+	let blocks = blocks();
+
+	let native = execute_blocks(&blocks, true);
+	let wasm = execute_blocks(&blocks, false);
+
+	assert_eq!(native, wasm);
+}
+
+#[test]
+fn balance_transfer_pays_fees() {
+	let blocks = blocks();
+	let genesis_balance = new_test_ext().execute_with(|| Balances::free_balance(alice()));
+
+	for use_native in &[true, false] {
+		let outcomes = execute_blocks(&blocks, *use_native);
+		let block1 = &outcomes[0];
+
+		let fee = genesis_balance - TRANSFER - block1.free_balances[0];
+		assert!(fee > 0, "no fee charged for the transfer");
+		assert!(has_event(block1, |event| matches!(
+			event,
+			Event::pallet_balances(pallet_balances::RawEvent::Transfer(from, to, amount))
+				if *from == alice() && *to == bob() && *amount == TRANSFER
+		)));
+	}
+}
+
+#[test]
+fn nft_is_minted() {
+	let blocks = blocks();
+
+	for use_native in &[true, false] {
+		let outcomes = execute_blocks(&blocks, *use_native);
+
+		assert!(has_event(&outcomes[0], |event| matches!(
+			event,
+			Event::pallet_assets_nft_Instance2(pallet_assets_nft::RawEvent::Minted(_, owner, supply, _, _))
+				if *owner == bob() && *supply == NFT_SUPPLY
+		)));
+	}
+
+	let mut t = new_test_ext();
+	for (block, _) in &blocks {
+		executor_call::<NeverNativeValue, fn() -> _>(&mut t, "Core_execute_block", block, false, None)
+			.unwrap();
+	}
+	t.execute_with(|| {
+		assert_eq!(AssetNFT::total_for_account(bob()), 1);
+		assert_eq!(AssetNFT::assets_for_account(bob())[0].1, b"executor".to_vec());
+	});
+}
+
+#[test]
+fn contract_is_deployed_and_called() {
+	let blocks = blocks();
+	let (_, code_hash) = noop_code();
+	let contract = contract_address(&code_hash, &charlie());
+
+	for use_native in &[true, false] {
+		let outcomes = execute_blocks(&blocks, *use_native);
+
+		assert!(has_event(&outcomes[1], |event| matches!(
+			event,
+			Event::pallet_contracts(pallet_contracts::RawEvent::Instantiated(deployer, address))
+				if *deployer == charlie() && *address == contract
+		)));
+	}
+
+	let mut t = new_test_ext();
+	for (block, _) in &blocks {
+		executor_call::<NeverNativeValue, fn() -> _>(&mut t, "Core_execute_block", block, false, None)
+			.unwrap();
+	}
+	t.execute_with(|| {
+		assert!(pallet_contracts::ContractInfoOf::<Runtime>::get(&contract)
+			.and_then(|info| info.get_alive())
+			.is_some());
+	});
+}
+
+#[test]
+fn wasm_execution_rejects_a_block_with_a_wrong_state_root() {
+	let blocks = blocks();
+	let (block, _) = &blocks[0];
+
+	let mut block = <gama_runtime::Block as codec::Decode>::decode(&mut &block[..]).unwrap();
+	block.header.state_root = Default::default();
+
+	let mut t = new_test_ext();
+	assert!(executor_call::<NeverNativeValue, fn() -> _>(
+		&mut t,
+		"Core_execute_block",
+		&block.encode(),
+		false,
+		None,
+	).is_err());
+}
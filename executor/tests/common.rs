@@ -0,0 +1,227 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers building blocks of signed extrinsics on the gama genesis and executing them through
+//! `GamaExecutor`, natively or in WASM.
+
+#![allow(dead_code)]
+
+use codec::{Decode, Encode};
+use aochain_executor::GamaExecutor;
+use frame_support::Hashable;
+use gama_runtime::{
+	Block, BuildStorage, CheckedExtrinsic, Header, Indices, Runtime, SignedExtra,
+	UncheckedExtrinsic, VERSION,
+};
+use node_primitives::{AccountId, BlockNumber, Hash};
+use sc_executor::{NativeExecutor, WasmExecutionMethod, error::Result};
+use sp_core::{
+	NativeOrEncoded, NeverNativeValue,
+	storage::well_known_keys,
+	traits::{CodeExecutor, RuntimeCode, WrappedRuntimeCode},
+};
+use sp_keyring::AccountKeyring;
+use sp_runtime::{
+	ApplyExtrinsicResult,
+	generic::Era,
+	traits::{BlakeTwo256, StaticLookup},
+};
+use sp_state_machine::TestExternalities as CoreTestExternalities;
+
+pub type TestExternalities = CoreTestExternalities<BlakeTwo256>;
+
+/// `System::BlockHash(0)` after building the genesis storage, which extrinsics are signed for.
+pub const GENESIS_HASH: [u8; 32] = [69u8; 32];
+
+pub fn alice() -> AccountId {
+	AccountKeyring::Alice.into()
+}
+
+pub fn bob() -> AccountId {
+	AccountKeyring::Bob.into()
+}
+
+pub fn charlie() -> AccountId {
+	AccountKeyring::Charlie.into()
+}
+
+pub fn executor() -> NativeExecutor<GamaExecutor> {
+	NativeExecutor::new(WasmExecutionMethod::Interpreted, None, 8)
+}
+
+/// The gama genesis of a single Alice authority, with the keyring accounts endowed.
+pub fn new_test_ext() -> TestExternalities {
+	let genesis = node_cli::chain_spec::gama::testnet_genesis(
+		vec![node_cli::chain_spec::authority_keys_from_seed("Alice")],
+		None,
+		false,
+	);
+
+	TestExternalities::new(genesis.build_storage().unwrap())
+}
+
+/// Calls `method` of the runtime in the `:code` of `t`, and checks it ran natively when asked to.
+pub fn executor_call<
+	R: Decode + Encode + PartialEq,
+	NC: FnOnce() -> std::result::Result<R, String> + std::panic::UnwindSafe,
+>(
+	t: &mut TestExternalities,
+	method: &str,
+	data: &[u8],
+	use_native: bool,
+	native_call: Option<NC>,
+) -> Result<NativeOrEncoded<R>> {
+	let mut t = t.ext();
+
+	let code = t.storage(well_known_keys::CODE).unwrap();
+	let heap_pages = t.storage(well_known_keys::HEAP_PAGES);
+	let runtime_code = RuntimeCode {
+		code_fetcher: &WrappedRuntimeCode(code.as_slice().into()),
+		hash: sp_core::blake2_256(&code).to_vec(),
+		heap_pages: heap_pages.and_then(|hp| Decode::decode(&mut &hp[..]).ok()),
+	};
+
+	let (result, used_native) = executor().call::<R, NC>(
+		&mut t,
+		&runtime_code,
+		method,
+		data,
+		use_native,
+		native_call,
+	);
+	assert_eq!(used_native, use_native, "{} did not run in the requested mode", method);
+
+	result
+}
+
+pub fn signed_extra(nonce: u32, tip: u128) -> SignedExtra {
+	(
+		frame_system::CheckSpecVersion::new(),
+		frame_system::CheckTxVersion::new(),
+		frame_system::CheckGenesis::new(),
+		frame_system::CheckEra::from(Era::Immortal),
+		frame_system::CheckNonce::from(nonce),
+		frame_system::CheckWeight::new(),
+		pallet_asset_tx_payment::ChargeAssetTxPayment::from(tip, None),
+	)
+}
+
+/// Signs `xt` with the keyring account of its signer.
+pub fn sign(xt: CheckedExtrinsic) -> UncheckedExtrinsic {
+	match xt.signed {
+		Some((signed, extra)) => {
+			let payload = (
+				xt.function,
+				extra.clone(),
+				VERSION.spec_version,
+				VERSION.transaction_version,
+				GENESIS_HASH,
+				GENESIS_HASH,
+			);
+			let key = AccountKeyring::from_account_id(&signed).unwrap();
+			let signature = payload.using_encoded(|b| {
+				if b.len() > 256 {
+					key.sign(&sp_io::hashing::blake2_256(b))
+				} else {
+					key.sign(b)
+				}
+			}).into();
+			UncheckedExtrinsic {
+				signature: Some((Indices::unlookup(signed), signature, extra)),
+				function: payload.0,
+			}
+		}
+		None => UncheckedExtrinsic {
+			signature: None,
+			function: xt.function,
+		},
+	}
+}
+
+/// Builds a block of `extrinsics` on the state of `env`, leaving `env` at the end of the block.
+///
+/// Returns the encoded block and its hash. Every extrinsic must dispatch successfully.
+pub fn construct_block(
+	env: &mut TestExternalities,
+	number: BlockNumber,
+	parent_hash: Hash,
+	extrinsics: Vec<CheckedExtrinsic>,
+) -> (Vec<u8>, Hash) {
+	use sp_trie::{TrieConfiguration, trie_types::Layout};
+
+	let extrinsics = extrinsics.into_iter().map(sign).collect::<Vec<_>>();
+	let extrinsics_root = Layout::<BlakeTwo256>::ordered_trie_root(
+		extrinsics.iter().map(Encode::encode)
+	).to_fixed_bytes().into();
+
+	let header = Header {
+		parent_hash,
+		number,
+		extrinsics_root,
+		state_root: Default::default(),
+		digest: Default::default(),
+	};
+
+	executor_call::<NeverNativeValue, fn() -> _>(
+		env,
+		"Core_initialize_block",
+		&header.encode(),
+		true,
+		None,
+	).unwrap();
+
+	for extrinsic in extrinsics.iter() {
+		let result = executor_call::<NeverNativeValue, fn() -> _>(
+			env,
+			"BlockBuilder_apply_extrinsic",
+			&extrinsic.encode(),
+			true,
+			None,
+		).expect("application of an extrinsic failed").into_encoded();
+
+		match ApplyExtrinsicResult::decode(&mut &result[..]).expect("apply result deserialization failed") {
+			Ok(Ok(())) => {},
+			Ok(Err(e)) => panic!("Dispatching {:?} failed: {:?}", extrinsic.function, e),
+			Err(e) => panic!("Applying {:?} failed: {:?}", extrinsic.function, e),
+		}
+	}
+
+	let header = match executor_call::<NeverNativeValue, fn() -> _>(
+		env,
+		"BlockBuilder_finalize_block",
+		&[0u8; 0],
+		true,
+		None,
+	).unwrap() {
+		NativeOrEncoded::Native(_) => unreachable!(),
+		NativeOrEncoded::Encoded(h) => Header::decode(&mut &h[..]).unwrap(),
+	};
+
+	let hash = header.blake2_256();
+	(Block { header, extrinsics }.encode(), hash.into())
+}
+
+/// The account a contract instantiated by `origin` from `code_hash` with empty input gets.
+pub fn contract_address(code_hash: &Hash, origin: &AccountId) -> AccountId {
+	use pallet_contracts::ContractAddressFor;
+
+	<Runtime as pallet_contracts::Trait>::DetermineContractAddress::contract_address_for(
+		code_hash,
+		&[],
+		origin,
+	)
+}
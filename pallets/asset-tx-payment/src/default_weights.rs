@@ -0,0 +1,15 @@
+#![allow(unused_parens)]
+
+use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
+
+impl crate::WeightInfo for () {
+    fn set_rate() -> Weight {
+        (20_000_000 as Weight)
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn sweep() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+}
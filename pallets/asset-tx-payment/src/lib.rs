@@ -0,0 +1,393 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Asset Transaction Payment
+//!
+//! Lets the signer of an extrinsic pay its fee in tokens of an assets-nft asset instead of the
+//! native currency, through the [`ChargeAssetTxPayment`] signed extension.
+//!
+//! The fee is computed by `pallet_transaction_payment` in the native currency and converted
+//! into asset tokens, either at a rate set by `RateOrigin` or, if no rate is set, at the rate
+//! quoted by `PoolRate`. The tokens are moved to the pallet's fee account, which in exchange
+//! pays the native equivalent out of its own free balance. That native imbalance is then
+//! handed to `OnTransactionPayment` exactly as a native fee would be.
+//!
+//! The fee account is endowed at genesis and can be topped up with plain transfers. The asset
+//! tokens it collects stay there until `RateOrigin` sweeps them, e.g. to the treasury.
+
+use codec::{Decode, Encode};
+use frame_support::{
+	Parameter, decl_error, decl_event, decl_module, decl_storage, dispatch, ensure,
+	traits::{Currency, EnsureOrigin, ExistenceRequirement, Get, Imbalance, OnUnbalanced, WithdrawReason},
+	weights::{DispatchInfo, PostDispatchInfo},
+};
+use frame_system::{self as system};
+use pallet_assets_nft::Token;
+use pallet_transaction_payment::ChargeTransactionPayment;
+use sp_runtime::{
+	FixedPointNumber, FixedPointOperand, FixedU128, ModuleId, Perbill,
+	traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, DispatchInfoOf, Dispatchable, Member,
+		PostDispatchInfoOf, SaturatedConversion, Saturating, SignedExtension, StaticLookup, Zero,
+	},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
+	},
+};
+
+mod weight_info;
+mod default_weights;
+
+pub use crate::weight_info::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub type BalanceOf<T> =
+	<<T as pallet_transaction_payment::Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+
+pub type NegativeImbalanceOf<T> =
+	<<T as pallet_transaction_payment::Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::NegativeImbalance;
+
+/// Quotes the native currency in units of an asset, e.g. from a liquidity pool.
+pub trait AssetRate<AssetId, Balance, AssetBalance> {
+	/// Amount of `asset_id` tokens worth `amount` of native currency, if the asset can be quoted.
+	fn native_to_asset(asset_id: &AssetId, amount: Balance) -> Option<AssetBalance>;
+}
+
+impl<AssetId, Balance, AssetBalance> AssetRate<AssetId, Balance, AssetBalance> for () {
+	fn native_to_asset(_: &AssetId, _: Balance) -> Option<AssetBalance> {
+		None
+	}
+}
+
+pub trait Trait: pallet_transaction_payment::Trait {
+	type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+	type AssetId: Member + Parameter + Copy + Default;
+
+	type AssetBalance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy;
+
+	/// The asset tokens fees can be paid in.
+	type Assets: Token<Self::AccountId, Self::AssetBalance, AssetId = Self::AssetId>;
+
+	/// Origin allowed to set the fee rate of an asset and to sweep the collected tokens.
+	type RateOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Rate used for assets without a governance-set rate.
+	type PoolRate: AssetRate<Self::AssetId, BalanceOf<Self>, Self::AssetBalance>;
+
+	/// Id of the account collecting the asset tokens and paying the native fees.
+	type ModuleId: Get<ModuleId>;
+
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as AssetTxPayment {
+		/// Number of asset tokens charged per unit of native fee.
+		AssetRates get(fn asset_rate):
+			map hasher(twox_64_concat) T::AssetId => Option<FixedU128>;
+	}
+	add_extra_genesis {
+		/// Native free balance of the fee account, which pays the native side of asset fees.
+		config(fee_account_balance): BalanceOf<T>;
+		build(|config: &GenesisConfig<T>| {
+			if !config.fee_account_balance.is_zero() {
+				T::Currency::make_free_balance_be(&Module::<T>::account_id(), config.fee_account_balance);
+			}
+		});
+	}
+}
+
+decl_event!(
+	pub enum Event<T>
+	where
+		<T as system::Trait>::AccountId,
+		AssetId = <T as Trait>::AssetId,
+		Balance = BalanceOf<T>,
+		AssetBalance = <T as Trait>::AssetBalance,
+	{
+		/// The fee rate of an asset was set or cleared. [asset_id, rate]
+		AssetRateSet(AssetId, Option<FixedU128>),
+		/// A transaction fee was paid in asset tokens. [who, asset_id, native_fee, tokens]
+		AssetTxFeePaid(AccountId, AssetId, Balance, AssetBalance),
+		/// The tokens of an asset collected as fees were swept. [asset_id, dest, tokens]
+		Swept(AssetId, AccountId, AssetBalance),
+	}
+);
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		ZeroRate,
+		/// The fee account holds no tokens of the asset.
+		NothingToSweep,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		fn deposit_event() = default;
+
+		/// Set the number of `asset_id` tokens charged per unit of native fee, or clear it to
+		/// fall back to `PoolRate`.
+		#[weight = T::WeightInfo::set_rate()]
+		pub fn set_rate(
+			origin,
+			asset_id: T::AssetId,
+			rate: Option<FixedU128>,
+		) -> dispatch::DispatchResult {
+			T::RateOrigin::ensure_origin(origin)?;
+			ensure!(rate.map_or(true, |r| !r.is_zero()), Error::<T>::ZeroRate);
+
+			match rate {
+				Some(rate) => AssetRates::<T>::insert(&asset_id, rate),
+				None => AssetRates::<T>::remove(&asset_id),
+			}
+			Self::deposit_event(RawEvent::AssetRateSet(asset_id, rate));
+
+			Ok(())
+		}
+
+		/// Move every `asset_id` token collected by the fee account to `dest`.
+		#[weight = T::WeightInfo::sweep()]
+		pub fn sweep(
+			origin,
+			asset_id: T::AssetId,
+			dest: <T::Lookup as StaticLookup>::Source,
+		) -> dispatch::DispatchResult {
+			T::RateOrigin::ensure_origin(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+
+			let fee_account = Self::account_id();
+			let tokens = T::Assets::balance(&fee_account, &asset_id);
+			ensure!(!tokens.is_zero(), Error::<T>::NothingToSweep);
+
+			T::Assets::transfer(&fee_account, &dest, &asset_id, tokens)?;
+			Self::deposit_event(RawEvent::Swept(asset_id, dest, tokens));
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	/// The account collecting asset tokens and paying native fees.
+	pub fn account_id() -> T::AccountId {
+		T::ModuleId::get().into_account()
+	}
+
+	/// Amount of `asset_id` tokens charged for a native fee of `amount`.
+	///
+	/// Rounds up to at least one token for a non-zero fee.
+	pub fn native_to_asset(asset_id: &T::AssetId, amount: BalanceOf<T>) -> Option<T::AssetBalance>
+		where BalanceOf<T>: FixedPointOperand
+	{
+		let tokens = match Self::asset_rate(asset_id) {
+			Some(rate) => rate
+				.saturating_mul_int(amount.saturated_into::<u128>())
+				.saturated_into::<T::AssetBalance>(),
+			None => T::PoolRate::native_to_asset(asset_id, amount)?,
+		};
+
+		if tokens.is_zero() && !amount.is_zero() {
+			Some(1u32.into())
+		} else {
+			Some(tokens)
+		}
+	}
+}
+
+/// The payment withdrawn in `pre_dispatch`, settled in `post_dispatch`.
+pub enum InitialPayment<T: Trait> {
+	/// Paid in the native currency by `ChargeTransactionPayment`.
+	Native((BalanceOf<T>, T::AccountId, Option<NegativeImbalanceOf<T>>, BalanceOf<T>)),
+	/// Paid in `tokens` of `asset_id`, in exchange for `imbalance` from the fee account.
+	Asset {
+		who: T::AccountId,
+		asset_id: T::AssetId,
+		tip: BalanceOf<T>,
+		tokens: T::AssetBalance,
+		imbalance: NegativeImbalanceOf<T>,
+	},
+}
+
+impl<T: Trait> Default for InitialPayment<T> {
+	fn default() -> Self {
+		InitialPayment::Native(Default::default())
+	}
+}
+
+/// Require the transactor to pay for themselves, optionally in tokens of `asset_id`.
+///
+/// Without an asset this behaves exactly like `ChargeTransactionPayment`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct ChargeAssetTxPayment<T: Trait + Send + Sync> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	asset_id: Option<T::AssetId>,
+}
+
+impl<T: Trait + Send + Sync> ChargeAssetTxPayment<T> where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	T::Call: Dispatchable<Info=DispatchInfo, PostInfo=PostDispatchInfo>,
+{
+	/// Utility constructor. Used only in client/factory code.
+	pub fn from(tip: BalanceOf<T>, asset_id: Option<T::AssetId>) -> Self {
+		Self { tip, asset_id }
+	}
+
+	fn native(&self) -> ChargeTransactionPayment<T> {
+		ChargeTransactionPayment::<T>::from(self.tip)
+	}
+
+	/// Take the fee in `asset_id` tokens from `who` and the native equivalent from the fee
+	/// account.
+	fn withdraw_fee(
+		&self,
+		who: &T::AccountId,
+		asset_id: &T::AssetId,
+		info: &DispatchInfoOf<T::Call>,
+		len: usize,
+	) -> Result<(BalanceOf<T>, T::AssetBalance, NegativeImbalanceOf<T>), TransactionValidityError> {
+		let fee = pallet_transaction_payment::Module::<T>::compute_fee(len as u32, info, self.tip);
+
+		let tokens = Module::<T>::native_to_asset(asset_id, fee)
+			.ok_or(InvalidTransaction::Payment)?;
+		if T::Assets::balance(who, asset_id) < tokens {
+			return Err(InvalidTransaction::Payment.into());
+		}
+
+		let fee_account = Module::<T>::account_id();
+		let imbalance = T::Currency::withdraw(
+			&fee_account,
+			fee,
+			if self.tip.is_zero() {
+				WithdrawReason::TransactionPayment.into()
+			} else {
+				WithdrawReason::TransactionPayment | WithdrawReason::Tip
+			},
+			ExistenceRequirement::KeepAlive,
+		).map_err(|_| InvalidTransaction::Payment)?;
+
+		if !tokens.is_zero() {
+			T::Assets::transfer(who, &fee_account, asset_id, tokens)
+				.map_err(|_| InvalidTransaction::Payment)?;
+		}
+
+		Ok((fee, tokens, imbalance))
+	}
+
+	/// Same priority `ChargeTransactionPayment` gives a transaction paying `final_fee`.
+	fn get_priority(len: usize, info: &DispatchInfoOf<T::Call>, final_fee: BalanceOf<T>) -> TransactionPriority {
+		let weight_saturation = T::MaximumBlockWeight::get() / info.weight.max(1);
+		let len_saturation = T::MaximumBlockLength::get() as u64 / (len as u64).max(1);
+		let coefficient: BalanceOf<T> = weight_saturation.min(len_saturation).saturated_into::<BalanceOf<T>>();
+		final_fee.saturating_mul(coefficient).saturated_into::<TransactionPriority>()
+	}
+}
+
+impl<T: Trait + Send + Sync> sp_std::fmt::Debug for ChargeAssetTxPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeAssetTxPayment<{:?}, {:?}>", self.tip, self.asset_id)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Trait + Send + Sync> SignedExtension for ChargeAssetTxPayment<T> where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	T::Call: Dispatchable<Info=DispatchInfo, PostInfo=PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = InitialPayment<T>;
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> { Ok(()) }
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		match self.asset_id {
+			None => self.native().validate(who, call, info, len),
+			Some(ref asset_id) => {
+				let (fee, _, _) = self.withdraw_fee(who, asset_id, info, len)?;
+				Ok(ValidTransaction {
+					priority: Self::get_priority(len, info, fee),
+					..Default::default()
+				})
+			}
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		match self.asset_id {
+			None => self.native().pre_dispatch(who, call, info, len).map(InitialPayment::Native),
+			Some(asset_id) => {
+				let (_, tokens, imbalance) = self.withdraw_fee(who, &asset_id, info, len)?;
+				Ok(InitialPayment::Asset { who: who.clone(), asset_id, tip: self.tip, tokens, imbalance })
+			}
+		}
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &dispatch::DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (who, asset_id, tip, tokens, imbalance) = match pre {
+			InitialPayment::Native(pre) =>
+				return ChargeTransactionPayment::<T>::post_dispatch(pre, info, post_info, len, result),
+			InitialPayment::Asset { who, asset_id, tip, tokens, imbalance } =>
+				(who, asset_id, tip, tokens, imbalance),
+		};
+
+		let paid = imbalance.peek();
+		let actual_fee = pallet_transaction_payment::Module::<T>::compute_actual_fee(
+			len as u32, info, post_info, tip,
+		).min(paid);
+		let refund = paid.saturating_sub(actual_fee);
+
+		// refund the unused part of the fee in the same proportion it was paid.
+		let refund_tokens = Perbill::from_rational_approximation(refund, paid) * tokens;
+		let fee_account = Module::<T>::account_id();
+		if !refund_tokens.is_zero() {
+			T::Assets::transfer(&fee_account, &who, &asset_id, refund_tokens)
+				.map_err(|_| InvalidTransaction::Payment)?;
+		}
+
+		let (actual_payment, native_refund) = imbalance.split(actual_fee);
+		T::Currency::resolve_creating(&fee_account, native_refund);
+
+		let (tip, fee) = actual_payment.split(tip);
+		T::OnTransactionPayment::on_unbalanceds(Some(fee).into_iter().chain(Some(tip)));
+
+		Module::<T>::deposit_event(RawEvent::AssetTxFeePaid(
+			who, asset_id, actual_fee, tokens.saturating_sub(refund_tokens),
+		));
+
+		Ok(())
+	}
+}
@@ -0,0 +1,181 @@
+use crate::{AssetRate, Module, Trait};
+use sp_core::H256;
+use frame_support::{
+	impl_outer_origin, impl_outer_event, impl_outer_dispatch, parameter_types,
+	traits::{Currency, OnUnbalanced},
+	weights::{Weight, IdentityFee},
+};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup}, testing::Header, ModuleId, Perbill,
+};
+use frame_system as system;
+use std::cell::RefCell;
+
+mod asset_tx_payment {
+	pub use crate::Event;
+}
+
+impl_outer_event! {
+	pub enum TestEvent for Test {
+		system<T>,
+		pallet_balances<T>,
+		pallet_assets_nft<T>,
+		asset_tx_payment<T>,
+	}
+}
+
+impl_outer_origin! {
+	pub enum Origin for Test {}
+}
+
+impl_outer_dispatch! {
+	pub enum Call for Test where origin: Origin {
+		frame_system::System,
+		pallet_balances::Balances,
+	}
+}
+
+// Configure a mock runtime to test the pallet.
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
+}
+
+impl system::Trait for Test {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type MaximumBlockLength = MaximumBlockLength;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Trait for Test {
+	type MaxLocks = ();
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = TestEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+pub const TREASURY: u64 = 99;
+
+/// Collects all native fees and tips into `TREASURY`.
+pub struct DealWithFees;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for DealWithFees {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+		Balances::resolve_creating(&TREASURY, amount);
+	}
+}
+
+parameter_types! {
+	pub const TransactionByteFee: u64 = 1;
+}
+
+impl pallet_transaction_payment::Trait for Test {
+	type Currency = Balances;
+	type OnTransactionPayment = DealWithFees;
+	type TransactionByteFee = TransactionByteFee;
+	type WeightToFee = IdentityFee<u64>;
+	type FeeMultiplierUpdate = ();
+}
+
+parameter_types! {
+	pub const MaxAssets: u128 = 10;
+	pub const MaxAssetsPerUser: u64 = 10;
+	pub const BurnOwnedCheck: bool = false;
+	pub const AllowBurn: bool = true;
+}
+
+impl pallet_assets_nft::Trait for Test {
+	type Event = TestEvent;
+	type MintOrigin = system::EnsureSigned<Self::AccountId>;
+	type BurnOrigin = system::EnsureSigned<Self::AccountId>;
+	type AssetInfo = Vec<u8>;
+	type AssetLimit = MaxAssets;
+	type BurnOwnedCheck = BurnOwnedCheck;
+	type AllowBurn = AllowBurn;
+	type UserAssetLimit = MaxAssetsPerUser;
+	type Balance = u128;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	pub static POOL_ENABLED: RefCell<bool> = RefCell::new(false);
+}
+
+/// Quotes three tokens per unit of native currency for every asset, once `POOL_ENABLED` is set.
+pub struct TripleRate;
+impl AssetRate<H256, u64, u128> for TripleRate {
+	fn native_to_asset(_: &H256, amount: u64) -> Option<u128> {
+		if POOL_ENABLED.with(|v| *v.borrow()) {
+			Some(amount as u128 * 3)
+		} else {
+			None
+		}
+	}
+}
+
+parameter_types! {
+	pub const AssetTxPaymentModuleId: ModuleId = ModuleId(*b"py/asfee");
+}
+
+impl Trait for Test {
+	type Event = TestEvent;
+	type AssetId = H256;
+	type AssetBalance = u128;
+	type Assets = AssetsNFT;
+	type RateOrigin = system::EnsureRoot<Self::AccountId>;
+	type PoolRate = TripleRate;
+	type ModuleId = AssetTxPaymentModuleId;
+	type WeightInfo = ();
+}
+
+pub type System = system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type AssetsNFT = pallet_assets_nft::Module<Test>;
+pub type AssetTxPayment = Module<Test>;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100), (2, 100)],
+	}.assimilate_storage(&mut t).unwrap();
+	crate::GenesisConfig::<Test> {
+		fee_account_balance: 1_000,
+	}.assimilate_storage(&mut t).unwrap();
+	t.into()
+}
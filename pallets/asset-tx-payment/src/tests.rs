@@ -0,0 +1,215 @@
+use crate::*;
+use crate::{Error, mock::*};
+use frame_support::{assert_ok, assert_noop, weights::Weight};
+use sp_core::H256;
+use sp_runtime::{FixedU128, traits::BadOrigin};
+use super::RawEvent;
+
+const CALL: &<Test as system::Trait>::Call =
+	&Call::Balances(pallet_balances::Call::transfer(2, 10));
+
+const LEN: usize = 10;
+
+fn info_from_weight(w: Weight) -> DispatchInfo {
+	DispatchInfo { weight: w, ..Default::default() }
+}
+
+fn post_info_from_weight(w: Weight) -> PostDispatchInfo {
+	PostDispatchInfo { actual_weight: Some(w), pays_fee: Default::default() }
+}
+
+fn last_event() -> TestEvent {
+	System::events()
+		.pop()
+		.expect("Event expected")
+		.event
+}
+
+/// Mint an asset with 1_000 tokens for account 1 and return its id.
+fn mint_asset() -> H256 {
+	assert_ok!(AssetsNFT::mint(Origin::signed(0), 1, vec![], 1_000));
+	AssetsNFT::assets_for_account(1)[0].0
+}
+
+fn rate(n: u128) -> Option<FixedU128> {
+	Some(FixedU128::saturating_from_integer(n))
+}
+
+fn tokens(who: u64, asset_id: &H256) -> u128 {
+	<AssetsNFT as Token<_, _>>::balance(&who, asset_id)
+}
+
+#[test]
+fn set_rate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = mint_asset();
+
+		assert_noop!(AssetTxPayment::set_rate(Origin::signed(1), asset_id, rate(2)), BadOrigin);
+		assert_noop!(
+			AssetTxPayment::set_rate(Origin::root(), asset_id, rate(0)),
+			Error::<Test>::ZeroRate
+		);
+
+		assert_ok!(AssetTxPayment::set_rate(Origin::root(), asset_id, rate(2)));
+		assert_eq!(AssetTxPayment::asset_rate(asset_id), rate(2));
+		assert_eq!(last_event(), TestEvent::asset_tx_payment(RawEvent::AssetRateSet(asset_id, rate(2))));
+
+		assert_ok!(AssetTxPayment::set_rate(Origin::root(), asset_id, None));
+		assert_eq!(AssetTxPayment::asset_rate(asset_id), None);
+		assert_eq!(last_event(), TestEvent::asset_tx_payment(RawEvent::AssetRateSet(asset_id, None)));
+	});
+}
+
+#[test]
+fn native_payment_is_unchanged() {
+	new_test_ext().execute_with(|| {
+		let pre = ChargeAssetTxPayment::<Test>::from(0, None)
+			.pre_dispatch(&1, CALL, &info_from_weight(5), LEN)
+			.unwrap();
+		assert_eq!(Balances::free_balance(1), 100 - 15);
+
+		assert_ok!(ChargeAssetTxPayment::<Test>::post_dispatch(
+			pre, &info_from_weight(5), &post_info_from_weight(3), LEN, &Ok(())
+		));
+		assert_eq!(Balances::free_balance(1), 100 - 13);
+		assert_eq!(Balances::free_balance(TREASURY), 13);
+		assert_eq!(Balances::free_balance(AssetTxPayment::account_id()), 1_000);
+	});
+}
+
+#[test]
+fn pays_fee_in_asset_tokens() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = mint_asset();
+		let fee_account = AssetTxPayment::account_id();
+		assert_ok!(AssetTxPayment::set_rate(Origin::root(), asset_id, rate(2)));
+
+		let pre = ChargeAssetTxPayment::<Test>::from(0, Some(asset_id))
+			.pre_dispatch(&1, CALL, &info_from_weight(5), LEN)
+			.unwrap();
+		assert_eq!(tokens(1, &asset_id), 1_000 - 30);
+		assert_eq!(tokens(fee_account, &asset_id), 30);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(fee_account), 1_000 - 15);
+
+		// 13 of the 15 paid are used, 2/15 of the tokens are refunded.
+		assert_ok!(ChargeAssetTxPayment::<Test>::post_dispatch(
+			pre, &info_from_weight(5), &post_info_from_weight(3), LEN, &Ok(())
+		));
+		assert_eq!(tokens(1, &asset_id), 1_000 - 26);
+		assert_eq!(tokens(fee_account, &asset_id), 26);
+		assert_eq!(Balances::free_balance(fee_account), 1_000 - 13);
+		assert_eq!(Balances::free_balance(TREASURY), 13);
+		assert_eq!(
+			last_event(),
+			TestEvent::asset_tx_payment(RawEvent::AssetTxFeePaid(1, asset_id, 13, 26))
+		);
+	});
+}
+
+#[test]
+fn tip_is_paid_in_asset_tokens() {
+	new_test_ext().execute_with(|| {
+		let asset_id = mint_asset();
+		assert_ok!(AssetTxPayment::set_rate(Origin::root(), asset_id, rate(2)));
+
+		let pre = ChargeAssetTxPayment::<Test>::from(5, Some(asset_id))
+			.pre_dispatch(&1, CALL, &info_from_weight(5), LEN)
+			.unwrap();
+		assert_eq!(tokens(1, &asset_id), 1_000 - 40);
+
+		assert_ok!(ChargeAssetTxPayment::<Test>::post_dispatch(
+			pre, &info_from_weight(5), &post_info_from_weight(5), LEN, &Ok(())
+		));
+		assert_eq!(tokens(1, &asset_id), 1_000 - 40);
+		assert_eq!(Balances::free_balance(TREASURY), 20);
+	});
+}
+
+#[test]
+fn falls_back_to_pool_rate() {
+	new_test_ext().execute_with(|| {
+		let asset_id = mint_asset();
+
+		assert_eq!(AssetTxPayment::native_to_asset(&asset_id, 15), None);
+
+		POOL_ENABLED.with(|v| *v.borrow_mut() = true);
+		assert_eq!(AssetTxPayment::native_to_asset(&asset_id, 15), Some(45));
+
+		// a governance rate takes precedence over the pool.
+		assert_ok!(AssetTxPayment::set_rate(Origin::root(), asset_id, rate(2)));
+		assert_eq!(AssetTxPayment::native_to_asset(&asset_id, 15), Some(30));
+		POOL_ENABLED.with(|v| *v.borrow_mut() = false);
+	});
+}
+
+#[test]
+fn small_fees_cost_at_least_one_token() {
+	new_test_ext().execute_with(|| {
+		let asset_id = mint_asset();
+		assert_ok!(AssetTxPayment::set_rate(
+			Origin::root(), asset_id, Some(FixedU128::saturating_from_rational(1, 100))
+		));
+
+		assert_eq!(AssetTxPayment::native_to_asset(&asset_id, 15), Some(1));
+		assert_eq!(AssetTxPayment::native_to_asset(&asset_id, 0), Some(0));
+	});
+}
+
+#[test]
+fn invalid_asset_payments() {
+	new_test_ext().execute_with(|| {
+		let asset_id = mint_asset();
+		let fee_account = AssetTxPayment::account_id();
+		let payment = ChargeAssetTxPayment::<Test>::from(0, Some(asset_id));
+		let invalid = TransactionValidityError::Invalid(InvalidTransaction::Payment);
+
+		// no rate for the asset.
+		assert_eq!(payment.validate(&1, CALL, &info_from_weight(5), LEN), Err(invalid));
+
+		assert_ok!(AssetTxPayment::set_rate(Origin::root(), asset_id, rate(2)));
+
+		// not enough tokens.
+		assert_eq!(payment.validate(&2, CALL, &info_from_weight(5), LEN), Err(invalid));
+
+		// the fee account cannot cover the native fee.
+		assert_ok!(Balances::transfer(Origin::signed(fee_account), 2, 990));
+		assert_eq!(
+			payment.clone().pre_dispatch(&1, CALL, &info_from_weight(5), LEN).map(|_| ()),
+			Err(invalid)
+		);
+		assert_eq!(tokens(1, &asset_id), 1_000);
+	});
+}
+
+#[test]
+fn fee_account_is_endowed_at_genesis() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Balances::free_balance(AssetTxPayment::account_id()), 1_000);
+		assert_eq!(Balances::total_issuance(), 1_200);
+	});
+}
+
+#[test]
+fn sweep() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = mint_asset();
+		let fee_account = AssetTxPayment::account_id();
+
+		assert_noop!(
+			AssetTxPayment::sweep(Origin::root(), asset_id, TREASURY),
+			Error::<Test>::NothingToSweep
+		);
+
+		assert_ok!(<AssetsNFT as Token<_, _>>::transfer(&1, &fee_account, &asset_id, 30));
+		assert_noop!(AssetTxPayment::sweep(Origin::signed(1), asset_id, TREASURY), BadOrigin);
+
+		assert_ok!(AssetTxPayment::sweep(Origin::root(), asset_id, TREASURY));
+		assert_eq!(tokens(fee_account, &asset_id), 0);
+		assert_eq!(tokens(TREASURY, &asset_id), 30);
+		assert_eq!(last_event(), TestEvent::asset_tx_payment(RawEvent::Swept(asset_id, TREASURY, 30)));
+	});
+}
@@ -0,0 +1,6 @@
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+    fn set_rate() -> Weight;
+    fn sweep() -> Weight;
+}
@@ -19,9 +19,9 @@ mod token;
 mod weight_info;
 mod default_weights;
 
-use crate::nft::NFT;
-use crate::token::Token;
-use crate::weight_info::WeightInfo;
+pub use crate::nft::NFT;
+pub use crate::token::Token;
+pub use crate::weight_info::WeightInfo;
 
 #[cfg(test)]
 mod mock;
@@ -55,6 +55,21 @@ pub type AssetId<T> = <T as system::Trait>::Hash;
 
 pub type Asset<T, I> = (AssetId<T>, <T as Trait<I>>::AssetInfo);
 
+/// Version of the layout of [`RawEvent`], exposed as the `EventSchemaVersion` module constant.
+///
+/// It is bumped whenever a variant is added, removed or changes its fields, so indexers can
+/// pick the decoder matching the runtime they are reading. Each instance of the pallet emits
+/// the same events under its own module name, which tells the instances apart.
+///
+/// - `1`: `Burned(asset_id)`, `Minted(asset_id, owner, supply)`,
+///   `AssetTransferred(asset_id, to)`, `TokenTransferred(asset_id, to, amount)`.
+/// - `2`: adds the sender, the previous owner, the burned supply, the `AssetInfo` hash and
+///   the operator, as documented on each variant.
+///
+/// The operator of an event is the signed account that dispatched it. It is `None` for other
+/// origins and when another pallet acts through the `NFT` or `Token` traits.
+pub const EVENT_SCHEMA_VERSION: u32 = 2;
+
 decl_storage! {
 	trait Store for Module<T: Trait<I>, I: Instance = DefaultInstance> as AssetNFT {
 		TotalAsset get(fn total_asset): u128 = 0;
@@ -85,10 +100,17 @@ decl_event!(
 		<T as system::Trait>::AccountId,
 		<T as Trait<I>>::Balance,
 	{
-        Burned(AssetId),
-        Minted(AssetId, AccountId, Balance),
-        AssetTransferred(AssetId, AccountId),
-        TokenTransferred(AssetId, AccountId, Balance),
+		/// An asset was burned along with its tokens, `burned_supply` of them.
+		/// [asset_id, owner, burned_supply, operator]
+		Burned(AssetId, AccountId, Balance, Option<AccountId>),
+		/// An asset was minted with `supply` tokens, all held by its owner. `info_hash` is the
+		/// blake2-256 hash of the encoded `AssetInfo`.
+		/// [asset_id, owner, supply, info_hash, operator]
+		Minted(AssetId, AccountId, Balance, [u8; 32], Option<AccountId>),
+		/// The ownership of an asset moved. [asset_id, from, to, operator]
+		AssetTransferred(AssetId, AccountId, AccountId, Option<AccountId>),
+		/// Tokens of an asset moved. [asset_id, from, to, amount, operator]
+		TokenTransferred(AssetId, AccountId, AccountId, Balance, Option<AccountId>),
 	}
 );
 
@@ -110,6 +132,9 @@ decl_module! {
 	pub struct Module<T: Trait<I>, I: Instance = DefaultInstance> for enum Call where origin: T::Origin {
 		type Error = Error<T, I>;
 
+		/// Version of the event schema, see [`EVENT_SCHEMA_VERSION`].
+		const EventSchemaVersion: u32 = EVENT_SCHEMA_VERSION;
+
 		fn deposit_event() = default;
 
 		#[weight = T::WeightInfo::mint()]
@@ -119,11 +144,13 @@ decl_module! {
 			asset_info: T::AssetInfo,
 			balance: T::Balance,
 		) -> dispatch::DispatchResult {
-			T::MintOrigin::ensure_origin(origin)?;
+			T::MintOrigin::ensure_origin(origin.clone())?;
+			let operator = ensure_signed(origin).ok();
 
+			let info_hash = asset_info.blake2_256();
 			let asset_id = <Self as NFT<_>>::mint(&dest_account, asset_info)?;
-            <Self as Token<_, _>>::issue(&dest_account, &asset_id, balance)?;
-            Self::deposit_event(RawEvent::Minted(asset_id, dest_account.clone(), balance));
+			<Self as Token<_, _>>::issue(&dest_account, &asset_id, balance)?;
+			Self::deposit_event(RawEvent::Minted(asset_id, dest_account, balance, info_hash, operator));
 
 			Ok(())
 		}
@@ -136,14 +163,18 @@ decl_module! {
 			ensure!(T::AllowBurn::get(), Error::<T, I>::DisallowedOperation);
 
 			T::BurnOrigin::ensure_origin(origin.clone())?;
+			let owner = Self::account_for_asset(&asset_id);
 
 			if T::BurnOwnedCheck::get() {
-				let who = ensure_signed(origin)?;
-				ensure!(who == Self::account_for_asset(&asset_id), Error::<T, I>::NotAssetOwner);
+				let who = ensure_signed(origin.clone())?;
+				ensure!(who == owner, Error::<T, I>::NotAssetOwner);
 			}
 
+			let operator = ensure_signed(origin).ok();
+
+			let burned_supply = Self::total_asset_supply(&asset_id);
 			<Self as NFT<_>>::burn(&asset_id)?;
-			Self::deposit_event(RawEvent::Burned(asset_id.clone()));
+			Self::deposit_event(RawEvent::Burned(asset_id, owner, burned_supply, operator));
 
             Ok(())
 		}
@@ -158,10 +189,7 @@ decl_module! {
 			ensure!(who == Self::account_for_asset(&asset_id), Error::<T, I>::NotAssetOwner);
 
 			let dest_account = T::Lookup::lookup(dest_account)?;
-			<Self as NFT<_>>::transfer(&dest_account, &asset_id)?;
-            Self::deposit_event(RawEvent::AssetTransferred(asset_id.clone(), dest_account.clone()));
-
-            Ok(())
+			Self::do_transfer_asset(&dest_account, &asset_id, Some(who))
 		}
 
 		#[weight = T::WeightInfo::transfer_token()]
@@ -175,14 +203,70 @@ decl_module! {
 			ensure!(!Self::asset_balances(&asset_id, &who).is_zero(), Error::<T, I>::NotAssetTokenOwner);
 
 			let dest_account = T::Lookup::lookup(dest_account)?;
-			<Self as Token<_, _>>::transfer(&who, &dest_account, &asset_id, amount.clone())?;
-            Self::deposit_event(RawEvent::TokenTransferred(asset_id.clone(), dest_account.clone(), amount.clone()));
-
-			Ok(())
+			Self::do_transfer_token(&who, &dest_account, &asset_id, amount, Some(who.clone()))
 		}
 	}
 }
 
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+	/// Move the ownership of `asset_id` to `dest_account` on behalf of `operator`.
+	fn do_transfer_asset(
+		dest_account: &T::AccountId,
+		asset_id: &AssetId<T>,
+		operator: Option<T::AccountId>,
+	) -> dispatch::DispatchResult {
+		let owner = Self::account_for_asset(&asset_id);
+
+		ensure!(owner != T::AccountId::default(), Error::<T, I>::NonexistentAsset);
+		ensure!(Self::total_for_account(dest_account) < T::UserAssetLimit::get(), Error::<T, I>::TooManyAssetsForAccount);
+
+		let transfer_asset = (*asset_id, <T as Trait<I>>::AssetInfo::default());
+
+		<TotalForAccount<T, I>>::mutate(&owner, |total| *total -= 1);
+		<TotalForAccount<T, I>>::mutate(dest_account, |total| *total += 1);
+		<AccountForAsset<T, I>>::insert(&asset_id, &dest_account);
+
+		let asset = <AssetsForAccount<T, I>>::mutate(&owner, |assets| {
+			let pos = assets
+				.binary_search(&transfer_asset)
+				.expect("We already checked that we have the correct owner; qed");
+			assets.remove(pos)
+		});
+
+		<AssetsForAccount<T, I>>::mutate(dest_account, |assets| {
+			match assets.binary_search(&asset) {
+				Ok(_pos) => {} // should never happen
+				Err(pos) => assets.insert(pos, asset),
+			}
+		});
+
+		Self::deposit_event(RawEvent::AssetTransferred(*asset_id, owner, dest_account.clone(), operator));
+
+		Ok(())
+	}
+
+	/// Move `amount` tokens of `asset_id` from `origin` to `target` on behalf of `operator`.
+	fn do_transfer_token(
+		origin: &T::AccountId,
+		target: &T::AccountId,
+		asset_id: &AssetId<T>,
+		amount: T::Balance,
+		operator: Option<T::AccountId>,
+	) -> dispatch::DispatchResult {
+		let origin_balance = <AssetBalances<T, I>>::get(asset_id, origin.clone());
+
+		ensure!(!amount.is_zero(), Error::<T, I>::TokenAmountZero);
+		ensure!(origin_balance >= amount, Error::<T, I>::TokenBalanceLow);
+
+		<AssetBalances<T, I>>::insert(asset_id, origin.clone(), origin_balance - amount.clone());
+		<AssetBalances<T, I>>::mutate(asset_id, target, |balance| *balance += amount.clone());
+
+		Self::deposit_event(RawEvent::TokenTransferred(*asset_id, origin.clone(), target.clone(), amount, operator));
+
+		Ok(())
+	}
+}
+
 impl<T: Trait<I>, I: Instance> NFT<T::AccountId> for Module<T, I> {
 	type AssetId = AssetId<T>;
 	type AssetInfo = T::AssetInfo;
@@ -262,32 +346,7 @@ impl<T: Trait<I>, I: Instance> NFT<T::AccountId> for Module<T, I> {
 		dest_account: &T::AccountId,
 		asset_id: &Self::AssetId
 	) -> dispatch::DispatchResult {
-		let owner = Self::account_for_asset(&asset_id);
-
-		ensure!(owner != T::AccountId::default(), Error::<T, I>::NonexistentAsset);
-		ensure!(Self::total_for_account(dest_account) < T::UserAssetLimit::get(), Error::<T, I>::TooManyAssetsForAccount);
-
-		let transfer_asset = (*asset_id, <T as Trait<I>>::AssetInfo::default());
-
-		<TotalForAccount<T, I>>::mutate(&owner, |total| *total -= 1);
-		<TotalForAccount<T, I>>::mutate(dest_account, |total| *total += 1);
-		<AccountForAsset<T, I>>::insert(&asset_id, &dest_account);
-
-		let asset = <AssetsForAccount<T, I>>::mutate(owner, |assets| {
-			let pos = assets
-				.binary_search(&transfer_asset)
-				.expect("We already checked that we have the correct owner; qed");
-			assets.remove(pos)
-		});
-
-		<AssetsForAccount<T, I>>::mutate(dest_account, |assets| {
-			match assets.binary_search(&asset) {
-				Ok(_pos) => {} // should never happen
-				Err(pos) => assets.insert(pos, asset),
-			}
-		});
-
-		Ok(())
+		Self::do_transfer_asset(dest_account, asset_id, None)
 	}
 }
 
@@ -301,15 +360,11 @@ impl<T: Trait<I>, I: Instance> Token<T::AccountId, T::Balance> for Module<T, I>
 		Ok(())
 	}
 
-	fn transfer(origin: &T::AccountId, target: &T::AccountId, asset_id: &Self::AssetId, amount: T::Balance) -> dispatch::DispatchResult {
-		let origin_balance = <AssetBalances<T, I>>::get(asset_id, origin.clone());
-
-		ensure!(!amount.is_zero(), Error::<T, I>::TokenAmountZero);
-		ensure!(origin_balance >= amount, Error::<T, I>::TokenBalanceLow);
-
-		<AssetBalances<T, I>>::insert(asset_id, origin.clone(), origin_balance - amount.clone());
-		<AssetBalances<T, I>>::mutate(asset_id, target, |balance| *balance += amount.clone());
+	fn balance(target: &T::AccountId, asset_id: &Self::AssetId) -> T::Balance {
+		<AssetBalances<T, I>>::get(asset_id, target)
+	}
 
-		Ok(())
+	fn transfer(origin: &T::AccountId, target: &T::AccountId, asset_id: &Self::AssetId, amount: T::Balance) -> dispatch::DispatchResult {
+		Self::do_transfer_token(origin, target, asset_id, amount, None)
 	}
 }
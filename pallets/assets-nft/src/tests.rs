@@ -64,7 +64,7 @@ fn mint() {
 		assert_eq!(AssetsNFT::asset_balances(asset_id, 1), 100);
 		assert_eq!(AssetsNFT::total_asset_supply(asset_id), 100);
 		assert_eq!(events(), [
-			TestEvent::asset_nft(RawEvent::Minted(asset_id, 1, 100)),
+			TestEvent::asset_nft(RawEvent::Minted(asset_id, 1, 100, ASSET_ID, Some(0))),
 		]);
 	});
 }
@@ -85,7 +85,7 @@ fn burn() {
 		assert_eq!(AssetsNFT::account_for_asset(asset_id), 0);
 		assert_eq!(AssetsNFT::asset_balances(asset_id, 1), 0);
 		assert_eq!(AssetsNFT::total_asset_supply(asset_id), 0);
-		assert_eq!(last_event(), TestEvent::asset_nft(RawEvent::Burned(asset_id)));
+		assert_eq!(last_event(), TestEvent::asset_nft(RawEvent::Burned(asset_id, 1, 100, Some(0))));
 	})
 }
 
@@ -103,7 +103,7 @@ fn transfer_asset() {
 		assert_eq!(AssetsNFT::account_for_asset(asset_id), 2);
 		assert_eq!(AssetsNFT::assets_for_account(1), vec![]);
 		assert_eq!(AssetsNFT::assets_for_account(2), vec![(asset_id, vec![])]);
-		assert_eq!(last_event(), TestEvent::asset_nft(RawEvent::AssetTransferred(asset_id, 2)));
+		assert_eq!(last_event(), TestEvent::asset_nft(RawEvent::AssetTransferred(asset_id, 1, 2, Some(1))));
 	})
 }
 
@@ -118,7 +118,7 @@ fn transfer_token() {
 
 		assert_eq!(AssetsNFT::asset_balances(asset_id, 1), 80);
 		assert_eq!(AssetsNFT::asset_balances(asset_id, 2), 20);
-		assert_eq!(last_event(), TestEvent::asset_nft(RawEvent::TokenTransferred(asset_id, 2, 20)));
+		assert_eq!(last_event(), TestEvent::asset_nft(RawEvent::TokenTransferred(asset_id, 1, 2, 20, Some(1))));
 
 		assert_noop!(
 			AssetsNFT::transfer_token(Origin::signed(1), 2, asset_id, 0),
@@ -136,3 +136,21 @@ fn transfer_token() {
 		);
 	})
 }
+
+#[test]
+fn trait_transfers_have_no_operator() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = H256::from_slice(&ASSET_ID);
+
+		assert_ok!(AssetsNFT::mint(Origin::signed(0), 1, vec![], 100));
+		events();
+
+		assert_ok!(<AssetsNFT as Token<_, _>>::transfer(&1, &2, &asset_id, 30));
+		assert_ok!(<AssetsNFT as NFT<_>>::transfer(&2, &asset_id));
+		assert_eq!(events(), [
+			TestEvent::asset_nft(RawEvent::TokenTransferred(asset_id, 1, 2, 30, None)),
+			TestEvent::asset_nft(RawEvent::AssetTransferred(asset_id, 1, 2, None)),
+		]);
+	})
+}
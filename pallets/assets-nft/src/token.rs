@@ -5,5 +5,6 @@ pub trait Token<AccountId, Balance> {
     type AssetId;
 
     fn issue(origin: &AccountId, assert_id: &Self::AssetId, total: Balance) -> DispatchResult;
+    fn balance(target: &AccountId, assert_id: &Self::AssetId) -> Balance;
     fn transfer(origin: &AccountId, target: &AccountId, assert_id: &Self::AssetId, amount: Balance) -> DispatchResult;
 }
\ No newline at end of file
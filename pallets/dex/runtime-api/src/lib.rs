@@ -0,0 +1,43 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API definition for the dex pallet.
+
+use codec::{Codec, Decode, Encode};
+use sp_runtime::RuntimeDebug;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// The reserves and outstanding liquidity shares of a pool.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PoolInfo<Balance, AssetBalance> {
+	/// Native currency held by the pool.
+	pub native_reserve: Balance,
+	/// Asset tokens held by the pool.
+	pub asset_reserve: AssetBalance,
+	/// Liquidity shares issued to providers.
+	pub total_shares: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Quotes swaps against the native/asset pools.
+	///
+	/// Every quote includes the swap fee and is `None` if the pool does not exist or lacks the
+	/// liquidity for the swap.
+	pub trait DexApi<AssetId, Balance, AssetBalance> where
+		AssetId: Codec,
+		Balance: Codec,
+		AssetBalance: Codec,
+	{
+		/// The state of the pool of `asset_id`.
+		fn pool(asset_id: AssetId) -> Option<PoolInfo<Balance, AssetBalance>>;
+		/// Tokens received when selling exactly `native_in`.
+		fn quote_exact_native_for_asset(asset_id: AssetId, native_in: Balance) -> Option<AssetBalance>;
+		/// Native currency required to buy exactly `asset_out` tokens.
+		fn quote_native_for_exact_asset(asset_id: AssetId, asset_out: AssetBalance) -> Option<Balance>;
+		/// Native currency received when selling exactly `asset_in` tokens.
+		fn quote_exact_asset_for_native(asset_id: AssetId, asset_in: AssetBalance) -> Option<Balance>;
+		/// Tokens required to buy exactly `native_out`.
+		fn quote_asset_for_exact_native(asset_id: AssetId, native_out: Balance) -> Option<AssetBalance>;
+	}
+}
@@ -0,0 +1,467 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Dex
+//!
+//! Constant-product (`x * y = k`) liquidity pools between the native currency and the tokens of
+//! an assets-nft asset, one pool per asset.
+//!
+//! Liquidity providers deposit both sides of a pool at its current ratio and receive shares of
+//! it, which are redeemed for a proportional part of the reserves. The first deposit sets the
+//! price and mints `sqrt(native * tokens)` shares. Swaps charge `SwapFee` on their input, which
+//! stays in the pool and so accrues to the liquidity providers. Every swap comes in an
+//! exact-input and an exact-output variant, each bounded by a slippage limit.
+//!
+//! The reserves of a pool are held by an account derived from `ModuleId` and the asset id.
+//! Native currency only leaves that account keeping it alive, unless the last shares of the
+//! pool are redeemed.
+
+use frame_support::{
+	Parameter, decl_error, decl_event, decl_module, decl_storage, dispatch, ensure, transactional,
+	traits::{Currency, ExistenceRequirement, Get},
+};
+use frame_system::{self as system, ensure_signed};
+use pallet_assets_nft::Token;
+use sp_core::U256;
+use sp_runtime::{
+	ModuleId, PerThing, Permill,
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, Bounded, Member, SaturatedConversion, Zero},
+};
+
+mod weight_info;
+mod default_weights;
+
+pub use crate::weight_info::WeightInfo;
+pub use pallet_dex_runtime_api::PoolInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub type BalanceOf<T> =
+	<<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+
+pub type PoolInfoOf<T> = PoolInfo<BalanceOf<T>, <T as Trait>::AssetBalance>;
+
+pub trait Trait: system::Trait {
+	type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+	/// The native currency.
+	type Currency: Currency<Self::AccountId>;
+
+	type AssetId: Member + Parameter + Copy + Default;
+
+	type AssetBalance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy;
+
+	/// The asset tokens traded against the native currency.
+	type Assets: Token<Self::AccountId, Self::AssetBalance, AssetId = Self::AssetId>;
+
+	/// Fee taken from the input of every swap and left in the pool.
+	type SwapFee: Get<Permill>;
+
+	/// Id the pool accounts are derived from.
+	type ModuleId: Get<ModuleId>;
+
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as Dex {
+		/// The pool trading the native currency against the tokens of an asset.
+		Pools get(fn pool):
+			map hasher(twox_64_concat) T::AssetId => Option<PoolInfoOf<T>>;
+
+		/// Liquidity shares of a pool owned by an account.
+		Shares get(fn shares):
+			double_map hasher(twox_64_concat) T::AssetId, hasher(blake2_128_concat) T::AccountId => BalanceOf<T>;
+	}
+}
+
+decl_event!(
+	pub enum Event<T>
+	where
+		<T as system::Trait>::AccountId,
+		AssetId = <T as Trait>::AssetId,
+		Balance = BalanceOf<T>,
+		AssetBalance = <T as Trait>::AssetBalance,
+	{
+		/// Liquidity was deposited into a pool. [who, asset_id, native, tokens, shares]
+		LiquidityAdded(AccountId, AssetId, Balance, AssetBalance, Balance),
+		/// Liquidity was withdrawn from a pool. [who, asset_id, native, tokens, shares]
+		LiquidityRemoved(AccountId, AssetId, Balance, AssetBalance, Balance),
+		/// Native currency was sold for tokens. [who, asset_id, native_in, tokens_out]
+		SwappedNativeForAsset(AccountId, AssetId, Balance, AssetBalance),
+		/// Tokens were sold for native currency. [who, asset_id, tokens_in, native_out]
+		SwappedAssetForNative(AccountId, AssetId, AssetBalance, Balance),
+	}
+);
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// An amount was zero.
+		ZeroAmount,
+		/// The asset has no pool.
+		PoolNotFound,
+		/// The pool cannot provide the requested amount.
+		InsufficientLiquidity,
+		/// The account owns fewer shares than requested.
+		InsufficientShares,
+		/// The amounts moved are worse than the given limit.
+		SlippageExceeded,
+		Overflow,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		const SwapFee: Permill = T::SwapFee::get();
+
+		fn deposit_event() = default;
+
+		/// Deposit `native_amount` and the matching amount of tokens into the pool of `asset_id`,
+		/// creating it if needed.
+		///
+		/// A new pool takes exactly `max_asset_amount` tokens, which sets its price. Otherwise
+		/// the tokens are taken at the pool's ratio and may not exceed `max_asset_amount`.
+		/// Fails if fewer than `min_shares` shares would be minted.
+		#[weight = T::WeightInfo::add_liquidity()]
+		#[transactional]
+		pub fn add_liquidity(
+			origin,
+			asset_id: T::AssetId,
+			native_amount: BalanceOf<T>,
+			max_asset_amount: T::AssetBalance,
+			min_shares: BalanceOf<T>,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!native_amount.is_zero() && !max_asset_amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let mut pool = Self::pool(&asset_id).unwrap_or_default();
+			let (asset_amount, shares) = if pool.total_shares.is_zero() {
+				let shares = (U256::from(native_amount.saturated_into::<u128>())
+					* U256::from(max_asset_amount.saturated_into::<u128>()))
+					.integer_sqrt();
+				(max_asset_amount, to_u128(shares).ok_or(Error::<T>::Overflow)?)
+			} else {
+				let native_reserve = pool.native_reserve.saturated_into::<u128>();
+				let asset_amount = mul_div_ceil(
+					native_amount.saturated_into(),
+					pool.asset_reserve.saturated_into(),
+					native_reserve,
+				).ok_or(Error::<T>::Overflow)?;
+				let shares = mul_div(
+					native_amount.saturated_into(),
+					pool.total_shares.saturated_into(),
+					native_reserve,
+				).ok_or(Error::<T>::Overflow)?;
+				(asset_amount.saturated_into(), shares)
+			};
+			let shares: BalanceOf<T> = shares.saturated_into();
+			ensure!(asset_amount <= max_asset_amount, Error::<T>::SlippageExceeded);
+			ensure!(!shares.is_zero() && shares >= min_shares, Error::<T>::SlippageExceeded);
+
+			let pool_account = Self::pool_account(&asset_id);
+			T::Currency::transfer(&who, &pool_account, native_amount, ExistenceRequirement::KeepAlive)?;
+			T::Assets::transfer(&who, &pool_account, &asset_id, asset_amount)?;
+
+			pool.native_reserve += native_amount;
+			pool.asset_reserve += asset_amount;
+			pool.total_shares += shares;
+			Pools::<T>::insert(&asset_id, pool);
+			Shares::<T>::mutate(&asset_id, &who, |owned| *owned += shares);
+
+			Self::deposit_event(RawEvent::LiquidityAdded(who, asset_id, native_amount, asset_amount, shares));
+
+			Ok(())
+		}
+
+		/// Redeem `shares` of the pool of `asset_id` for their part of its reserves, failing if
+		/// that is less than `min_native` or `min_asset`.
+		///
+		/// Redeeming the last shares of a pool closes it.
+		#[weight = T::WeightInfo::remove_liquidity()]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin,
+			asset_id: T::AssetId,
+			shares: BalanceOf<T>,
+			min_native: BalanceOf<T>,
+			min_asset: T::AssetBalance,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!shares.is_zero(), Error::<T>::ZeroAmount);
+			let mut pool = Self::pool(&asset_id).ok_or(Error::<T>::PoolNotFound)?;
+			let owned = Self::shares(&asset_id, &who);
+			ensure!(owned >= shares, Error::<T>::InsufficientShares);
+
+			let total_shares = pool.total_shares.saturated_into::<u128>();
+			let native_amount: BalanceOf<T> = mul_div(
+				shares.saturated_into(),
+				pool.native_reserve.saturated_into(),
+				total_shares,
+			).ok_or(Error::<T>::Overflow)?.saturated_into();
+			let asset_amount: T::AssetBalance = mul_div(
+				shares.saturated_into(),
+				pool.asset_reserve.saturated_into(),
+				total_shares,
+			).ok_or(Error::<T>::Overflow)?.saturated_into();
+			ensure!(native_amount >= min_native && asset_amount >= min_asset, Error::<T>::SlippageExceeded);
+
+			let closing = shares == pool.total_shares;
+			let pool_account = Self::pool_account(&asset_id);
+			if !native_amount.is_zero() {
+				let existence = if closing {
+					ExistenceRequirement::AllowDeath
+				} else {
+					ExistenceRequirement::KeepAlive
+				};
+				T::Currency::transfer(&pool_account, &who, native_amount, existence)?;
+			}
+			if !asset_amount.is_zero() {
+				T::Assets::transfer(&pool_account, &who, &asset_id, asset_amount)?;
+			}
+
+			if closing {
+				Pools::<T>::remove(&asset_id);
+			} else {
+				pool.native_reserve -= native_amount;
+				pool.asset_reserve -= asset_amount;
+				pool.total_shares -= shares;
+				Pools::<T>::insert(&asset_id, pool);
+			}
+			if owned == shares {
+				Shares::<T>::remove(&asset_id, &who);
+			} else {
+				Shares::<T>::insert(&asset_id, &who, owned - shares);
+			}
+
+			Self::deposit_event(RawEvent::LiquidityRemoved(who, asset_id, native_amount, asset_amount, shares));
+
+			Ok(())
+		}
+
+		/// Sell exactly `native_in` for at least `min_asset_out` tokens of `asset_id`.
+		#[weight = T::WeightInfo::swap_exact_native_for_asset()]
+		#[transactional]
+		pub fn swap_exact_native_for_asset(
+			origin,
+			asset_id: T::AssetId,
+			native_in: BalanceOf<T>,
+			min_asset_out: T::AssetBalance,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!native_in.is_zero(), Error::<T>::ZeroAmount);
+			let pool = Self::pool(&asset_id).ok_or(Error::<T>::PoolNotFound)?;
+			let asset_out = Self::amount_out(native_in, pool.native_reserve, pool.asset_reserve)
+				.ok_or(Error::<T>::InsufficientLiquidity)?;
+			ensure!(asset_out >= min_asset_out, Error::<T>::SlippageExceeded);
+
+			Self::swap_native_for_asset(who, asset_id, pool, native_in, asset_out)
+		}
+
+		/// Buy exactly `asset_out` tokens of `asset_id` for at most `max_native_in`.
+		#[weight = T::WeightInfo::swap_native_for_exact_asset()]
+		#[transactional]
+		pub fn swap_native_for_exact_asset(
+			origin,
+			asset_id: T::AssetId,
+			asset_out: T::AssetBalance,
+			max_native_in: BalanceOf<T>,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!asset_out.is_zero(), Error::<T>::ZeroAmount);
+			let pool = Self::pool(&asset_id).ok_or(Error::<T>::PoolNotFound)?;
+			let native_in = Self::amount_in(asset_out, pool.native_reserve, pool.asset_reserve)
+				.ok_or(Error::<T>::InsufficientLiquidity)?;
+			ensure!(native_in <= max_native_in, Error::<T>::SlippageExceeded);
+
+			Self::swap_native_for_asset(who, asset_id, pool, native_in, asset_out)
+		}
+
+		/// Sell exactly `asset_in` tokens of `asset_id` for at least `min_native_out`.
+		#[weight = T::WeightInfo::swap_exact_asset_for_native()]
+		#[transactional]
+		pub fn swap_exact_asset_for_native(
+			origin,
+			asset_id: T::AssetId,
+			asset_in: T::AssetBalance,
+			min_native_out: BalanceOf<T>,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!asset_in.is_zero(), Error::<T>::ZeroAmount);
+			let pool = Self::pool(&asset_id).ok_or(Error::<T>::PoolNotFound)?;
+			let native_out = Self::amount_out(asset_in, pool.asset_reserve, pool.native_reserve)
+				.ok_or(Error::<T>::InsufficientLiquidity)?;
+			ensure!(native_out >= min_native_out, Error::<T>::SlippageExceeded);
+
+			Self::swap_asset_for_native(who, asset_id, pool, asset_in, native_out)
+		}
+
+		/// Buy exactly `native_out` for at most `max_asset_in` tokens of `asset_id`.
+		#[weight = T::WeightInfo::swap_asset_for_exact_native()]
+		#[transactional]
+		pub fn swap_asset_for_exact_native(
+			origin,
+			asset_id: T::AssetId,
+			native_out: BalanceOf<T>,
+			max_asset_in: T::AssetBalance,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!native_out.is_zero(), Error::<T>::ZeroAmount);
+			let pool = Self::pool(&asset_id).ok_or(Error::<T>::PoolNotFound)?;
+			let asset_in = Self::amount_in(native_out, pool.asset_reserve, pool.native_reserve)
+				.ok_or(Error::<T>::InsufficientLiquidity)?;
+			ensure!(asset_in <= max_asset_in, Error::<T>::SlippageExceeded);
+
+			Self::swap_asset_for_native(who, asset_id, pool, asset_in, native_out)
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	/// The account holding the reserves of the pool of `asset_id`.
+	pub fn pool_account(asset_id: &T::AssetId) -> T::AccountId {
+		T::ModuleId::get().into_sub_account(asset_id)
+	}
+
+	/// Tokens received when selling exactly `native_in`.
+	pub fn quote_exact_native_for_asset(asset_id: &T::AssetId, native_in: BalanceOf<T>) -> Option<T::AssetBalance> {
+		let pool = Self::pool(asset_id)?;
+		Self::amount_out(native_in, pool.native_reserve, pool.asset_reserve)
+	}
+
+	/// Native currency required to buy exactly `asset_out` tokens.
+	pub fn quote_native_for_exact_asset(asset_id: &T::AssetId, asset_out: T::AssetBalance) -> Option<BalanceOf<T>> {
+		let pool = Self::pool(asset_id)?;
+		Self::amount_in(asset_out, pool.native_reserve, pool.asset_reserve)
+	}
+
+	/// Native currency received when selling exactly `asset_in` tokens.
+	pub fn quote_exact_asset_for_native(asset_id: &T::AssetId, asset_in: T::AssetBalance) -> Option<BalanceOf<T>> {
+		let pool = Self::pool(asset_id)?;
+		Self::amount_out(asset_in, pool.asset_reserve, pool.native_reserve)
+	}
+
+	/// Tokens required to buy exactly `native_out`.
+	pub fn quote_asset_for_exact_native(asset_id: &T::AssetId, native_out: BalanceOf<T>) -> Option<T::AssetBalance> {
+		let pool = Self::pool(asset_id)?;
+		Self::amount_in(native_out, pool.asset_reserve, pool.native_reserve)
+	}
+
+	/// Output of a swap of `amount_in`, after the fee. `None` if nothing would be received.
+	fn amount_out<In, Out>(amount_in: In, reserve_in: In, reserve_out: Out) -> Option<Out>
+		where In: AtLeast32BitUnsigned, Out: AtLeast32BitUnsigned
+	{
+		if amount_in.is_zero() || reserve_in.is_zero() || reserve_out.is_zero() {
+			return None;
+		}
+		let in_after_fee = U256::from(amount_in.saturated_into::<u128>())
+			* U256::from((Permill::one() - T::SwapFee::get()).deconstruct());
+		let numerator = in_after_fee * U256::from(reserve_out.saturated_into::<u128>());
+		let denominator = U256::from(reserve_in.saturated_into::<u128>())
+			* U256::from(Permill::ACCURACY)
+			+ in_after_fee;
+		let amount_out = to_u128(numerator / denominator)?;
+
+		if amount_out.is_zero() {
+			None
+		} else {
+			Some(amount_out.saturated_into())
+		}
+	}
+
+	/// Input, fee included, needed for a swap to output `amount_out`. `None` if the pool
+	/// cannot provide it.
+	fn amount_in<In, Out>(amount_out: Out, reserve_in: In, reserve_out: Out) -> Option<In>
+		where In: AtLeast32BitUnsigned, Out: AtLeast32BitUnsigned
+	{
+		let fee_complement = (Permill::one() - T::SwapFee::get()).deconstruct();
+		if amount_out.is_zero() || reserve_in.is_zero() || amount_out >= reserve_out || fee_complement.is_zero() {
+			return None;
+		}
+		let numerator = U256::from(reserve_in.saturated_into::<u128>())
+			* U256::from(amount_out.saturated_into::<u128>())
+			* U256::from(Permill::ACCURACY);
+		let denominator = U256::from((reserve_out - amount_out).saturated_into::<u128>())
+			* U256::from(fee_complement);
+		let amount_in = to_u128(numerator / denominator + U256::one())?;
+
+		if amount_in > In::max_value().saturated_into::<u128>() {
+			None
+		} else {
+			Some(amount_in.saturated_into())
+		}
+	}
+
+	fn swap_native_for_asset(
+		who: T::AccountId,
+		asset_id: T::AssetId,
+		mut pool: PoolInfoOf<T>,
+		native_in: BalanceOf<T>,
+		asset_out: T::AssetBalance,
+	) -> dispatch::DispatchResult {
+		let pool_account = Self::pool_account(&asset_id);
+		T::Currency::transfer(&who, &pool_account, native_in, ExistenceRequirement::KeepAlive)?;
+		T::Assets::transfer(&pool_account, &who, &asset_id, asset_out)?;
+
+		pool.native_reserve += native_in;
+		pool.asset_reserve -= asset_out;
+		Pools::<T>::insert(&asset_id, pool);
+
+		Self::deposit_event(RawEvent::SwappedNativeForAsset(who, asset_id, native_in, asset_out));
+
+		Ok(())
+	}
+
+	fn swap_asset_for_native(
+		who: T::AccountId,
+		asset_id: T::AssetId,
+		mut pool: PoolInfoOf<T>,
+		asset_in: T::AssetBalance,
+		native_out: BalanceOf<T>,
+	) -> dispatch::DispatchResult {
+		let pool_account = Self::pool_account(&asset_id);
+		T::Assets::transfer(&who, &pool_account, &asset_id, asset_in)?;
+		T::Currency::transfer(&pool_account, &who, native_out, ExistenceRequirement::KeepAlive)?;
+
+		pool.asset_reserve += asset_in;
+		pool.native_reserve -= native_out;
+		Pools::<T>::insert(&asset_id, pool);
+
+		Self::deposit_event(RawEvent::SwappedAssetForNative(who, asset_id, asset_in, native_out));
+
+		Ok(())
+	}
+}
+
+fn to_u128(value: U256) -> Option<u128> {
+	if value > U256::from(u128::max_value()) {
+		None
+	} else {
+		Some(value.low_u128())
+	}
+}
+
+/// `a * b / c`, rounded down.
+fn mul_div(a: u128, b: u128, c: u128) -> Option<u128> {
+	if c.is_zero() {
+		return None;
+	}
+	to_u128(U256::from(a) * U256::from(b) / U256::from(c))
+}
+
+/// `a * b / c`, rounded up.
+fn mul_div_ceil(a: u128, b: u128, c: u128) -> Option<u128> {
+	if c.is_zero() {
+		return None;
+	}
+	let (quotient, remainder) = (U256::from(a) * U256::from(b)).div_mod(U256::from(c));
+	if remainder.is_zero() {
+		to_u128(quotient)
+	} else {
+		to_u128(quotient + U256::one())
+	}
+}
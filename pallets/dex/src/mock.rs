@@ -0,0 +1,129 @@
+use crate::{Module, Trait};
+use sp_core::H256;
+use frame_support::{impl_outer_origin, impl_outer_event, parameter_types, weights::Weight};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup}, testing::Header, ModuleId, Perbill, Permill,
+};
+use frame_system as system;
+
+mod dex {
+	pub use crate::Event;
+}
+
+impl_outer_event! {
+	pub enum TestEvent for Test {
+		system<T>,
+		pallet_balances<T>,
+		pallet_assets_nft<T>,
+		dex<T>,
+	}
+}
+
+impl_outer_origin! {
+	pub enum Origin for Test {}
+}
+
+// Configure a mock runtime to test the pallet.
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
+}
+
+impl system::Trait for Test {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Call = ();
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type MaximumBlockLength = MaximumBlockLength;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Trait for Test {
+	type MaxLocks = ();
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = TestEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MaxAssets: u128 = 10;
+	pub const MaxAssetsPerUser: u64 = 10;
+	pub const BurnOwnedCheck: bool = false;
+	pub const AllowBurn: bool = true;
+}
+
+impl pallet_assets_nft::Trait for Test {
+	type Event = TestEvent;
+	type MintOrigin = system::EnsureSigned<Self::AccountId>;
+	type BurnOrigin = system::EnsureSigned<Self::AccountId>;
+	type AssetInfo = Vec<u8>;
+	type AssetLimit = MaxAssets;
+	type BurnOwnedCheck = BurnOwnedCheck;
+	type AllowBurn = AllowBurn;
+	type UserAssetLimit = MaxAssetsPerUser;
+	type Balance = u128;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const SwapFee: Permill = Permill::from_perthousand(3);
+	pub const DexModuleId: ModuleId = ModuleId(*b"py/dex!!");
+}
+
+impl Trait for Test {
+	type Event = TestEvent;
+	type Currency = Balances;
+	type AssetId = H256;
+	type AssetBalance = u128;
+	type Assets = AssetsNFT;
+	type SwapFee = SwapFee;
+	type ModuleId = DexModuleId;
+	type WeightInfo = ();
+}
+
+pub type System = system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type AssetsNFT = pallet_assets_nft::Module<Test>;
+pub type Dex = Module<Test>;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 10_000), (2, 10_000)],
+	}.assimilate_storage(&mut t).unwrap();
+	t.into()
+}
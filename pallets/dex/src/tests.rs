@@ -0,0 +1,252 @@
+use crate::{Error, mock::*};
+use frame_support::{assert_ok, assert_noop};
+use pallet_assets_nft::Token;
+use sp_core::H256;
+use super::{PoolInfo, RawEvent};
+
+fn last_event() -> TestEvent {
+	System::events()
+		.pop()
+		.expect("Event expected")
+		.event
+}
+
+/// Mint an asset with 10_000 tokens, split between accounts 1 and 2, and return its id.
+fn mint_asset() -> H256 {
+	assert_ok!(AssetsNFT::mint(Origin::signed(0), 1, vec![], 10_000));
+	let asset_id = AssetsNFT::assets_for_account(1)[0].0;
+	assert_ok!(<AssetsNFT as Token<_, _>>::transfer(&1, &2, &asset_id, 5_000));
+	asset_id
+}
+
+/// Mint an asset and open its pool with 1_000 native and 4_000 tokens of account 1.
+fn open_pool() -> H256 {
+	let asset_id = mint_asset();
+	assert_ok!(Dex::add_liquidity(Origin::signed(1), asset_id, 1_000, 4_000, 0));
+	asset_id
+}
+
+fn tokens(who: u64, asset_id: &H256) -> u128 {
+	<AssetsNFT as Token<_, _>>::balance(&who, asset_id)
+}
+
+fn pool(native_reserve: u64, asset_reserve: u128, total_shares: u64) -> Option<PoolInfo<u64, u128>> {
+	Some(PoolInfo { native_reserve, asset_reserve, total_shares })
+}
+
+#[test]
+fn add_liquidity_creates_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = mint_asset();
+		let pool_account = Dex::pool_account(&asset_id);
+
+		assert_ok!(Dex::add_liquidity(Origin::signed(1), asset_id, 1_000, 4_000, 2_000));
+
+		assert_eq!(Dex::pool(&asset_id), pool(1_000, 4_000, 2_000));
+		assert_eq!(Dex::shares(&asset_id, 1), 2_000);
+		assert_eq!(Balances::free_balance(1), 9_000);
+		assert_eq!(Balances::free_balance(pool_account), 1_000);
+		assert_eq!(tokens(1, &asset_id), 1_000);
+		assert_eq!(tokens(pool_account, &asset_id), 4_000);
+		assert_eq!(
+			last_event(),
+			TestEvent::dex(RawEvent::LiquidityAdded(1, asset_id, 1_000, 4_000, 2_000))
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_at_pool_ratio() {
+	new_test_ext().execute_with(|| {
+		let asset_id = open_pool();
+
+		assert_noop!(
+			Dex::add_liquidity(Origin::signed(2), asset_id, 500, 1_999, 0),
+			Error::<Test>::SlippageExceeded
+		);
+		assert_noop!(
+			Dex::add_liquidity(Origin::signed(2), asset_id, 500, 2_000, 1_001),
+			Error::<Test>::SlippageExceeded
+		);
+		assert_noop!(
+			Dex::add_liquidity(Origin::signed(2), asset_id, 0, 2_000, 0),
+			Error::<Test>::ZeroAmount
+		);
+
+		// surplus tokens are not taken.
+		assert_ok!(Dex::add_liquidity(Origin::signed(2), asset_id, 500, 3_000, 1_000));
+		assert_eq!(Dex::pool(&asset_id), pool(1_500, 6_000, 3_000));
+		assert_eq!(Dex::shares(&asset_id, 2), 1_000);
+		assert_eq!(Balances::free_balance(2), 9_500);
+		assert_eq!(tokens(2, &asset_id), 3_000);
+	});
+}
+
+#[test]
+fn remove_liquidity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = open_pool();
+		let pool_account = Dex::pool_account(&asset_id);
+		assert_ok!(Dex::add_liquidity(Origin::signed(2), asset_id, 500, 2_000, 0));
+
+		assert_noop!(
+			Dex::remove_liquidity(Origin::signed(2), asset_id, 1_001, 0, 0),
+			Error::<Test>::InsufficientShares
+		);
+		assert_noop!(
+			Dex::remove_liquidity(Origin::signed(2), asset_id, 1_000, 501, 0),
+			Error::<Test>::SlippageExceeded
+		);
+
+		assert_ok!(Dex::remove_liquidity(Origin::signed(2), asset_id, 1_000, 500, 2_000));
+		assert_eq!(Dex::pool(&asset_id), pool(1_000, 4_000, 2_000));
+		assert_eq!(Dex::shares(&asset_id, 2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000);
+		assert_eq!(tokens(2, &asset_id), 5_000);
+		assert_eq!(
+			last_event(),
+			TestEvent::dex(RawEvent::LiquidityRemoved(2, asset_id, 500, 2_000, 1_000))
+		);
+
+		// the last shares close the pool.
+		assert_ok!(Dex::remove_liquidity(Origin::signed(1), asset_id, 2_000, 0, 0));
+		assert_eq!(Dex::pool(&asset_id), None);
+		assert_eq!(Balances::free_balance(1), 10_000);
+		assert_eq!(Balances::free_balance(pool_account), 0);
+		assert_eq!(tokens(pool_account, &asset_id), 0);
+		assert_noop!(
+			Dex::remove_liquidity(Origin::signed(1), asset_id, 1, 0, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn swap_exact_native_for_asset() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = open_pool();
+
+		assert_eq!(Dex::quote_exact_native_for_asset(&asset_id, 100), Some(362));
+		assert_noop!(
+			Dex::swap_exact_native_for_asset(Origin::signed(2), asset_id, 100, 363),
+			Error::<Test>::SlippageExceeded
+		);
+
+		assert_ok!(Dex::swap_exact_native_for_asset(Origin::signed(2), asset_id, 100, 362));
+		assert_eq!(Dex::pool(&asset_id), pool(1_100, 3_638, 2_000));
+		assert_eq!(Balances::free_balance(2), 9_900);
+		assert_eq!(tokens(2, &asset_id), 5_362);
+		assert_eq!(
+			last_event(),
+			TestEvent::dex(RawEvent::SwappedNativeForAsset(2, asset_id, 100, 362))
+		);
+	});
+}
+
+#[test]
+fn swap_native_for_exact_asset() {
+	new_test_ext().execute_with(|| {
+		let asset_id = open_pool();
+
+		assert_eq!(Dex::quote_native_for_exact_asset(&asset_id, 362), Some(100));
+		assert_noop!(
+			Dex::swap_native_for_exact_asset(Origin::signed(2), asset_id, 362, 99),
+			Error::<Test>::SlippageExceeded
+		);
+
+		assert_ok!(Dex::swap_native_for_exact_asset(Origin::signed(2), asset_id, 362, 100));
+		assert_eq!(Dex::pool(&asset_id), pool(1_100, 3_638, 2_000));
+		assert_eq!(Balances::free_balance(2), 9_900);
+		assert_eq!(tokens(2, &asset_id), 5_362);
+	});
+}
+
+#[test]
+fn swap_exact_asset_for_native() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = open_pool();
+
+		assert_eq!(Dex::quote_exact_asset_for_native(&asset_id, 400), Some(90));
+		assert_noop!(
+			Dex::swap_exact_asset_for_native(Origin::signed(2), asset_id, 400, 91),
+			Error::<Test>::SlippageExceeded
+		);
+
+		assert_ok!(Dex::swap_exact_asset_for_native(Origin::signed(2), asset_id, 400, 90));
+		assert_eq!(Dex::pool(&asset_id), pool(910, 4_400, 2_000));
+		assert_eq!(Balances::free_balance(2), 10_090);
+		assert_eq!(tokens(2, &asset_id), 4_600);
+		assert_eq!(
+			last_event(),
+			TestEvent::dex(RawEvent::SwappedAssetForNative(2, asset_id, 400, 90))
+		);
+	});
+}
+
+#[test]
+fn swap_asset_for_exact_native() {
+	new_test_ext().execute_with(|| {
+		let asset_id = open_pool();
+
+		assert_eq!(Dex::quote_asset_for_exact_native(&asset_id, 90), Some(397));
+		assert_noop!(
+			Dex::swap_asset_for_exact_native(Origin::signed(2), asset_id, 90, 396),
+			Error::<Test>::SlippageExceeded
+		);
+
+		assert_ok!(Dex::swap_asset_for_exact_native(Origin::signed(2), asset_id, 90, 397));
+		assert_eq!(Dex::pool(&asset_id), pool(910, 4_397, 2_000));
+		assert_eq!(Balances::free_balance(2), 10_090);
+		assert_eq!(tokens(2, &asset_id), 4_603);
+	});
+}
+
+#[test]
+fn swaps_need_a_pool_with_liquidity() {
+	new_test_ext().execute_with(|| {
+		let asset_id = mint_asset();
+
+		assert_eq!(Dex::quote_exact_native_for_asset(&asset_id, 100), None);
+		assert_noop!(
+			Dex::swap_exact_native_for_asset(Origin::signed(2), asset_id, 100, 0),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_ok!(Dex::add_liquidity(Origin::signed(1), asset_id, 1_000, 4_000, 0));
+		assert_noop!(
+			Dex::swap_exact_native_for_asset(Origin::signed(2), asset_id, 0, 0),
+			Error::<Test>::ZeroAmount
+		);
+		// too small to buy a single token.
+		assert_noop!(
+			Dex::swap_exact_asset_for_native(Origin::signed(2), asset_id, 1, 0),
+			Error::<Test>::InsufficientLiquidity
+		);
+		// the whole reserve can never be bought.
+		assert_eq!(Dex::quote_native_for_exact_asset(&asset_id, 4_000), None);
+		assert_noop!(
+			Dex::swap_native_for_exact_asset(Origin::signed(2), asset_id, 4_000, 10_000),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn fees_accrue_to_liquidity_providers() {
+	new_test_ext().execute_with(|| {
+		let asset_id = open_pool();
+
+		assert_ok!(Dex::swap_exact_native_for_asset(Origin::signed(2), asset_id, 100, 0));
+		assert_ok!(Dex::swap_exact_asset_for_native(Origin::signed(2), asset_id, 362, 0));
+		assert_eq!(Dex::pool(&asset_id), pool(1_001, 4_000, 2_000));
+		assert_eq!(Balances::free_balance(2), 9_999);
+
+		assert_ok!(Dex::remove_liquidity(Origin::signed(1), asset_id, 2_000, 1_001, 4_000));
+		assert_eq!(Balances::free_balance(1), 10_001);
+		assert_eq!(tokens(1, &asset_id), 5_000);
+	});
+}
@@ -0,0 +1,10 @@
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+    fn add_liquidity() -> Weight;
+    fn remove_liquidity() -> Weight;
+    fn swap_exact_native_for_asset() -> Weight;
+    fn swap_native_for_exact_asset() -> Weight;
+    fn swap_exact_asset_for_native() -> Weight;
+    fn swap_asset_for_exact_native() -> Weight;
+}
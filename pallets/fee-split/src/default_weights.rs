@@ -0,0 +1,14 @@
+#![allow(unused_parens)]
+
+use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
+
+impl crate::WeightInfo for () {
+    fn set_fee_ratio() -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_tip_ratio() -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
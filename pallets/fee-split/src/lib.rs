@@ -0,0 +1,174 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Fee Split
+//!
+//! Splits the transaction fees and tips between the treasury, the block author and a burnt
+//! share, with ratios `UpdateOrigin` can change without a runtime upgrade.
+//!
+//! [`DealWithFees`] is meant as the `OnTransactionPayment` handler of the transaction payment
+//! pallet. Fees and tips have their own [`SplitRatio`], both 80% treasury and 20% author until
+//! set otherwise.
+
+use codec::{Decode, Encode};
+use frame_support::{
+	decl_error, decl_event, decl_module, decl_storage, dispatch, ensure,
+	traits::{Currency, EnsureOrigin, Imbalance, OnUnbalanced},
+};
+use frame_system::{self as system};
+use sp_runtime::RuntimeDebug;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+mod weight_info;
+mod default_weights;
+
+pub use crate::weight_info::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub type NegativeImbalanceOf<T> =
+	<<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::NegativeImbalance;
+
+/// Parts of an amount going to each destination, relative to their sum.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SplitRatio {
+	/// Parts deposited to the treasury.
+	pub treasury: u32,
+	/// Parts paid to the block author.
+	pub author: u32,
+	/// Parts burnt.
+	pub burn: u32,
+}
+
+impl Default for SplitRatio {
+	fn default() -> Self {
+		SplitRatio { treasury: 80, author: 20, burn: 0 }
+	}
+}
+
+impl SplitRatio {
+	fn is_zero(&self) -> bool {
+		self.treasury.saturating_add(self.author).saturating_add(self.burn) == 0
+	}
+}
+
+pub trait Trait: system::Trait {
+	type Event: From<Event> + Into<<Self as system::Trait>::Event>;
+
+	/// The currency fees and tips are paid in.
+	type Currency: Currency<Self::AccountId>;
+
+	/// Origin allowed to change the ratios.
+	type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Receives the treasury share.
+	type TreasuryShare: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+	/// Receives the block author share.
+	type AuthorShare: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as FeeSplit {
+		/// How the fee of a transaction is split.
+		FeeRatio get(fn fee_ratio): SplitRatio;
+		/// How the tip of a transaction is split.
+		TipRatio get(fn tip_ratio): SplitRatio;
+	}
+}
+
+decl_event!(
+	pub enum Event {
+		/// The split of fees changed. [ratio]
+		FeeRatioSet(SplitRatio),
+		/// The split of tips changed. [ratio]
+		TipRatioSet(SplitRatio),
+	}
+);
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// All parts of the ratio are zero.
+		ZeroRatio,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		fn deposit_event() = default;
+
+		/// Set how the fee of a transaction is split.
+		#[weight = T::WeightInfo::set_fee_ratio()]
+		pub fn set_fee_ratio(origin, ratio: SplitRatio) -> dispatch::DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!ratio.is_zero(), Error::<T>::ZeroRatio);
+
+			FeeRatio::put(ratio);
+			Self::deposit_event(Event::FeeRatioSet(ratio));
+
+			Ok(())
+		}
+
+		/// Set how the tip of a transaction is split.
+		#[weight = T::WeightInfo::set_tip_ratio()]
+		pub fn set_tip_ratio(origin, ratio: SplitRatio) -> dispatch::DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!ratio.is_zero(), Error::<T>::ZeroRatio);
+
+			TipRatio::put(ratio);
+			Self::deposit_event(Event::TipRatioSet(ratio));
+
+			Ok(())
+		}
+	}
+}
+
+/// Splits fees and tips by the ratios stored in this pallet.
+///
+/// Each share is rounded down in the order treasury, author, burn, so the last share with
+/// non-zero parts takes the remainder.
+pub struct DealWithFees<T>(PhantomData<T>);
+
+impl<T: Trait> OnUnbalanced<NegativeImbalanceOf<T>> for DealWithFees<T> {
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item=NegativeImbalanceOf<T>>) {
+		if let Some(fees) = fees_then_tips.next() {
+			let (mut treasury, mut author, mut burn) = split(fees, FeeRatio::get());
+
+			if let Some(tips) = fees_then_tips.next() {
+				let (tips_treasury, tips_author, tips_burn) = split(tips, TipRatio::get());
+				treasury.subsume(tips_treasury);
+				author.subsume(tips_author);
+				burn.subsume(tips_burn);
+			}
+
+			T::TreasuryShare::on_unbalanced(treasury);
+			T::AuthorShare::on_unbalanced(author);
+			// Dropping a negative imbalance reduces the total issuance.
+			drop(burn);
+		}
+	}
+}
+
+/// Splits `amount` into its treasury, author and burn shares.
+fn split<I: Imbalance<Balance>, Balance>(amount: I, ratio: SplitRatio) -> (I, I, I) where
+	Balance: From<u32> + sp_runtime::traits::Saturating + sp_std::ops::Div<Output=Balance>,
+{
+	let rest_parts = ratio.author.saturating_add(ratio.burn);
+	if rest_parts == 0 {
+		return (amount, I::zero(), I::zero());
+	}
+
+	let (treasury, rest) = amount.ration(ratio.treasury, rest_parts);
+	let (author, burn) = rest.ration(ratio.author, ratio.burn);
+	(treasury, author, burn)
+}
@@ -0,0 +1,102 @@
+use crate::{DealWithFees, Error, Event, SplitRatio, mock::*};
+use frame_support::{assert_ok, assert_noop, traits::{Currency, OnUnbalanced}};
+use sp_runtime::traits::BadOrigin;
+
+fn last_event() -> TestEvent {
+	System::events()
+		.pop()
+		.expect("Event expected")
+		.event
+}
+
+fn ratio(treasury: u32, author: u32, burn: u32) -> SplitRatio {
+	SplitRatio { treasury, author, burn }
+}
+
+fn pay(fee: u64, tip: Option<u64>) {
+	let fee = Balances::issue(fee);
+	match tip {
+		Some(tip) => DealWithFees::<Test>::on_unbalanceds(vec![fee, Balances::issue(tip)].into_iter()),
+		None => DealWithFees::<Test>::on_unbalanceds(vec![fee].into_iter()),
+	}
+}
+
+#[test]
+fn default_split_is_80_20() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(FeeSplit::fee_ratio(), ratio(80, 20, 0));
+		assert_eq!(FeeSplit::tip_ratio(), ratio(80, 20, 0));
+
+		pay(100, Some(10));
+		assert_eq!(Balances::free_balance(TREASURY), 88);
+		assert_eq!(Balances::free_balance(AUTHOR), 22);
+		assert_eq!(Balances::total_issuance(), 110);
+	});
+}
+
+#[test]
+fn set_ratios() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(FeeSplit::set_fee_ratio(Origin::signed(1), ratio(1, 1, 1)), BadOrigin);
+		assert_noop!(FeeSplit::set_tip_ratio(Origin::signed(1), ratio(1, 1, 1)), BadOrigin);
+		assert_noop!(FeeSplit::set_fee_ratio(Origin::root(), ratio(0, 0, 0)), Error::<Test>::ZeroRatio);
+		assert_noop!(FeeSplit::set_tip_ratio(Origin::root(), ratio(0, 0, 0)), Error::<Test>::ZeroRatio);
+
+		assert_ok!(FeeSplit::set_fee_ratio(Origin::root(), ratio(50, 25, 25)));
+		assert_eq!(FeeSplit::fee_ratio(), ratio(50, 25, 25));
+		assert_eq!(last_event(), TestEvent::fee_split(Event::FeeRatioSet(ratio(50, 25, 25))));
+
+		assert_ok!(FeeSplit::set_tip_ratio(Origin::root(), ratio(0, 1, 0)));
+		assert_eq!(FeeSplit::tip_ratio(), ratio(0, 1, 0));
+		assert_eq!(last_event(), TestEvent::fee_split(Event::TipRatioSet(ratio(0, 1, 0))));
+	});
+}
+
+#[test]
+fn fees_and_tips_use_their_own_ratio() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeSplit::set_fee_ratio(Origin::root(), ratio(50, 25, 25)));
+		assert_ok!(FeeSplit::set_tip_ratio(Origin::root(), ratio(0, 1, 0)));
+
+		pay(100, Some(10));
+		assert_eq!(Balances::free_balance(TREASURY), 50);
+		assert_eq!(Balances::free_balance(AUTHOR), 35);
+		// The burnt quarter of the fee left the total issuance.
+		assert_eq!(Balances::total_issuance(), 85);
+
+		pay(100, None);
+		assert_eq!(Balances::free_balance(TREASURY), 100);
+		assert_eq!(Balances::free_balance(AUTHOR), 60);
+		assert_eq!(Balances::total_issuance(), 160);
+	});
+}
+
+#[test]
+fn rounding_favours_the_later_shares() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeSplit::set_fee_ratio(Origin::root(), ratio(1, 1, 1)));
+		assert_ok!(FeeSplit::set_tip_ratio(Origin::root(), ratio(1, 1, 0)));
+
+		// 10 / 3 rounds down to 3 for the treasury, the remaining 7 splits into 3 and 4.
+		// 5 / 2 rounds down to 2 for the treasury, the author takes the remaining 3.
+		pay(10, Some(5));
+		assert_eq!(Balances::free_balance(TREASURY), 5);
+		assert_eq!(Balances::free_balance(AUTHOR), 6);
+		assert_eq!(Balances::total_issuance(), 11);
+	});
+}
+
+#[test]
+fn missing_shares_get_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeSplit::set_fee_ratio(Origin::root(), ratio(1, 0, 0)));
+		assert_ok!(FeeSplit::set_tip_ratio(Origin::root(), ratio(0, 0, 1)));
+
+		pay(7, Some(3));
+		assert_eq!(Balances::free_balance(TREASURY), 7);
+		assert_eq!(Balances::free_balance(AUTHOR), 0);
+		assert_eq!(Balances::total_issuance(), 7);
+	});
+}
@@ -0,0 +1,6 @@
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+    fn set_fee_ratio() -> Weight;
+    fn set_tip_ratio() -> Weight;
+}
@@ -0,0 +1,114 @@
+//! RPC interface for the fungible-assets pallet.
+
+use std::sync::Arc;
+use std::marker::PhantomData;
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::U256;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_fungible_assets_runtime_api::FungibleAssetsApi as FungibleAssetsRuntimeApi;
+
+/// Balances are returned as `NumberOrHex`, since they may not fit a JavaScript number.
+#[rpc]
+pub trait FungibleAssetsApi<BlockHash, AssetId, AccountId> {
+	/// The balance of `who` in `asset_id`.
+	#[rpc(name = "fungibleAssets_balance")]
+	fn balance(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<NumberOrHex>;
+
+	/// The total supply of `asset_id`, `null` if it does not exist.
+	#[rpc(name = "fungibleAssets_totalSupply")]
+	fn total_supply(
+		&self,
+		asset_id: AssetId,
+		at: Option<BlockHash>,
+	) -> Result<Option<NumberOrHex>>;
+}
+
+/// A struct that implements the [`FungibleAssetsApi`].
+pub struct FungibleAssets<C, M> {
+	client: Arc<C>,
+	_marker: PhantomData<M>,
+}
+
+impl<C, M> FungibleAssets<C, M> {
+	/// Create new `FungibleAssets` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		FungibleAssets { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: "Unable to query fungible assets.".into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, AssetId, AccountId, Balance> FungibleAssetsApi<<Block as BlockT>::Hash, AssetId, AccountId>
+	for FungibleAssets<C, (Block, Balance)>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: FungibleAssetsRuntimeApi<Block, AssetId, AccountId, Balance>,
+	AssetId: Codec,
+	AccountId: Codec,
+	Balance: Codec + Into<U256> + Send + Sync + 'static,
+{
+	fn balance(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.balance(&at, asset_id, who)
+			.map(|balance| NumberOrHex::Hex(balance.into()))
+			.map_err(runtime_error)
+	}
+
+	fn total_supply(
+		&self,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.total_supply(&at, asset_id)
+			.map(|supply| supply.map(|supply| NumberOrHex::Hex(supply.into())))
+			.map_err(runtime_error)
+	}
+}
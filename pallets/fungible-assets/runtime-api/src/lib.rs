@@ -0,0 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API definition for the fungible-assets pallet.
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// Queries the balances of fungible assets.
+	pub trait FungibleAssetsApi<AssetId, AccountId, Balance> where
+		AssetId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The balance of `who` in `asset_id`, zero for unknown assets.
+		fn balance(asset_id: AssetId, who: AccountId) -> Balance;
+		/// The total supply of `asset_id`, `None` if it does not exist.
+		fn total_supply(asset_id: AssetId) -> Option<Balance>;
+	}
+}
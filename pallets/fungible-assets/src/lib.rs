@@ -0,0 +1,691 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Fungible Assets
+//!
+//! Plain fungible tokens, independent of the NFTs of `pallet_assets_nft`.
+//!
+//! Anyone can create an asset under a free id by reserving `AssetDeposit`, and `ForceOrigin` can
+//! create one without a deposit. Each asset has four roles: the owner sets the metadata and can
+//! destroy the asset once its supply is zero, the issuer mints, the admin burns from any account
+//! and the freezer freezes and thaws single accounts or the whole asset.
+//!
+//! No account holds less than the `min_balance` of an asset: a transfer or burn that would leave
+//! less than that takes the whole balance instead. Holders can approve a delegate to transfer
+//! up to an amount on their behalf, reserving `ApprovalDeposit` until the approval is spent or
+//! cancelled. Destroying an asset removes its approvals, and is weighed by how many there are.
+//!
+//! Assets, their metadata and balances can be created in the genesis config.
+
+use codec::{Decode, Encode};
+use frame_support::{
+	IterableStorageDoubleMap, Parameter, decl_error, decl_event, decl_module, decl_storage, dispatch,
+	ensure, transactional,
+	traits::{Currency, EnsureOrigin, Get, ReservableCurrency},
+};
+use frame_system::{self as system, ensure_signed};
+use sp_runtime::{
+	RuntimeDebug,
+	traits::{AtLeast32BitUnsigned, CheckedAdd, Member, StaticLookup, Zero},
+};
+use sp_std::prelude::*;
+
+mod weight_info;
+mod default_weights;
+
+pub use crate::weight_info::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub type DepositBalanceOf<T> =
+	<<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+
+pub type AssetDetailsOf<T> =
+	AssetDetails<<T as Trait>::Balance, <T as system::Trait>::AccountId, DepositBalanceOf<T>>;
+
+pub type ApprovalOf<T> = Approval<<T as Trait>::Balance, DepositBalanceOf<T>>;
+
+/// The state of an asset.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct AssetDetails<Balance, AccountId, DepositBalance> {
+	/// Sets the metadata and destroys the asset.
+	pub owner: AccountId,
+	/// Mints tokens.
+	pub issuer: AccountId,
+	/// Burns tokens from any account.
+	pub admin: AccountId,
+	/// Freezes and thaws accounts and the asset.
+	pub freezer: AccountId,
+	/// Tokens in circulation.
+	pub supply: Balance,
+	/// Reserved from the owner until the asset is destroyed.
+	pub deposit: DepositBalance,
+	/// The smallest balance an account may hold.
+	pub min_balance: Balance,
+	/// Accounts holding the asset.
+	pub accounts: u32,
+	/// Approvals of delegates in the asset.
+	pub approvals: u32,
+	/// Whether transfers of the asset are suspended.
+	pub is_frozen: bool,
+}
+
+/// The balance of an account in an asset.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct AssetBalance<Balance> {
+	pub balance: Balance,
+	/// Whether the account may not transfer its balance.
+	pub is_frozen: bool,
+}
+
+/// The amount a delegate may transfer on behalf of an owner.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct Approval<Balance, DepositBalance> {
+	pub amount: Balance,
+	/// Reserved from the owner until the approval is spent or cancelled.
+	pub deposit: DepositBalance,
+}
+
+/// Descriptive information about an asset.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct AssetMetadata {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	/// Number of decimals the balances are displayed with.
+	pub decimals: u8,
+}
+
+pub trait Trait: system::Trait {
+	type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+	type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy;
+
+	type AssetId: Member + Parameter + Default + Copy;
+
+	/// The currency the asset deposit is reserved in.
+	type Currency: ReservableCurrency<Self::AccountId>;
+
+	/// Origin allowed to create assets without a deposit.
+	type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Reserved from the creator of an asset, covering the asset and its metadata.
+	type AssetDeposit: Get<DepositBalanceOf<Self>>;
+
+	/// Reserved from the owner of an approval, covering its storage.
+	type ApprovalDeposit: Get<DepositBalanceOf<Self>>;
+
+	/// Maximum length of the name and symbol of an asset.
+	type StringLimit: Get<u32>;
+
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as FungibleAssets {
+		/// Details of an asset.
+		Asset get(fn asset):
+			map hasher(blake2_128_concat) T::AssetId => Option<AssetDetailsOf<T>>;
+
+		/// The balance of an account in an asset.
+		Account get(fn account):
+			double_map hasher(blake2_128_concat) T::AssetId, hasher(blake2_128_concat) T::AccountId
+			=> AssetBalance<T::Balance>;
+
+		/// Approval of a delegate to transfer on behalf of an owner, keyed by `(owner, delegate)`.
+		Approvals get(fn approvals):
+			double_map hasher(blake2_128_concat) T::AssetId, hasher(blake2_128_concat) (T::AccountId, T::AccountId)
+			=> Option<ApprovalOf<T>>;
+
+		/// Metadata of an asset.
+		Metadata get(fn metadata):
+			map hasher(blake2_128_concat) T::AssetId => AssetMetadata;
+	}
+	add_extra_genesis {
+		/// Assets to create, as `(id, owner, min_balance)`. The owner holds every role.
+		config(assets): Vec<(T::AssetId, T::AccountId, T::Balance)>;
+		/// Metadata of genesis assets, as `(id, name, symbol, decimals)`.
+		config(metadata): Vec<(T::AssetId, Vec<u8>, Vec<u8>, u8)>;
+		/// Balances in genesis assets, as `(id, account, balance)`.
+		config(accounts): Vec<(T::AssetId, T::AccountId, T::Balance)>;
+		build(|config: &GenesisConfig<T>| {
+			for (id, owner, min_balance) in &config.assets {
+				assert!(!Asset::<T>::contains_key(id), "Asset id already in use");
+				assert!(!min_balance.is_zero(), "Min balance should not be zero");
+				Asset::<T>::insert(id, AssetDetails {
+					owner: owner.clone(),
+					issuer: owner.clone(),
+					admin: owner.clone(),
+					freezer: owner.clone(),
+					supply: Zero::zero(),
+					deposit: Zero::zero(),
+					min_balance: *min_balance,
+					accounts: 0,
+					approvals: 0,
+					is_frozen: false,
+				});
+			}
+
+			for (id, name, symbol, decimals) in &config.metadata {
+				assert!(Asset::<T>::contains_key(id), "Metadata of an unknown asset");
+				Metadata::<T>::insert(id, AssetMetadata {
+					name: name.clone(),
+					symbol: symbol.clone(),
+					decimals: *decimals,
+				});
+			}
+
+			for (id, who, balance) in &config.accounts {
+				let mut details = Asset::<T>::get(id).expect("Balance in an unknown asset");
+				assert!(*balance >= details.min_balance, "Balance below the min balance");
+				assert!(!Account::<T>::contains_key(id, who), "Balance set twice");
+				details.supply = details.supply.checked_add(balance).expect("Supply overflow");
+				details.accounts += 1;
+				Account::<T>::insert(id, who, AssetBalance { balance: *balance, is_frozen: false });
+				Asset::<T>::insert(id, details);
+			}
+		});
+	}
+}
+
+decl_event!(
+	pub enum Event<T>
+	where
+		<T as system::Trait>::AccountId,
+		AssetId = <T as Trait>::AssetId,
+		Balance = <T as Trait>::Balance,
+	{
+		/// An asset was created. [asset_id, owner, admin]
+		Created(AssetId, AccountId, AccountId),
+		/// An asset was destroyed. [asset_id]
+		Destroyed(AssetId),
+		/// Tokens were minted. [asset_id, beneficiary, amount]
+		Issued(AssetId, AccountId, Balance),
+		/// Tokens were burned. [asset_id, who, amount]
+		Burned(AssetId, AccountId, Balance),
+		/// Tokens were transferred. [asset_id, from, to, amount]
+		Transferred(AssetId, AccountId, AccountId, Balance),
+		/// Tokens were transferred by a delegate. [asset_id, owner, delegate, to, amount]
+		TransferredApproved(AssetId, AccountId, AccountId, AccountId, Balance),
+		/// An account was frozen. [asset_id, who]
+		Frozen(AssetId, AccountId),
+		/// An account was thawed. [asset_id, who]
+		Thawed(AssetId, AccountId),
+		/// An asset was frozen. [asset_id]
+		AssetFrozen(AssetId),
+		/// An asset was thawed. [asset_id]
+		AssetThawed(AssetId),
+		/// The metadata of an asset was set. [asset_id, name, symbol, decimals]
+		MetadataSet(AssetId, Vec<u8>, Vec<u8>, u8),
+		/// A delegate was approved to transfer tokens. [asset_id, owner, delegate, amount]
+		ApprovedTransfer(AssetId, AccountId, AccountId, Balance),
+		/// An approval was cancelled. [asset_id, owner, delegate]
+		ApprovalCancelled(AssetId, AccountId, AccountId),
+	}
+);
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// The asset id is already in use.
+		InUse,
+		/// The asset does not exist.
+		Unknown,
+		/// The account does not hold the asset.
+		NoAccount,
+		/// The origin does not hold the role required.
+		NoPermission,
+		AmountZero,
+		MinBalanceZero,
+		/// The balance is too low.
+		BalanceLow,
+		/// The resulting balance would be below the min balance.
+		BelowMinimum,
+		/// The asset or the account is frozen.
+		Frozen,
+		Overflow,
+		/// The name or symbol is too long.
+		BadMetadata,
+		/// The delegate is not approved for the amount.
+		Unapproved,
+		/// The asset still has a supply.
+		InCirculation,
+		/// The asset has more approvals than the witness given.
+		BadWitness,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		const AssetDeposit: DepositBalanceOf<T> = T::AssetDeposit::get();
+
+		const ApprovalDeposit: DepositBalanceOf<T> = T::ApprovalDeposit::get();
+
+		const StringLimit: u32 = T::StringLimit::get();
+
+		fn deposit_event() = default;
+
+		/// Create asset `id` owned by the signer, with `admin` as issuer, admin and freezer.
+		///
+		/// Reserves `AssetDeposit` from the signer.
+		#[weight = T::WeightInfo::create()]
+		pub fn create(
+			origin,
+			id: T::AssetId,
+			admin: <T::Lookup as StaticLookup>::Source,
+			min_balance: T::Balance,
+		) -> dispatch::DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let admin = T::Lookup::lookup(admin)?;
+			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+			ensure!(!min_balance.is_zero(), Error::<T>::MinBalanceZero);
+
+			let deposit = T::AssetDeposit::get();
+			T::Currency::reserve(&owner, deposit)?;
+
+			Self::insert_asset(id, owner, admin, deposit, min_balance);
+
+			Ok(())
+		}
+
+		/// Create asset `id` owned by `owner`, who holds every role, without a deposit.
+		#[weight = T::WeightInfo::force_create()]
+		pub fn force_create(
+			origin,
+			id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			min_balance: T::Balance,
+		) -> dispatch::DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+			ensure!(!min_balance.is_zero(), Error::<T>::MinBalanceZero);
+
+			Self::insert_asset(id, owner.clone(), owner, Zero::zero(), min_balance);
+
+			Ok(())
+		}
+
+		/// Destroy asset `id`, whose supply must be zero, returning the deposit to its owner.
+		///
+		/// Its approvals are removed and their deposits returned. `approvals` must be at least
+		/// their number, it bounds the weight.
+		#[weight = T::WeightInfo::destroy(*approvals)]
+		pub fn destroy(origin, id: T::AssetId, #[compact] approvals: u32) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			let details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(who == details.owner, Error::<T>::NoPermission);
+			ensure!(details.supply.is_zero(), Error::<T>::InCirculation);
+			ensure!(details.approvals <= approvals, Error::<T>::BadWitness);
+
+			for ((owner, _), approval) in Approvals::<T>::drain_prefix(id) {
+				T::Currency::unreserve(&owner, approval.deposit);
+			}
+			T::Currency::unreserve(&details.owner, details.deposit);
+			Asset::<T>::remove(id);
+			Metadata::<T>::remove(id);
+			// No account is left once the supply is zero.
+			Account::<T>::remove_prefix(id);
+
+			Self::deposit_event(RawEvent::Destroyed(id));
+
+			Ok(())
+		}
+
+		/// Mint `amount` tokens of asset `id` to `beneficiary`. Only callable by the issuer.
+		#[weight = T::WeightInfo::mint()]
+		pub fn mint(
+			origin,
+			id: T::AssetId,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			amount: T::Balance,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			let mut details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(who == details.issuer, Error::<T>::NoPermission);
+
+			details.supply = details.supply.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			let mut account = Self::account(id, &beneficiary);
+			let new_account = account.balance.is_zero();
+			account.balance = account.balance.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			ensure!(account.balance >= details.min_balance, Error::<T>::BelowMinimum);
+
+			if new_account {
+				details.accounts = details.accounts.saturating_add(1);
+			}
+			Account::<T>::insert(id, &beneficiary, account);
+			Asset::<T>::insert(id, details);
+
+			Self::deposit_event(RawEvent::Issued(id, beneficiary, amount));
+
+			Ok(())
+		}
+
+		/// Burn up to `amount` tokens of asset `id` from `who`. Only callable by the admin.
+		///
+		/// Burns the whole balance if less than the min balance would be left.
+		#[weight = T::WeightInfo::burn()]
+		pub fn burn(
+			origin,
+			id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			amount: T::Balance,
+		) -> dispatch::DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			let mut details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.admin, Error::<T>::NoPermission);
+
+			let mut account = Self::account(id, &who);
+			ensure!(!account.balance.is_zero(), Error::<T>::BalanceLow);
+			let mut burned = amount.min(account.balance);
+			if account.balance - burned < details.min_balance {
+				burned = account.balance;
+			}
+
+			details.supply -= burned;
+			account.balance -= burned;
+			if account.balance.is_zero() {
+				details.accounts = details.accounts.saturating_sub(1);
+				Account::<T>::remove(id, &who);
+			} else {
+				Account::<T>::insert(id, &who, account);
+			}
+			Asset::<T>::insert(id, details);
+
+			Self::deposit_event(RawEvent::Burned(id, who, burned));
+
+			Ok(())
+		}
+
+		/// Transfer `amount` tokens of asset `id` to `target`.
+		///
+		/// Transfers the whole balance if less than the min balance would be left.
+		#[weight = T::WeightInfo::transfer()]
+		pub fn transfer(
+			origin,
+			id: T::AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			amount: T::Balance,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+
+			let transferred = Self::do_transfer(id, &who, &target, amount)?;
+			Self::deposit_event(RawEvent::Transferred(id, who, target, transferred));
+
+			Ok(())
+		}
+
+		/// Freeze the balance of `who` in asset `id`. Only callable by the freezer.
+		#[weight = T::WeightInfo::freeze()]
+		pub fn freeze(
+			origin,
+			id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> dispatch::DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.freezer, Error::<T>::NoPermission);
+			ensure!(Account::<T>::contains_key(id, &who), Error::<T>::NoAccount);
+
+			Account::<T>::mutate(id, &who, |account| account.is_frozen = true);
+			Self::deposit_event(RawEvent::Frozen(id, who));
+
+			Ok(())
+		}
+
+		/// Thaw the balance of `who` in asset `id`. Only callable by the freezer.
+		#[weight = T::WeightInfo::thaw()]
+		pub fn thaw(
+			origin,
+			id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> dispatch::DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.freezer, Error::<T>::NoPermission);
+			ensure!(Account::<T>::contains_key(id, &who), Error::<T>::NoAccount);
+
+			Account::<T>::mutate(id, &who, |account| account.is_frozen = false);
+			Self::deposit_event(RawEvent::Thawed(id, who));
+
+			Ok(())
+		}
+
+		/// Suspend all transfers of asset `id`. Only callable by the freezer.
+		#[weight = T::WeightInfo::freeze_asset()]
+		pub fn freeze_asset(origin, id: T::AssetId) -> dispatch::DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let mut details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.freezer, Error::<T>::NoPermission);
+
+			details.is_frozen = true;
+			Asset::<T>::insert(id, details);
+			Self::deposit_event(RawEvent::AssetFrozen(id));
+
+			Ok(())
+		}
+
+		/// Resume transfers of asset `id`. Only callable by the freezer.
+		#[weight = T::WeightInfo::thaw_asset()]
+		pub fn thaw_asset(origin, id: T::AssetId) -> dispatch::DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let mut details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.freezer, Error::<T>::NoPermission);
+
+			details.is_frozen = false;
+			Asset::<T>::insert(id, details);
+			Self::deposit_event(RawEvent::AssetThawed(id));
+
+			Ok(())
+		}
+
+		/// Set the metadata of asset `id`. Only callable by the owner.
+		#[weight = T::WeightInfo::set_metadata(name.len() as u32, symbol.len() as u32)]
+		pub fn set_metadata(
+			origin,
+			id: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) -> dispatch::DispatchResult {
+			let who = ensure_signed(origin)?;
+			let limit = T::StringLimit::get() as usize;
+			ensure!(name.len() <= limit && symbol.len() <= limit, Error::<T>::BadMetadata);
+			let details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(who == details.owner, Error::<T>::NoPermission);
+
+			Metadata::<T>::insert(id, AssetMetadata {
+				name: name.clone(),
+				symbol: symbol.clone(),
+				decimals,
+			});
+			Self::deposit_event(RawEvent::MetadataSet(id, name, symbol, decimals));
+
+			Ok(())
+		}
+
+		/// Allow `delegate` to transfer up to `amount` tokens of asset `id` on behalf of the
+		/// signer, replacing any previous approval.
+		///
+		/// The signer must hold the asset. A new approval reserves `ApprovalDeposit`.
+		#[weight = T::WeightInfo::approve_transfer()]
+		pub fn approve_transfer(
+			origin,
+			id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			amount: T::Balance,
+		) -> dispatch::DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			let mut details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(Account::<T>::contains_key(id, &owner), Error::<T>::NoAccount);
+
+			let key = (owner, delegate);
+			let approval = match Self::approvals(id, &key) {
+				Some(approval) => Approval { amount, ..approval },
+				None => {
+					let deposit = T::ApprovalDeposit::get();
+					T::Currency::reserve(&key.0, deposit)?;
+					details.approvals = details.approvals.saturating_add(1);
+					Asset::<T>::insert(id, details);
+					Approval { amount, deposit }
+				},
+			};
+			Approvals::<T>::insert(id, &key, approval);
+			let (owner, delegate) = key;
+			Self::deposit_event(RawEvent::ApprovedTransfer(id, owner, delegate, amount));
+
+			Ok(())
+		}
+
+		/// Cancel the approval of `delegate` for asset `id`.
+		#[weight = T::WeightInfo::cancel_approval()]
+		pub fn cancel_approval(
+			origin,
+			id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) -> dispatch::DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let key = (owner, delegate);
+			let approval = Approvals::<T>::take(id, &key).ok_or(Error::<T>::Unapproved)?;
+
+			Self::release_approval(id, &key.0, approval);
+			let (owner, delegate) = key;
+			Self::deposit_event(RawEvent::ApprovalCancelled(id, owner, delegate));
+
+			Ok(())
+		}
+
+		/// Transfer `amount` tokens of asset `id` from `owner` to `target`, spending the approval
+		/// of the signer.
+		#[weight = T::WeightInfo::transfer_approved()]
+		#[transactional]
+		pub fn transfer_approved(
+			origin,
+			id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			target: <T::Lookup as StaticLookup>::Source,
+			amount: T::Balance,
+		) -> dispatch::DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let target = T::Lookup::lookup(target)?;
+			let key = (owner, delegate);
+			let mut approval = Self::approvals(id, &key).ok_or(Error::<T>::Unapproved)?;
+
+			let transferred = Self::do_transfer(id, &key.0, &target, amount)?;
+			ensure!(transferred <= approval.amount, Error::<T>::Unapproved);
+
+			approval.amount -= transferred;
+			if approval.amount.is_zero() {
+				Approvals::<T>::remove(id, &key);
+				Self::release_approval(id, &key.0, approval);
+			} else {
+				Approvals::<T>::insert(id, &key, approval);
+			}
+			let (owner, delegate) = key;
+			Self::deposit_event(RawEvent::TransferredApproved(id, owner, delegate, target, transferred));
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	/// The balance of `who` in asset `id`.
+	pub fn balance(id: T::AssetId, who: T::AccountId) -> T::Balance {
+		Self::account(id, who).balance
+	}
+
+	/// The total supply of asset `id`, `None` if it does not exist.
+	pub fn total_supply(id: T::AssetId) -> Option<T::Balance> {
+		Self::asset(id).map(|details| details.supply)
+	}
+
+	fn insert_asset(
+		id: T::AssetId,
+		owner: T::AccountId,
+		admin: T::AccountId,
+		deposit: DepositBalanceOf<T>,
+		min_balance: T::Balance,
+	) {
+		Asset::<T>::insert(id, AssetDetails {
+			owner: owner.clone(),
+			issuer: admin.clone(),
+			admin: admin.clone(),
+			freezer: admin.clone(),
+			supply: Zero::zero(),
+			deposit,
+			min_balance,
+			accounts: 0,
+			approvals: 0,
+			is_frozen: false,
+		});
+		Self::deposit_event(RawEvent::Created(id, owner, admin));
+	}
+
+	/// Return the deposit of a removed approval of `owner` and uncount it.
+	fn release_approval(id: T::AssetId, owner: &T::AccountId, approval: ApprovalOf<T>) {
+		T::Currency::unreserve(owner, approval.deposit);
+		Asset::<T>::mutate(id, |details| if let Some(details) = details {
+			details.approvals = details.approvals.saturating_sub(1);
+		});
+	}
+
+	/// Move `amount` tokens of asset `id` from `source` to `dest`, or the whole balance of
+	/// `source` if less than the min balance would be left. Returns the amount moved.
+	fn do_transfer(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, dispatch::DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+		let mut details = Self::asset(id).ok_or(Error::<T>::Unknown)?;
+		ensure!(!details.is_frozen, Error::<T>::Frozen);
+
+		let source_account = Self::account(id, source);
+		ensure!(!source_account.is_frozen, Error::<T>::Frozen);
+		ensure!(source_account.balance >= amount, Error::<T>::BalanceLow);
+
+		let amount = if source_account.balance - amount < details.min_balance {
+			source_account.balance
+		} else {
+			amount
+		};
+		if source == dest {
+			return Ok(amount);
+		}
+
+		let mut dest_account = Self::account(id, dest);
+		let new_account = dest_account.balance.is_zero();
+		dest_account.balance = dest_account.balance.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+		ensure!(dest_account.balance >= details.min_balance, Error::<T>::BelowMinimum);
+
+		if new_account {
+			details.accounts = details.accounts.saturating_add(1);
+		}
+		Account::<T>::insert(id, dest, dest_account);
+		if amount == source_account.balance {
+			details.accounts = details.accounts.saturating_sub(1);
+			Account::<T>::remove(id, source);
+		} else {
+			Account::<T>::mutate(id, source, |account| account.balance -= amount);
+		}
+		Asset::<T>::insert(id, details);
+
+		Ok(amount)
+	}
+}
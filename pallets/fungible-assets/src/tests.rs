@@ -0,0 +1,261 @@
+use crate::{AssetBalance, AssetMetadata, Error, mock::*};
+use frame_support::{assert_ok, assert_noop};
+use sp_runtime::traits::BadOrigin;
+use super::RawEvent;
+
+fn last_event() -> TestEvent {
+	System::events()
+		.pop()
+		.expect("Event expected")
+		.event
+}
+
+/// Create asset 0 owned by account 1, administered by itself, with a min balance of 10 and
+/// 100 tokens minted to account 1.
+fn create_asset() {
+	assert_ok!(FungibleAssets::create(Origin::signed(1), 0, 1, 10));
+	assert_ok!(FungibleAssets::mint(Origin::signed(1), 0, 1, 100));
+}
+
+#[test]
+fn genesis_assets() {
+	new_test_ext().execute_with(|| {
+		let details = FungibleAssets::asset(GENESIS_ASSET).unwrap();
+		assert_eq!(details.owner, 1);
+		assert_eq!(details.supply, 150);
+		assert_eq!(details.accounts, 2);
+		assert_eq!(details.deposit, 0);
+		assert_eq!(FungibleAssets::balance(GENESIS_ASSET, 2), 50);
+		assert_eq!(FungibleAssets::metadata(GENESIS_ASSET), AssetMetadata {
+			name: b"Genesis".to_vec(),
+			symbol: b"GEN".to_vec(),
+			decimals: 12,
+		});
+	});
+}
+
+#[test]
+fn create_and_destroy() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(FungibleAssets::create(Origin::signed(1), 0, 2, 0), Error::<Test>::MinBalanceZero);
+		assert_noop!(FungibleAssets::create(Origin::signed(1), GENESIS_ASSET, 2, 1), Error::<Test>::InUse);
+		assert_noop!(FungibleAssets::create(Origin::signed(3), 0, 2, 1), pallet_balances::Error::<Test, pallet_balances::DefaultInstance>::InsufficientBalance);
+
+		assert_ok!(FungibleAssets::create(Origin::signed(1), 0, 2, 1));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::Created(0, 1, 2)));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		let details = FungibleAssets::asset(0).unwrap();
+		assert_eq!((details.owner, details.issuer, details.admin, details.freezer), (1, 2, 2, 2));
+
+		assert_ok!(FungibleAssets::mint(Origin::signed(2), 0, 3, 5));
+		assert_noop!(FungibleAssets::destroy(Origin::signed(2), 0, 0), Error::<Test>::NoPermission);
+		assert_noop!(FungibleAssets::destroy(Origin::signed(1), 0, 0), Error::<Test>::InCirculation);
+
+		assert_ok!(FungibleAssets::burn(Origin::signed(2), 0, 3, 5));
+		assert_ok!(FungibleAssets::destroy(Origin::signed(1), 0, 0));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::Destroyed(0)));
+		assert_eq!(FungibleAssets::asset(0), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn force_create() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(FungibleAssets::force_create(Origin::signed(1), 0, 3, 1), BadOrigin);
+
+		assert_ok!(FungibleAssets::force_create(Origin::root(), 0, 3, 1));
+		let details = FungibleAssets::asset(0).unwrap();
+		assert_eq!((details.owner, details.issuer, details.admin, details.freezer), (3, 3, 3, 3));
+		assert_eq!(details.deposit, 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+#[test]
+fn mint_and_burn() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_asset();
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::Issued(0, 1, 100)));
+
+		assert_noop!(FungibleAssets::mint(Origin::signed(2), 0, 2, 10), Error::<Test>::NoPermission);
+		assert_noop!(FungibleAssets::mint(Origin::signed(1), 0, 2, 9), Error::<Test>::BelowMinimum);
+		assert_noop!(FungibleAssets::mint(Origin::signed(1), 1, 2, 10), Error::<Test>::Unknown);
+		assert_noop!(FungibleAssets::mint(Origin::signed(1), 0, 2, 0), Error::<Test>::AmountZero);
+
+		assert_ok!(FungibleAssets::mint(Origin::signed(1), 0, 2, 20));
+		assert_eq!(FungibleAssets::total_supply(0), Some(120));
+		assert_eq!(FungibleAssets::asset(0).unwrap().accounts, 2);
+
+		assert_noop!(FungibleAssets::burn(Origin::signed(2), 0, 2, 5), Error::<Test>::NoPermission);
+		assert_ok!(FungibleAssets::burn(Origin::signed(1), 0, 2, 5));
+		assert_eq!(FungibleAssets::balance(0, 2), 15);
+
+		// leaving less than the min balance burns everything.
+		assert_ok!(FungibleAssets::burn(Origin::signed(1), 0, 2, 6));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::Burned(0, 2, 15)));
+		assert_eq!(FungibleAssets::balance(0, 2), 0);
+		assert_eq!(FungibleAssets::total_supply(0), Some(100));
+		assert_eq!(FungibleAssets::asset(0).unwrap().accounts, 1);
+		assert_noop!(FungibleAssets::burn(Origin::signed(1), 0, 2, 1), Error::<Test>::BalanceLow);
+	});
+}
+
+#[test]
+fn transfer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_asset();
+
+		assert_noop!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 101), Error::<Test>::BalanceLow);
+		assert_noop!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 9), Error::<Test>::BelowMinimum);
+
+		assert_ok!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::Transferred(0, 1, 2, 50)));
+		assert_eq!(FungibleAssets::balance(0, 1), 50);
+		assert_eq!(FungibleAssets::balance(0, 2), 50);
+		assert_eq!(FungibleAssets::asset(0).unwrap().accounts, 2);
+
+		// the dust below the min balance goes along.
+		assert_ok!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 45));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::Transferred(0, 1, 2, 50)));
+		assert_eq!(FungibleAssets::balance(0, 1), 0);
+		assert_eq!(FungibleAssets::balance(0, 2), 100);
+		assert_eq!(FungibleAssets::asset(0).unwrap().accounts, 1);
+		assert_eq!(FungibleAssets::total_supply(0), Some(100));
+	});
+}
+
+#[test]
+fn freezing() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_asset();
+		assert_ok!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 50));
+
+		assert_noop!(FungibleAssets::freeze(Origin::signed(2), 0, 1), Error::<Test>::NoPermission);
+		assert_noop!(FungibleAssets::freeze(Origin::signed(1), 0, 3), Error::<Test>::NoAccount);
+
+		assert_ok!(FungibleAssets::freeze(Origin::signed(1), 0, 2));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::Frozen(0, 2)));
+		assert_eq!(FungibleAssets::account(0, 2), AssetBalance { balance: 50, is_frozen: true });
+		assert_noop!(FungibleAssets::transfer(Origin::signed(2), 0, 1, 10), Error::<Test>::Frozen);
+		// frozen accounts still receive.
+		assert_ok!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 10));
+
+		assert_ok!(FungibleAssets::thaw(Origin::signed(1), 0, 2));
+		assert_ok!(FungibleAssets::transfer(Origin::signed(2), 0, 1, 10));
+
+		assert_ok!(FungibleAssets::freeze_asset(Origin::signed(1), 0));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::AssetFrozen(0)));
+		assert_noop!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::Frozen);
+		assert_ok!(FungibleAssets::thaw_asset(Origin::signed(1), 0));
+		assert_ok!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 10));
+	});
+}
+
+#[test]
+fn set_metadata() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_asset();
+
+		assert_noop!(
+			FungibleAssets::set_metadata(Origin::signed(2), 0, b"Token".to_vec(), b"TKN".to_vec(), 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			FungibleAssets::set_metadata(Origin::signed(1), 0, b"Long Token".to_vec(), b"TKN".to_vec(), 10),
+			Error::<Test>::BadMetadata
+		);
+
+		assert_ok!(FungibleAssets::set_metadata(Origin::signed(1), 0, b"Token".to_vec(), b"TKN".to_vec(), 10));
+		assert_eq!(FungibleAssets::metadata(0), AssetMetadata {
+			name: b"Token".to_vec(),
+			symbol: b"TKN".to_vec(),
+			decimals: 10,
+		});
+		assert_eq!(
+			last_event(),
+			TestEvent::fungible_assets(RawEvent::MetadataSet(0, b"Token".to_vec(), b"TKN".to_vec(), 10))
+		);
+	});
+}
+
+#[test]
+fn approvals() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_asset();
+
+		assert_noop!(FungibleAssets::approve_transfer(Origin::signed(2), 0, 1, 50), Error::<Test>::NoAccount);
+
+		assert_ok!(FungibleAssets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::ApprovedTransfer(0, 1, 2, 50)));
+		assert_eq!(Balances::reserved_balance(1), 10 + 1);
+		assert_eq!(FungibleAssets::asset(0).unwrap().approvals, 1);
+
+		assert_noop!(
+			FungibleAssets::transfer_approved(Origin::signed(2), 0, 1, 3, 51),
+			Error::<Test>::Unapproved
+		);
+		assert_noop!(
+			FungibleAssets::transfer_approved(Origin::signed(3), 0, 1, 3, 10),
+			Error::<Test>::Unapproved
+		);
+
+		assert_ok!(FungibleAssets::transfer_approved(Origin::signed(2), 0, 1, 3, 30));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::TransferredApproved(0, 1, 2, 3, 30)));
+		assert_eq!(FungibleAssets::balance(0, 3), 30);
+		assert_eq!(FungibleAssets::approvals(0, (1, 2)).unwrap().amount, 20);
+
+		assert_ok!(FungibleAssets::cancel_approval(Origin::signed(1), 0, 2));
+		assert_eq!(last_event(), TestEvent::fungible_assets(RawEvent::ApprovalCancelled(0, 1, 2)));
+		assert_eq!(FungibleAssets::approvals(0, (1, 2)), None);
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(FungibleAssets::asset(0).unwrap().approvals, 0);
+		assert_noop!(FungibleAssets::cancel_approval(Origin::signed(1), 0, 2), Error::<Test>::Unapproved);
+		assert_noop!(
+			FungibleAssets::transfer_approved(Origin::signed(2), 0, 1, 3, 10),
+			Error::<Test>::Unapproved
+		);
+	});
+}
+
+#[test]
+fn spent_approvals_return_their_deposit() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(FungibleAssets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		// replacing an approval keeps its deposit.
+		assert_ok!(FungibleAssets::approve_transfer(Origin::signed(1), 0, 2, 30));
+		assert_eq!(Balances::reserved_balance(1), 10 + 1);
+
+		assert_ok!(FungibleAssets::transfer_approved(Origin::signed(2), 0, 1, 3, 30));
+		assert_eq!(FungibleAssets::approvals(0, (1, 2)), None);
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(FungibleAssets::asset(0).unwrap().approvals, 0);
+	});
+}
+
+#[test]
+fn destroy_removes_approvals() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(FungibleAssets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(FungibleAssets::approve_transfer(Origin::signed(1), 0, 3, 10));
+		assert_ok!(FungibleAssets::approve_transfer(Origin::signed(2), 0, 3, 10));
+		assert_ok!(FungibleAssets::burn(Origin::signed(1), 0, 1, 50));
+		assert_ok!(FungibleAssets::burn(Origin::signed(1), 0, 2, 50));
+
+		assert_noop!(FungibleAssets::destroy(Origin::signed(1), 0, 1), Error::<Test>::BadWitness);
+		assert_ok!(FungibleAssets::destroy(Origin::signed(1), 0, 2));
+		assert_eq!(FungibleAssets::approvals(0, (1, 3)), None);
+		assert_eq!(FungibleAssets::approvals(0, (2, 3)), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
@@ -0,0 +1,18 @@
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+    fn create() -> Weight;
+    fn force_create() -> Weight;
+    fn destroy(a: u32) -> Weight;
+    fn mint() -> Weight;
+    fn burn() -> Weight;
+    fn transfer() -> Weight;
+    fn freeze() -> Weight;
+    fn thaw() -> Weight;
+    fn freeze_asset() -> Weight;
+    fn thaw_asset() -> Weight;
+    fn set_metadata(n: u32, s: u32) -> Weight;
+    fn approve_transfer() -> Weight;
+    fn cancel_approval() -> Weight;
+    fn transfer_approved() -> Weight;
+}
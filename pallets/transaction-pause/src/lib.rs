@@ -0,0 +1,131 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Transaction Pause
+//!
+//! Lets `UpdateOrigin` pause and unpause single calls, named by pallet and function as in the
+//! call metadata, without a runtime upgrade.
+//!
+//! The pallet only stores the paused calls: the runtime rejects them by consulting
+//! [`PausedTransactionFilter`] in its `BaseFilter`. Calls of `UnpausablePallets` can never be
+//! paused, so the governance able to unpause stays reachable.
+
+use frame_support::{
+	decl_error, decl_event, decl_module, decl_storage, dispatch, ensure,
+	traits::{EnsureOrigin, Filter, Get, GetCallMetadata},
+};
+use frame_system::{self as system};
+use sp_std::{marker::PhantomData, prelude::*};
+
+mod weight_info;
+mod default_weights;
+
+pub use crate::weight_info::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub trait Trait: system::Trait {
+	type Event: From<Event> + Into<<Self as system::Trait>::Event>;
+
+	/// Origin allowed to pause and unpause calls.
+	type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Names of the pallets whose calls cannot be paused.
+	type UnpausablePallets: Get<Vec<&'static str>>;
+
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as TransactionPause {
+		/// The paused calls, keyed by `(pallet_name, function_name)`.
+		PausedTransactions get(fn paused_transactions):
+			map hasher(twox_64_concat) (Vec<u8>, Vec<u8>) => Option<()>;
+	}
+}
+
+decl_event!(
+	pub enum Event {
+		/// A call was paused. [pallet_name, function_name]
+		TransactionPaused(Vec<u8>, Vec<u8>),
+		/// A call was unpaused. [pallet_name, function_name]
+		TransactionUnpaused(Vec<u8>, Vec<u8>),
+	}
+);
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// The calls of the pallet cannot be paused.
+		CannotPause,
+		AlreadyPaused,
+		NotPaused,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		fn deposit_event() = default;
+
+		/// Pause the call `function_name` of the pallet `pallet_name`.
+		#[weight = T::WeightInfo::pause_transaction()]
+		pub fn pause_transaction(
+			origin,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> dispatch::DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				!T::UnpausablePallets::get().iter().any(|name| name.as_bytes() == &pallet_name[..]),
+				Error::<T>::CannotPause
+			);
+
+			let key = (pallet_name, function_name);
+			ensure!(!PausedTransactions::contains_key(&key), Error::<T>::AlreadyPaused);
+
+			PausedTransactions::insert(&key, ());
+			let (pallet_name, function_name) = key;
+			Self::deposit_event(Event::TransactionPaused(pallet_name, function_name));
+
+			Ok(())
+		}
+
+		/// Unpause the call `function_name` of the pallet `pallet_name`.
+		#[weight = T::WeightInfo::unpause_transaction()]
+		pub fn unpause_transaction(
+			origin,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> dispatch::DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let key = (pallet_name, function_name);
+			ensure!(PausedTransactions::contains_key(&key), Error::<T>::NotPaused);
+
+			PausedTransactions::remove(&key);
+			let (pallet_name, function_name) = key;
+			Self::deposit_event(Event::TransactionUnpaused(pallet_name, function_name));
+
+			Ok(())
+		}
+	}
+}
+
+/// Rejects the calls paused in this pallet.
+pub struct PausedTransactionFilter<T>(PhantomData<T>);
+
+impl<T: Trait> Filter<<T as system::Trait>::Call> for PausedTransactionFilter<T> where
+	<T as system::Trait>::Call: GetCallMetadata,
+{
+	fn filter(call: &<T as system::Trait>::Call) -> bool {
+		let metadata = call.get_call_metadata();
+		!PausedTransactions::contains_key((
+			metadata.pallet_name.as_bytes(),
+			metadata.function_name.as_bytes(),
+		))
+	}
+}
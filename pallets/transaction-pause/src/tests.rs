@@ -0,0 +1,89 @@
+use crate::{Error, Event, PausedTransactionFilter, mock::*};
+use frame_support::{assert_ok, assert_noop, traits::Filter};
+use sp_runtime::traits::BadOrigin;
+
+const TRANSFER: &Call = &Call::Balances(pallet_balances::Call::transfer(2, 10));
+
+fn last_event() -> TestEvent {
+	System::events()
+		.pop()
+		.expect("Event expected")
+		.event
+}
+
+fn allowed(call: &Call) -> bool {
+	PausedTransactionFilter::<Test>::filter(call)
+}
+
+#[test]
+fn pause_transaction() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let transfer_keep_alive = Call::Balances(pallet_balances::Call::transfer_keep_alive(2, 10));
+		assert!(allowed(TRANSFER));
+
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::signed(1), b"Balances".to_vec(), b"transfer".to_vec()),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::pause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()));
+		assert_eq!(
+			last_event(),
+			TestEvent::transaction_pause(Event::TransactionPaused(b"Balances".to_vec(), b"transfer".to_vec()))
+		);
+		assert_eq!(
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			Some(())
+		);
+		assert!(!allowed(TRANSFER));
+		assert!(allowed(&transfer_keep_alive));
+
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()),
+			Error::<Test>::AlreadyPaused
+		);
+	});
+}
+
+#[test]
+fn unpause_transaction() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TransactionPause::unpause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()),
+			Error::<Test>::NotPaused
+		);
+
+		assert_ok!(TransactionPause::pause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()));
+		assert_noop!(
+			TransactionPause::unpause_transaction(Origin::signed(1), b"Balances".to_vec(), b"transfer".to_vec()),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::unpause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()));
+		assert_eq!(
+			last_event(),
+			TestEvent::transaction_pause(Event::TransactionUnpaused(b"Balances".to_vec(), b"transfer".to_vec()))
+		);
+		assert_eq!(TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())), None);
+		assert!(allowed(TRANSFER));
+	});
+}
+
+#[test]
+fn unpausable_pallets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), b"System".to_vec(), b"remark".to_vec()),
+			Error::<Test>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				Origin::root(), b"TransactionPause".to_vec(), b"unpause_transaction".to_vec()
+			),
+			Error::<Test>::CannotPause
+		);
+		assert!(allowed(&Call::System(frame_system::Call::remark(vec![]))));
+	});
+}
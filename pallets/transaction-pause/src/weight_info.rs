@@ -0,0 +1,6 @@
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+    fn pause_transaction() -> Weight;
+    fn unpause_transaction() -> Weight;
+}
@@ -1,34 +0,0 @@
-// Copyright (C) 2020 Parity Technologies (UK) Ltd.
-// SPDX-License-Identifier: Apache-2.0
-
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-// 	http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-
-//! A list of the different weight modules for our runtime.
-
-pub mod frame_system;
-pub mod pallet_balances;
-pub mod pallet_treasury;
-pub mod pallet_collective;
-pub mod pallet_democracy;
-pub mod pallet_identity;
-pub mod pallet_indices;
-pub mod pallet_im_online;
-pub mod pallet_multisig;
-pub mod pallet_proxy;
-pub mod pallet_scheduler;
-pub mod pallet_session;
-pub mod pallet_staking;
-pub mod pallet_timestamp;
-pub mod pallet_utility;
-pub mod pallet_vesting;
-pub mod pallet_elections_phragmen;
@@ -1,89 +0,0 @@
-// This file is part of Substrate.
-
-// Copyright (C) 2019-2020 Parity Technologies (UK) Ltd.
-// SPDX-License-Identifier: Apache-2.0
-
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-// 	http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc6
-
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-
-use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
-
-pub struct WeightInfo;
-impl pallet_elections_phragmen::WeightInfo for WeightInfo {
-	fn vote(v: u32, ) -> Weight {
-		(91_489_000 as Weight)
-			.saturating_add((199_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(DbWeight::get().reads(5 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn vote_update(v: u32, ) -> Weight {
-		(56_511_000 as Weight)
-			.saturating_add((245_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(DbWeight::get().reads(5 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn remove_voter() -> Weight {
-		(76_714_000 as Weight)
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn report_defunct_voter_correct(c: u32, v: u32, ) -> Weight {
-		(0 as Weight)
-			.saturating_add((1_743_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add((31_750_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(DbWeight::get().reads(7 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
-	}
-	fn report_defunct_voter_incorrect(c: u32, v: u32, ) -> Weight {
-		(0 as Weight)
-			.saturating_add((1_733_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add((31_861_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(DbWeight::get().reads(6 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn submit_candidacy(c: u32, ) -> Weight {
-		(74_714_000 as Weight)
-			.saturating_add((315_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn renounce_candidacy_candidate(c: u32, ) -> Weight {
-		(50_408_000 as Weight)
-			.saturating_add((159_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn renounce_candidacy_members() -> Weight {
-		(79_626_000 as Weight)
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(4 as Weight))
-	}
-	fn renounce_candidacy_runners_up() -> Weight {
-		(49_715_000 as Weight)
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn remove_member_with_replacement() -> Weight {
-		(76_572_000 as Weight)
-			.saturating_add(DbWeight::get().reads(4 as Weight))
-			.saturating_add(DbWeight::get().writes(5 as Weight))
-	}
-	fn remove_member_wrong_refund() -> Weight {
-		(8_777_000 as Weight)
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-	}
-}
@@ -1,85 +0,0 @@
-// Copyright (C) 2020 Parity Technologies (UK) Ltd.
-// SPDX-License-Identifier: Apache-2.0
-
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-// 	http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc5
-
-use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
-
-pub struct WeightInfo;
-impl pallet_proxy::WeightInfo for WeightInfo {
-	fn proxy(p: u32, ) -> Weight {
-		(26127000 as Weight)
-			.saturating_add((214000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-	}
-	fn proxy_announced(a: u32, p: u32, ) -> Weight {
-		(55405000 as Weight)
-			.saturating_add((774000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((209000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn remove_announcement(a: u32, p: u32, ) -> Weight {
-		(35879000 as Weight)
-			.saturating_add((783000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((20000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn reject_announcement(a: u32, p: u32, ) -> Weight {
-		(36097000 as Weight)
-			.saturating_add((780000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((12000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn announce(a: u32, p: u32, ) -> Weight {
-		(53769000 as Weight)
-			.saturating_add((675000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((214000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn add_proxy(p: u32, ) -> Weight {
-		(36082000 as Weight)
-			.saturating_add((234000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn remove_proxy(p: u32, ) -> Weight {
-		(32885000 as Weight)
-			.saturating_add((267000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn remove_proxies(p: u32, ) -> Weight {
-		(31735000 as Weight)
-			.saturating_add((215000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn anonymous(p: u32, ) -> Weight {
-		(50907000 as Weight)
-			.saturating_add((61000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn kill_anonymous(p: u32, ) -> Weight {
-		(33926000 as Weight)
-			.saturating_add((208000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-}
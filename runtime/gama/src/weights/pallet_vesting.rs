@@ -1,63 +0,0 @@
-// This file is part of Substrate.
-
-// Copyright (C) 2020 Parity Technologies (UK) Ltd.
-// SPDX-License-Identifier: Apache-2.0
-
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-// 	http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc6
-
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-
-use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
-
-pub struct WeightInfo;
-impl pallet_vesting::WeightInfo for WeightInfo {
-	fn vest_locked(l: u32, ) -> Weight {
-		(82109000 as Weight)
-			.saturating_add((332000 as Weight).saturating_mul(l as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn vest_unlocked(l: u32, ) -> Weight {
-		(88419000 as Weight)
-			.saturating_add((3000 as Weight).saturating_mul(l as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn vest_other_locked(l: u32, ) -> Weight {
-		(81277000 as Weight)
-			.saturating_add((321000 as Weight).saturating_mul(l as Weight))
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn vest_other_unlocked(l: u32, ) -> Weight {
-		(87584000 as Weight)
-			.saturating_add((19000 as Weight).saturating_mul(l as Weight))
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
-	}
-	fn vested_transfer(l: u32, ) -> Weight {
-		(185916000 as Weight)
-			.saturating_add((625000 as Weight).saturating_mul(l as Weight))
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
-	}
-	fn force_vested_transfer(l: u32, ) -> Weight {
-		(185916000 as Weight)
-			.saturating_add((625000 as Weight).saturating_mul(l as Weight))
-			.saturating_add(DbWeight::get().reads(4 as Weight))
-			.saturating_add(DbWeight::get().writes(4 as Weight))
-	}
-}
@@ -17,10 +17,11 @@
 
 //! Some configurable implementations as associated type for the substrate runtime.
 
-use node_primitives::Balance;
-use sp_runtime::traits::Convert;
+use node_primitives::{Balance, Hash};
+use sp_runtime::traits::{Convert, Zero};
 use frame_support::traits::{OnUnbalanced, Currency};
-use crate::{Balances, Authorship, NegativeImbalance};
+use pallet_asset_tx_payment::AssetRate;
+use crate::{Balances, Authorship, Dex, NegativeImbalance};
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -45,6 +46,19 @@ impl Convert<u128, Balance> for CurrencyToVoteHandler {
 	fn convert(x: u128) -> Balance { x * Self::factor() }
 }
 
+/// Prices transaction fees paid in asset tokens at the dex pool of the asset: the fee costs as
+/// many tokens as buying it from the pool would.
+pub struct DexPoolRate;
+
+impl AssetRate<Hash, Balance, u128> for DexPoolRate {
+	fn native_to_asset(asset_id: &Hash, amount: Balance) -> Option<u128> {
+		if amount.is_zero() {
+			return Some(Zero::zero());
+		}
+		Dex::quote_asset_for_exact_native(asset_id, amount)
+	}
+}
+
 #[cfg(test)]
 mod multiplier_tests {
 	use super::*;
@@ -30,7 +30,7 @@ use frame_support::{
 		Weight,
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 	},
-	traits::{Currency, Imbalance, KeyOwnerProofSystem, OnUnbalanced, Randomness, LockIdentifier,Filter},
+	traits::{Currency, KeyOwnerProofSystem, Randomness, LockIdentifier,Filter},
 };
 use frame_system::{EnsureRoot, EnsureOneOf, EnsureSigned};
 use frame_support::traits::InstanceFilter;
@@ -41,7 +41,7 @@ use sp_core::{
 	OpaqueMetadata,
 };
 pub use node_primitives::{AccountId, Signature};
-use node_primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment};
+use node_primitives::{AccountIndex, AssetId, Balance, BlockNumber, Hash, Index, Moment};
 use sp_api::impl_runtime_apis;
 use sp_runtime::{
 	Permill, Perbill, Perquintill, Percent, ApplyExtrinsicResult,
@@ -78,10 +78,20 @@ pub use pallet_staking::StakerStatus;
 
 /// Implementations of some helper traits passed into runtime modules as associated types.
 pub mod impls;
-use impls::{CurrencyToVoteHandler, Author};
+use impls::{CurrencyToVoteHandler, Author, DexPoolRate};
 
 /// Constant values used within the runtime.
 pub mod constants;
+
+/// The network the runtime is built for.
+pub mod network;
+
+/// Storage migrations of runtime upgrades.
+pub mod migrations;
+
+/// Placeholder keeping the call and event indices of the removed sudo pallet.
+#[cfg(not(feature = "sudo"))]
+pub mod retired_sudo;
 use constants::{time::*, currency::*, fee::*};
 use sp_runtime::generic::Era;
 
@@ -102,17 +112,18 @@ pub fn wasm_binary_unwrap() -> &'static [u8] {
 
 /// Runtime version.
 pub const VERSION: RuntimeVersion = RuntimeVersion {
-	spec_name: create_runtime_str!("colombo"),
-	impl_name: create_runtime_str!("aochain-colombo"),
+	spec_name: create_runtime_str!(network::SPEC_NAME),
+	impl_name: create_runtime_str!(network::IMPL_NAME),
 	authoring_version: 10,
 	// Per convention: if the runtime behavior changes, increment spec_version
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 3,
+	spec_version: 4,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Increment when the encoding of transactions changes, such as the signed extensions.
+	transaction_version: 2,
 };
 
 /// Native version.
@@ -128,41 +139,33 @@ pub struct BaseFilter;
 impl Filter<Call> for BaseFilter {
 	fn filter(call: &Call) -> bool {
 		match call {
-			// These modules are all allowed to be called by transactions:
+			// These modules are all allowed to be called by transactions, unless paused:
 			Call::Democracy(_) | Call::Council(_) | Call::TechnicalCommittee(_) |
 			Call::TechnicalMembership(_) | Call::Treasury(_) | Call::Elections(_) |
 			Call::System(_) | Call::Scheduler(_) | Call::Indices(_) |
-			Call::Babe(_) | Call::Timestamp(_) | Call::Sudo(_) |
+			Call::Babe(_) | Call::Timestamp(_) |
 			Call::Authorship(_) | Call::Staking(_) | Call::Offences(_) |
 			Call::Session(_) | Call::Grandpa(_) | Call::ImOnline(_) |
 			Call::AuthorityDiscovery(_) | Call::Balances(_) |
 			Call::Utility(_)  | Call::Vesting(_) | Call::FinalityTracker(_) |
 			Call::Identity(_) | Call::Proxy(_) | Call::Multisig(_) |
 			Call::Organization(_) | Call::OrganizationAssetNFT(_) | Call::AssetNFT(_) |
-			Call::Contracts(_) |
-			Call::RandomnessCollectiveFlip(_) | Call::Society(_) | Call::Recovery(_)
-			=> true
+			Call::AssetTxPayment(_) | Call::Dex(_) | Call::FungibleAssets(_) | Call::Contracts(_) |
+			Call::RandomnessCollectiveFlip(_) | Call::Society(_) | Call::Recovery(_) |
+			Call::TransactionPause(_) | Call::FeeSplit(_)
+			=> pallet_transaction_pause::PausedTransactionFilter::<Runtime>::filter(call),
+			#[cfg(feature = "sudo")]
+			Call::Sudo(_) => pallet_transaction_pause::PausedTransactionFilter::<Runtime>::filter(call),
+			#[cfg(not(feature = "sudo"))]
+			Call::RetiredSudo(_) => false,
 		}
 	}
 }
 
 type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 
-pub struct DealWithFees;
-impl OnUnbalanced<NegativeImbalance> for DealWithFees {
-	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item=NegativeImbalance>) {
-		if let Some(fees) = fees_then_tips.next() {
-			// for fees, 80% to treasury, 20% to author
-			let mut split = fees.ration(80, 20);
-			if let Some(tips) = fees_then_tips.next() {
-				// for tips, if any, 80% to treasury, 20% to author (though this can be anything)
-				tips.ration_merge_into(80, 20, &mut split);
-			}
-			Treasury::on_unbalanced(split.0);
-			Author::on_unbalanced(split.1);
-		}
-	}
-}
+/// Splits fees and tips between treasury, author and burn by the ratios set by governance.
+pub type DealWithFees = pallet_fee_split::DealWithFees<Runtime>;
 
 const AVERAGE_ON_INITIALIZE_WEIGHT: Perbill = Perbill::from_percent(10);
 parameter_types! {
@@ -250,17 +253,72 @@ pub enum ProxyType {
 	NonTransfer,
 	Governance,
 	Staking,
+	/// Minting, burning and transferring assets and tokens of either assets-nft instance.
+	AssetManagement,
+	/// Instantiating and calling contracts.
+	Contracts,
+	/// Providing judgements as an identity registrar.
+	IdentityJudgement,
+	/// Rejecting announcements made by a proxy of the account.
+	CancelProxy,
 }
 impl Default for ProxyType { fn default() -> Self { Self::Any } }
 impl InstanceFilter<Call> for ProxyType {
 	fn filter(&self, c: &Call) -> bool {
 		match self {
 			ProxyType::Any => true,
-			ProxyType::NonTransfer => !matches!(
+			// A whitelist, so that calls added later cannot move funds until they are reviewed.
+			ProxyType::NonTransfer => matches!(
 				c,
-				Call::Balances(..) |
-				Call::Vesting(pallet_vesting::Call::vested_transfer(..)) |
-				Call::Indices(pallet_indices::Call::transfer(..))
+				Call::System(..) |
+				Call::Utility(..) |
+				Call::Babe(..) |
+				Call::Timestamp(..) |
+				Call::Indices(pallet_indices::Call::claim(..)) |
+				Call::Indices(pallet_indices::Call::free(..)) |
+				Call::Indices(pallet_indices::Call::freeze(..)) |
+				Call::Staking(..) |
+				Call::Session(..) |
+				Call::Democracy(..) |
+				Call::Council(..) |
+				Call::TechnicalCommittee(..) |
+				Call::Elections(..) |
+				Call::TechnicalMembership(..) |
+				Call::FinalityTracker(..) |
+				Call::Grandpa(..) |
+				Call::Treasury(..) |
+				Call::Contracts(pallet_contracts::Call::put_code(..)) |
+				Call::Contracts(pallet_contracts::Call::claim_surcharge(..)) |
+				Call::ImOnline(..) |
+				Call::Identity(..) |
+				Call::Society(..) |
+				Call::Recovery(pallet_recovery::Call::create_recovery(..)) |
+				Call::Recovery(pallet_recovery::Call::initiate_recovery(..)) |
+				Call::Recovery(pallet_recovery::Call::vouch_recovery(..)) |
+				Call::Recovery(pallet_recovery::Call::claim_recovery(..)) |
+				Call::Recovery(pallet_recovery::Call::close_recovery(..)) |
+				Call::Recovery(pallet_recovery::Call::remove_recovery(..)) |
+				Call::Recovery(pallet_recovery::Call::cancel_recovered(..)) |
+				Call::Vesting(pallet_vesting::Call::vest(..)) |
+				Call::Vesting(pallet_vesting::Call::vest_other(..)) |
+				Call::Scheduler(..) |
+				Call::Proxy(..) |
+				Call::Multisig(..) |
+				Call::Organization(..) |
+				Call::OrganizationAssetNFT(pallet_assets_nft::Call::mint(..)) |
+				Call::OrganizationAssetNFT(pallet_assets_nft::Call::burn(..)) |
+				Call::AssetNFT(pallet_assets_nft::Call::mint(..)) |
+				Call::AssetNFT(pallet_assets_nft::Call::burn(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::create(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::destroy(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::mint(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::burn(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::freeze(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::thaw(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::freeze_asset(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::thaw_asset(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::set_metadata(..)) |
+				Call::FungibleAssets(pallet_fungible_assets::Call::cancel_approval(..))
 			),
 			ProxyType::Governance => matches!(
 				c,
@@ -272,6 +330,23 @@ impl InstanceFilter<Call> for ProxyType {
 				Call::Treasury(..)
 			),
 			ProxyType::Staking => matches!(c, Call::Staking(..)),
+			ProxyType::AssetManagement => matches!(
+				c,
+				Call::OrganizationAssetNFT(..) |
+				Call::AssetNFT(..) |
+				Call::FungibleAssets(..) |
+				Call::Utility(..)
+			),
+			ProxyType::Contracts => matches!(c, Call::Contracts(..)),
+			ProxyType::IdentityJudgement => matches!(
+				c,
+				Call::Identity(pallet_identity::Call::provide_judgement(..)) |
+				Call::Utility(..)
+			),
+			ProxyType::CancelProxy => matches!(
+				c,
+				Call::Proxy(pallet_proxy::Call::reject_announcement(..))
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -279,6 +354,9 @@ impl InstanceFilter<Call> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			// asset management includes asset and token transfers, contracts take a value.
+			(ProxyType::NonTransfer, ProxyType::AssetManagement) => false,
+			(ProxyType::NonTransfer, ProxyType::Contracts) => false,
 			(ProxyType::NonTransfer, _) => true,
 			_ => false,
 		}
@@ -705,11 +783,19 @@ impl pallet_contracts::Trait for Runtime {
 	type WeightPrice = pallet_transaction_payment::Module<Self>;
 }
 
+#[cfg(feature = "sudo")]
 impl pallet_sudo::Trait for Runtime {
 	type Event = Event;
 	type Call = Call;
 }
 
+#[cfg(not(feature = "sudo"))]
+impl retired_sudo::Trait for Runtime {
+	type Event = Event;
+}
+
+impl migrations::Trait for Runtime {}
+
 parameter_types! {
 	pub const SessionDuration: BlockNumber = EPOCH_DURATION_IN_SLOTS as _;
 	pub const ImOnlineUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
@@ -746,7 +832,7 @@ impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for R
 			frame_system::CheckEra::<Runtime>::from(era),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(tip, None),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -976,6 +1062,92 @@ impl pallet_assets_nft::Trait<pallet_assets_nft::Instance2> for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const AssetTxPaymentModuleId: ModuleId = ModuleId(*b"py/asfee");
+}
+
+impl pallet_asset_tx_payment::Trait for Runtime {
+	type Event = Event;
+	type AssetId = pallet_assets_nft::AssetId<Runtime>;
+	type AssetBalance = <Runtime as pallet_assets_nft::Trait<pallet_assets_nft::Instance2>>::Balance;
+	type Assets = AssetNFT;
+	type RateOrigin = EnsureRootOrHalfCouncil;
+	type PoolRate = DexPoolRate;
+	type ModuleId = AssetTxPaymentModuleId;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const SwapFee: Permill = Permill::from_perthousand(3);
+	pub const DexModuleId: ModuleId = ModuleId(*b"py/dex!!");
+}
+
+impl pallet_dex::Trait for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type AssetId = pallet_assets_nft::AssetId<Runtime>;
+	type AssetBalance = <Runtime as pallet_assets_nft::Trait<pallet_assets_nft::Instance2>>::Balance;
+	type Assets = AssetNFT;
+	type SwapFee = SwapFee;
+	type ModuleId = DexModuleId;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * DOLLARS;
+	pub const ApprovalDeposit: Balance = 1 * DOLLARS;
+	pub const AssetStringLimit: u32 = 50;
+}
+
+impl pallet_fungible_assets::Trait for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = AssetId;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRootOrHalfCouncil;
+	type AssetDeposit = AssetDeposit;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetStringLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub UnpausablePallets: Vec<&'static str> = {
+		let mut pallets = vec![
+			"System", "Democracy", "Council", "TechnicalCommittee", "TechnicalMembership",
+			"Elections", "TransactionPause",
+		];
+		if cfg!(feature = "sudo") {
+			pallets.push("Sudo");
+		}
+		pallets
+	};
+}
+
+impl pallet_fee_split::Trait for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type UpdateOrigin = EnsureRootOrHalfCouncil;
+	type TreasuryShare = Treasury;
+	type AuthorShare = Author;
+	type WeightInfo = ();
+}
+
+impl pallet_transaction_pause::Trait for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureOneOf<
+		AccountId,
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>
+	>;
+	type UnpausablePallets = UnpausablePallets;
+	type WeightInfo = ();
+}
+
+/// Declares the runtime with `$sudo` as the pallet at the place of the sudo pallet, which keeps
+/// the call and event indices of the pallets after it.
+macro_rules! runtime_with {
+	($($sudo:tt)*) => {
 construct_runtime!(
 	pub enum Runtime where
 		Block = Block,
@@ -1001,7 +1173,7 @@ construct_runtime!(
 		Grandpa: pallet_grandpa::{Module, Call, Storage, Config, Event, ValidateUnsigned},
 		Treasury: pallet_treasury::{Module, Call, Storage, Config, Event<T>},
 		Contracts: pallet_contracts::{Module, Call, Config, Storage, Event<T>},
-		Sudo: pallet_sudo::{Module, Call, Config<T>, Storage, Event<T>},
+		$($sudo)*
 		ImOnline: pallet_im_online::{Module, Call, Storage, Event<T>, ValidateUnsigned, Config<T>},
 		AuthorityDiscovery: pallet_authority_discovery::{Module, Call, Config},
 		Offences: pallet_offences::{Module, Call, Storage, Event},
@@ -1017,8 +1189,22 @@ construct_runtime!(
 		Organization: pallet_collective::<Instance3>::{Module, Call, Storage, Origin<T>, Event<T>, Config<T>},
 		OrganizationAssetNFT: pallet_assets_nft::<Instance1>::{Module, Call, Storage, Event<T>},
 		AssetNFT: pallet_assets_nft::<Instance2>::{Module, Call, Storage, Event<T>},
+		AssetTxPayment: pallet_asset_tx_payment::{Module, Call, Storage, Config<T>, Event<T>},
+		Dex: pallet_dex::{Module, Call, Storage, Event<T>},
+		FungibleAssets: pallet_fungible_assets::{Module, Call, Storage, Event<T>, Config<T>},
+		TransactionPause: pallet_transaction_pause::{Module, Call, Storage, Event},
+		FeeSplit: pallet_fee_split::{Module, Call, Storage, Event},
+		Migrations: migrations::{Module, Storage, Config},
 	}
 );
+	}
+}
+
+// Test networks built with the `sudo` feature keep a sudo key, live networks govern root.
+#[cfg(feature = "sudo")]
+runtime_with!(Sudo: pallet_sudo::{Module, Call, Config<T>, Storage, Event<T>},);
+#[cfg(not(feature = "sudo"))]
+runtime_with!(RetiredSudo: retired_sudo::{Module, Call, Event},);
 
 /// The address format for describing accounts.
 pub type Address = <Indices as StaticLookup>::Source;
@@ -1042,7 +1228,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -1051,7 +1237,14 @@ pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
-pub type Executive = frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllModules>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllModules,
+	migrations::RunMigrations,
+>;
 
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
@@ -1245,6 +1438,40 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_dex_runtime_api::DexApi<Block, Hash, Balance, u128> for Runtime {
+		fn pool(asset_id: Hash) -> Option<pallet_dex::PoolInfo<Balance, u128>> {
+			Dex::pool(asset_id)
+		}
+
+		fn quote_exact_native_for_asset(asset_id: Hash, native_in: Balance) -> Option<u128> {
+			Dex::quote_exact_native_for_asset(&asset_id, native_in)
+		}
+
+		fn quote_native_for_exact_asset(asset_id: Hash, asset_out: u128) -> Option<Balance> {
+			Dex::quote_native_for_exact_asset(&asset_id, asset_out)
+		}
+
+		fn quote_exact_asset_for_native(asset_id: Hash, asset_in: u128) -> Option<Balance> {
+			Dex::quote_exact_asset_for_native(&asset_id, asset_in)
+		}
+
+		fn quote_asset_for_exact_native(asset_id: Hash, native_out: Balance) -> Option<u128> {
+			Dex::quote_asset_for_exact_native(&asset_id, native_out)
+		}
+	}
+
+	impl pallet_fungible_assets_runtime_api::FungibleAssetsApi<Block, AssetId, AccountId, Balance>
+		for Runtime
+	{
+		fn balance(asset_id: AssetId, who: AccountId) -> Balance {
+			FungibleAssets::balance(asset_id, who)
+		}
+
+		fn total_supply(asset_id: AssetId) -> Option<Balance> {
+			FungibleAssets::total_supply(asset_id)
+		}
+	}
+
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -1333,4 +1560,436 @@ mod tests {
 
 		is_submit_signed_transaction::<Runtime>();
 	}
+
+	const ALL_PROXY_TYPES: [ProxyType; 8] = [
+		ProxyType::Any,
+		ProxyType::NonTransfer,
+		ProxyType::Governance,
+		ProxyType::Staking,
+		ProxyType::AssetManagement,
+		ProxyType::Contracts,
+		ProxyType::IdentityJudgement,
+		ProxyType::CancelProxy,
+	];
+
+	// Pallets whose calls are inherents or signed unsigned reports and cannot be built
+	// without consensus data. Proxies are never expected to dispatch them.
+	const UNSAMPLED_PALLETS: [&str; 2] = ["Babe", "ImOnline"];
+
+	fn account(seed: u8) -> AccountId {
+		AccountId::from([seed; 32])
+	}
+
+	fn address(seed: u8) -> Address {
+		Indices::unlookup(account(seed))
+	}
+
+	fn remark() -> Call {
+		Call::System(frame_system::Call::remark(vec![]))
+	}
+
+	fn sample_calls() -> Vec<Call> {
+		let mut calls = vec![
+			remark(),
+			Call::Utility(pallet_utility::Call::batch(vec![remark()])),
+			Call::Timestamp(pallet_timestamp::Call::set(0)),
+			Call::Authorship(pallet_authorship::Call::set_uncles(vec![])),
+			Call::Indices(pallet_indices::Call::claim(0)),
+			Call::Indices(pallet_indices::Call::transfer(account(2), 0)),
+			Call::Balances(BalancesCall::transfer(address(2), 1)),
+			Call::Staking(pallet_staking::Call::chill()),
+			Call::Session(pallet_session::Call::purge_keys()),
+			Call::Democracy(pallet_democracy::Call::remove_vote(0)),
+			Call::Council(pallet_collective::Call::vote(Hash::default(), 0, true)),
+			Call::TechnicalCommittee(pallet_collective::Call::vote(Hash::default(), 0, true)),
+			Call::Elections(pallet_elections_phragmen::Call::remove_voter()),
+			Call::TechnicalMembership(pallet_membership::Call::add_member(account(2))),
+			Call::FinalityTracker(pallet_finality_tracker::Call::final_hint(0)),
+			Call::Grandpa(pallet_grandpa::Call::note_stalled(0, 0)),
+			Call::Treasury(pallet_treasury::Call::propose_spend(1, address(2))),
+			Call::Contracts(pallet_contracts::Call::call(address(2), 0, 0, vec![])),
+			Call::Identity(pallet_identity::Call::clear_identity()),
+			Call::Identity(pallet_identity::Call::provide_judgement(
+				0, address(2), pallet_identity::Judgement::Reasonable,
+			)),
+			Call::Society(pallet_society::Call::bid(1)),
+			Call::Recovery(pallet_recovery::Call::remove_recovery()),
+			Call::Vesting(pallet_vesting::Call::vest()),
+			Call::Vesting(pallet_vesting::Call::vested_transfer(
+				address(2),
+				pallet_vesting::VestingInfo { locked: 1, per_block: 1, starting_block: 0 },
+			)),
+			Call::Scheduler(pallet_scheduler::Call::cancel(0, 0)),
+			Call::Proxy(pallet_proxy::Call::add_proxy(account(2), ProxyType::Any, 0)),
+			Call::Proxy(pallet_proxy::Call::reject_announcement(account(2), Hash::default())),
+			Call::Multisig(pallet_multisig::Call::as_multi_threshold_1(vec![], Box::new(remark()))),
+			Call::Organization(pallet_collective::Call::vote(Hash::default(), 0, true)),
+			Call::OrganizationAssetNFT(pallet_assets_nft::Call::mint(account(2), vec![], 1)),
+			Call::OrganizationAssetNFT(pallet_assets_nft::Call::transfer_asset(address(2), Hash::default())),
+			Call::OrganizationAssetNFT(pallet_assets_nft::Call::transfer_token(address(2), Hash::default(), 1)),
+			Call::AssetNFT(pallet_assets_nft::Call::mint(account(2), vec![], 1)),
+			Call::AssetNFT(pallet_assets_nft::Call::burn(Hash::default())),
+			Call::AssetNFT(pallet_assets_nft::Call::transfer_asset(address(2), Hash::default())),
+			Call::AssetNFT(pallet_assets_nft::Call::transfer_token(address(2), Hash::default(), 1)),
+			Call::AssetTxPayment(pallet_asset_tx_payment::Call::set_rate(Hash::default(), None)),
+			Call::Dex(pallet_dex::Call::swap_exact_native_for_asset(Hash::default(), 1, 0)),
+			Call::FungibleAssets(pallet_fungible_assets::Call::mint(0, address(2), 1)),
+			Call::TransactionPause(pallet_transaction_pause::Call::unpause_transaction(vec![], vec![])),
+			Call::FeeSplit(pallet_fee_split::Call::set_fee_ratio(Default::default())),
+		];
+		if cfg!(feature = "sudo") {
+			calls.push(sudo_call(remark()));
+		}
+		calls
+	}
+
+	#[cfg(feature = "sudo")]
+	fn sudo_call(call: Call) -> Call {
+		Call::Sudo(pallet_sudo::Call::sudo(Box::new(call)))
+	}
+
+	#[cfg(not(feature = "sudo"))]
+	fn sudo_call(_: Call) -> Call {
+		unreachable!("the runtime has no sudo pallet")
+	}
+
+	#[test]
+	fn sample_calls_cover_every_pallet() {
+		use frame_support::traits::GetCallMetadata;
+
+		let sampled = sample_calls()
+			.iter()
+			.map(|c| c.get_call_metadata().pallet_name)
+			.collect::<Vec<_>>();
+
+		for pallet in Call::get_module_names() {
+			if Call::get_call_names(pallet).is_empty() || UNSAMPLED_PALLETS.contains(pallet) {
+				continue;
+			}
+			assert!(sampled.contains(pallet), "no sample call for pallet {}", pallet);
+		}
+	}
+
+	#[test]
+	fn non_transfer_proxy_forbids_transfers() {
+		let transfers = [
+			Call::Balances(BalancesCall::transfer(address(2), 1)),
+			Call::Indices(pallet_indices::Call::transfer(account(2), 0)),
+			Call::OrganizationAssetNFT(pallet_assets_nft::Call::transfer_asset(address(2), Hash::default())),
+			Call::OrganizationAssetNFT(pallet_assets_nft::Call::transfer_token(address(2), Hash::default(), 1)),
+			Call::AssetNFT(pallet_assets_nft::Call::transfer_asset(address(2), Hash::default())),
+			Call::AssetNFT(pallet_assets_nft::Call::transfer_token(address(2), Hash::default(), 1)),
+			Call::Dex(pallet_dex::Call::swap_exact_asset_for_native(Hash::default(), 1, 0)),
+			Call::FungibleAssets(pallet_fungible_assets::Call::transfer(0, address(2), 1)),
+			Call::FungibleAssets(pallet_fungible_assets::Call::approve_transfer(0, address(2), 1)),
+			Call::FungibleAssets(pallet_fungible_assets::Call::transfer_approved(0, address(1), address(2), 1)),
+			Call::Contracts(pallet_contracts::Call::call(address(2), 1, 0, vec![])),
+			Call::Contracts(pallet_contracts::Call::instantiate(1, 0, Hash::default(), vec![])),
+			Call::AssetTxPayment(pallet_asset_tx_payment::Call::sweep(0, address(2))),
+		];
+		for call in transfers.iter() {
+			assert!(!ProxyType::NonTransfer.filter(call), "{:?} passed NonTransfer", call);
+		}
+
+		assert!(ProxyType::NonTransfer.filter(&Call::AssetNFT(pallet_assets_nft::Call::mint(account(2), vec![], 1))));
+		assert!(ProxyType::NonTransfer.filter(&remark()));
+	}
+
+	#[test]
+	fn dedicated_proxies_filter_calls() {
+		let judgement = Call::Identity(pallet_identity::Call::provide_judgement(
+			0, address(2), pallet_identity::Judgement::Reasonable,
+		));
+		let reject = Call::Proxy(pallet_proxy::Call::reject_announcement(account(2), Hash::default()));
+		let contract = Call::Contracts(pallet_contracts::Call::call(address(2), 0, 0, vec![]));
+		let transfer_token = Call::AssetNFT(pallet_assets_nft::Call::transfer_token(address(2), Hash::default(), 1));
+
+		assert!(ProxyType::AssetManagement.filter(&transfer_token));
+		assert!(ProxyType::AssetManagement.filter(&Call::OrganizationAssetNFT(
+			pallet_assets_nft::Call::mint(account(2), vec![], 1)
+		)));
+		assert!(!ProxyType::AssetManagement.filter(&Call::Balances(BalancesCall::transfer(address(2), 1))));
+		assert!(!ProxyType::AssetManagement.filter(&contract));
+
+		assert!(ProxyType::Contracts.filter(&contract));
+		assert!(!ProxyType::Contracts.filter(&transfer_token));
+
+		assert!(ProxyType::IdentityJudgement.filter(&judgement));
+		assert!(!ProxyType::IdentityJudgement.filter(&Call::Identity(pallet_identity::Call::clear_identity())));
+
+		assert!(ProxyType::CancelProxy.filter(&reject));
+		assert!(!ProxyType::CancelProxy.filter(
+			&Call::Proxy(pallet_proxy::Call::add_proxy(account(2), ProxyType::Any, 0))
+		));
+		assert!(!ProxyType::CancelProxy.filter(&judgement));
+	}
+
+	#[test]
+	fn proxy_supersets_are_consistent_with_filters() {
+		let calls = sample_calls();
+
+		for outer in ALL_PROXY_TYPES.iter() {
+			for inner in ALL_PROXY_TYPES.iter() {
+				if !outer.is_superset(inner) {
+					continue;
+				}
+				for call in calls.iter() {
+					assert!(
+						!inner.filter(call) || outer.filter(call),
+						"{:?} is a superset of {:?} but forbids {:?}", outer, inner, call,
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn only_any_is_superset_of_any() {
+		for proxy_type in ALL_PROXY_TYPES.iter() {
+			assert!(ProxyType::Any.is_superset(proxy_type));
+			assert!(proxy_type.is_superset(proxy_type));
+			if *proxy_type != ProxyType::Any {
+				assert!(!proxy_type.is_superset(&ProxyType::Any));
+			}
+		}
+		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::AssetManagement));
+		assert!(!ProxyType::AssetManagement.is_superset(&ProxyType::NonTransfer));
+		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Contracts));
+	}
+
+	#[test]
+	#[cfg(feature = "gama")]
+	fn gama_profile() {
+		assert_eq!(VERSION.spec_name, create_runtime_str!("gama"));
+		assert_eq!(VERSION.impl_name, create_runtime_str!("aochain-gama"));
+		assert_eq!(EPOCH_DURATION_IN_BLOCKS, 10 * MINUTES);
+		assert_eq!(network::SS58_PREFIX, 98);
+		assert_eq!(network::TOKEN_SYMBOL, "COM");
+	}
+
+	#[test]
+	#[cfg(feature = "colombo")]
+	fn colombo_profile() {
+		assert_eq!(VERSION.spec_name, create_runtime_str!("colombo"));
+		assert_eq!(VERSION.impl_name, create_runtime_str!("aochain-colombo"));
+		assert_eq!(EPOCH_DURATION_IN_BLOCKS, 1 * HOURS);
+		assert_eq!(network::SS58_PREFIX, 99);
+		assert_eq!(network::TOKEN_SYMBOL, "TCOM");
+	}
+
+	#[test]
+	#[cfg(not(feature = "sudo"))]
+	fn remove_sudo_clears_the_key() {
+		use frame_support::storage::migration::{get_storage_value, put_storage_value};
+		use migrations::{MigrationList, RemoveSudo};
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			put_storage_value(b"Sudo", b"Key", &[], account(1));
+
+			assert!(RemoveSudo::run_checked().is_ok());
+			assert_eq!(get_storage_value::<AccountId>(b"Sudo", b"Key", &[]), None);
+
+			// Nothing left to do on later upgrades, and no version is kept for the gone pallet.
+			assert_eq!(RemoveSudo::run(), RocksDbWeight::get().reads(1));
+			let prefix = sp_io::hashing::twox_128(b"Sudo");
+			assert!(sp_io::storage::next_key(&prefix).map_or(true, |key| !key.starts_with(&prefix)));
+		});
+	}
+
+	/// Declares a migration of the `Test` pallet from `$from`, which counts its runs in `Runs` and
+	/// fails its checks once it ran three times.
+	macro_rules! count_runs {
+		($name:ident, $from:expr) => {
+			struct $name;
+
+			impl migrations::Migration for $name {
+				const PALLET: &'static str = "Test";
+				const FROM: u16 = $from;
+
+				fn migrate() -> Weight {
+					frame_support::storage::migration::put_storage_value(b"Test", b"Runs", &[], runs() + 1);
+					1_000
+				}
+
+				fn post_upgrade() -> Result<(), &'static str> {
+					if runs() < 3 { Ok(()) } else { Err("ran too often") }
+				}
+			}
+		}
+	}
+
+	count_runs!(FromZero, 0);
+	count_runs!(FromOne, 1);
+	count_runs!(FromTwo, 2);
+
+	fn runs() -> u32 {
+		frame_support::storage::migration::get_storage_value(b"Test", b"Runs", &[]).unwrap_or_default()
+	}
+
+	#[test]
+	fn migrations_run_in_order_once() {
+		use migrations::{MigrationList, Versioned};
+
+		type Steps = (Versioned<FromOne>, Versioned<FromZero>, Versioned<FromTwo>);
+		let db = RocksDbWeight::get();
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			// Only the migration from version 0 applies, moving the pallet to version 1, so the
+			// migration from version 1 listed before it has to wait for the next upgrade.
+			assert_eq!(Steps::run(), db.reads(1) + 1_000 + db.reads_writes(1, 1) + db.reads(1));
+			assert_eq!(migrations::storage_version("Test"), 1);
+			assert_eq!(runs(), 1);
+
+			// The migrations from versions 1 and 2 now run in turn, the latter failing its check.
+			assert_eq!(Steps::run_checked(), Err("ran too often"));
+			assert_eq!(migrations::storage_version("Test"), 3);
+			assert_eq!(runs(), 3);
+
+			assert_eq!(Steps::run(), 3 * db.reads(1));
+		});
+	}
+
+	#[test]
+	fn genesis_marks_migrations_as_applied() {
+		use migrations::{MigrationList, Versioned};
+
+		type Steps = (Versioned<FromZero>, Versioned<FromOne>);
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			Steps::mark_applied();
+			assert_eq!(migrations::storage_version("Test"), 2);
+
+			// The first upgrade of the chain runs none of them.
+			assert_eq!(Steps::run(), 2 * RocksDbWeight::get().reads(1));
+			assert_eq!(runs(), 0);
+		});
+	}
+
+	/// State to test the migrations against: the raw chain spec named by `MIGRATION_SNAPSHOT`, as
+	/// written by `export-state`, or an empty state.
+	fn migration_snapshot() -> sp_io::TestExternalities {
+		let path = match std::env::var("MIGRATION_SNAPSHOT") {
+			Ok(path) => path,
+			Err(_) => return sp_io::TestExternalities::default(),
+		};
+
+		let spec: serde_json::Value = serde_json::from_slice(
+			&std::fs::read(&path).expect("MIGRATION_SNAPSHOT is readable"),
+		).expect("MIGRATION_SNAPSHOT is a chain spec");
+		let top = spec["genesis"]["raw"]["top"].as_object()
+			.expect("MIGRATION_SNAPSHOT is a raw chain spec");
+		let decode = |hex: &str| sp_core::bytes::from_hex(hex).expect("raw storage is hex");
+
+		sp_io::TestExternalities::new(sp_core::storage::Storage {
+			top: top.iter()
+				.map(|(key, value)| (decode(key), decode(value.as_str().unwrap_or_default())))
+				.collect(),
+			children_default: Default::default(),
+		})
+	}
+
+	#[test]
+	fn migrations_pass_their_checks_and_fit_in_a_block() {
+		use migrations::{MigrationList, Migrations};
+
+		migration_snapshot().execute_with(|| {
+			let weight = Migrations::run_checked().expect("migration checks pass");
+			assert!(weight <= MaximumBlockWeight::get());
+		});
+	}
+
+	#[test]
+	fn pallets_after_sudo_keep_their_indices() {
+		// The indices the calls and events had when sudo was the 19th pallet with calls and the
+		// 15th with events, in both variants of the runtime.
+		let call = Call::Scheduler(pallet_scheduler::Call::cancel(0, 0));
+		assert_eq!(call.encode()[0], 27);
+		let call = Call::Multisig(pallet_multisig::Call::as_multi_threshold_1(vec![], Box::new(remark())));
+		assert_eq!(call.encode()[0], 29);
+
+		let event = Event::pallet_scheduler(pallet_scheduler::RawEvent::Canceled(0, 0));
+		assert_eq!(event.encode()[0], 21);
+	}
+
+	#[test]
+	#[cfg(not(feature = "sudo"))]
+	fn root_calls_are_only_reachable_through_governance() {
+		use frame_support::traits::OnInitialize;
+		use pallet_democracy::{AccountVote, Conviction, Vote};
+		use sp_runtime::traits::{Dispatchable, Hash as HashT};
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(!Call::get_module_names().contains(&"Sudo"));
+
+			let proposer = account(1);
+			let _ = Balances::deposit_creating(&proposer, 1_000 * DOLLARS);
+			let call = Call::Balances(BalancesCall::set_balance(address(2), 42 * DOLLARS, 0));
+			let council = |ayes, members| Origin::from(
+				pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(ayes, members)
+			);
+
+			// Neither accounts nor the council can dispatch it directly.
+			assert!(call.clone().dispatch(Origin::signed(proposer.clone())).is_err());
+			assert!(call.clone().dispatch(council(4, 4)).is_err());
+
+			// A council majority tables it as an external proposal, the referendum passes and
+			// the scheduler enacts it as root.
+			let proposal_hash = BlakeTwo256::hash_of(&call);
+			assert!(Democracy::note_preimage(Origin::signed(proposer.clone()), call.encode()).is_ok());
+			assert!(Democracy::external_propose_majority(council(3, 4), proposal_hash).is_ok());
+
+			let launch = LaunchPeriod::get();
+			Democracy::on_initialize(launch);
+			assert!(Democracy::vote(Origin::signed(proposer), 0, AccountVote::Standard {
+				vote: Vote { aye: true, conviction: Conviction::None },
+				balance: 10 * DOLLARS,
+			}).is_ok());
+
+			let end = launch + VotingPeriod::get();
+			Democracy::on_initialize(end);
+			assert_eq!(Balances::free_balance(&account(2)), 0);
+
+			Scheduler::on_initialize(end + EnactmentPeriod::get());
+			assert_eq!(Balances::free_balance(&account(2)), 42 * DOLLARS);
+		});
+	}
+
+	#[test]
+	fn base_filter_rejects_paused_calls() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let transfer = Call::Balances(BalancesCall::transfer(address(2), 1));
+			let transfer_keep_alive = Call::Balances(BalancesCall::transfer_keep_alive(address(2), 1));
+
+			assert!(TransactionPause::pause_transaction(
+				Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()
+			).is_ok());
+			assert!(!BaseFilter::filter(&transfer));
+			assert!(BaseFilter::filter(&transfer_keep_alive));
+
+			assert!(TransactionPause::unpause_transaction(
+				Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()
+			).is_ok());
+			assert!(BaseFilter::filter(&transfer));
+		});
+	}
+
+	#[test]
+	fn governance_calls_cannot_be_paused() {
+		use frame_support::traits::GetCallMetadata;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			for call in sample_calls().iter() {
+				let metadata = call.get_call_metadata();
+				if !UnpausablePallets::get().contains(&metadata.pallet_name) {
+					continue;
+				}
+				assert!(TransactionPause::pause_transaction(
+					Origin::root(),
+					metadata.pallet_name.as_bytes().to_vec(),
+					metadata.function_name.as_bytes().to_vec(),
+				).is_err());
+				assert!(BaseFilter::filter(call), "{:?} was paused", call);
+			}
+		});
+	}
 }
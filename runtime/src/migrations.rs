@@ -0,0 +1,196 @@
+//! Storage migrations run by `Executive` on the first block of a new runtime.
+//!
+//! Every pallet has a storage version, 0 until its first migration. A [`Migration`] upgrades one
+//! pallet from version `FROM` to `FROM + 1` and is listed, wrapped in [`Versioned`], in
+//! [`Migrations`], which runs in order. A migration only runs while its pallet is at `FROM`, so
+//! it can stay listed until every network upgraded past it.
+//!
+//! The versions are kept by this module, a pallet of the runtime. Its genesis marks every listed
+//! migration as applied, since a new chain starts with the current layouts, and `Executive` runs
+//! the upgrade hooks on the first block of every chain. A pallet added to a live chain is at
+//! version 0 there, so its migrations must tolerate the new layout, which is usually empty.
+//!
+//! Migrations cleaning up after a pallet removed from the runtime are not versioned: there is no
+//! pallet left to track, so they must be idempotent instead, see [`RemoveSudo`].
+
+use frame_support::{
+	debug, decl_module, decl_storage,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::{Weight, constants::RocksDbWeight},
+};
+#[cfg(all(feature = "std", not(feature = "sudo")))]
+use frame_support::storage::migration::get_storage_value;
+#[cfg(not(feature = "sudo"))]
+use frame_support::storage::migration::take_storage_value;
+#[cfg(not(feature = "sudo"))]
+use node_primitives::AccountId;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The migrations of the runtime, in the order they run.
+#[cfg(not(feature = "sudo"))]
+pub type Migrations = (
+	RemoveSudo,
+);
+
+/// The migrations of the runtime, in the order they run.
+#[cfg(feature = "sudo")]
+pub type Migrations = ();
+
+/// Runs [`Migrations`], used as the `OnRuntimeUpgrade` of `Executive`.
+pub struct RunMigrations;
+
+impl OnRuntimeUpgrade for RunMigrations {
+	fn on_runtime_upgrade() -> Weight {
+		Migrations::run()
+	}
+}
+
+pub trait Trait: frame_system::Trait {}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as Migrations {
+		/// The storage version of each pallet, by name in `construct_runtime!`.
+		StorageVersions: map hasher(twox_64_concat) Vec<u8> => u16;
+	}
+	add_extra_genesis {
+		build(|_config: &GenesisConfig| Migrations::mark_applied());
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {}
+}
+
+/// The storage version of `pallet`.
+pub fn storage_version(pallet: &str) -> u16 {
+	StorageVersions::get(pallet.as_bytes())
+}
+
+fn set_storage_version(pallet: &str, version: u16) {
+	StorageVersions::insert(pallet.as_bytes(), version)
+}
+
+/// A storage migration of one pallet.
+pub trait Migration {
+	/// Name of the pallet in `construct_runtime!`, which prefixes its storage.
+	const PALLET: &'static str;
+	/// The storage version the migration upgrades from.
+	const FROM: u16;
+
+	/// Rewrites the storage, returning the weight it consumed.
+	fn migrate() -> Weight;
+
+	/// Checks the state before the migration.
+	#[cfg(feature = "std")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		Ok(())
+	}
+
+	/// Checks the state after the migration.
+	#[cfg(feature = "std")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		Ok(())
+	}
+}
+
+/// An ordered list of migrations.
+pub trait MigrationList {
+	/// Runs the migrations, returning the weight they consumed.
+	fn run() -> Weight;
+
+	/// Runs the migrations, each between its pre and post upgrade checks.
+	#[cfg(feature = "std")]
+	fn run_checked() -> Result<Weight, &'static str>;
+
+	/// Records the migrations as applied without running them, for a chain whose storage is
+	/// already in the new layouts.
+	fn mark_applied();
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl MigrationList for Tuple {
+	fn run() -> Weight {
+		let mut weight: Weight = 0;
+		for_tuples!( #( weight = weight.saturating_add(Tuple::run()); )* );
+		weight
+	}
+
+	#[cfg(feature = "std")]
+	fn run_checked() -> Result<Weight, &'static str> {
+		let mut weight: Weight = 0;
+		for_tuples!( #( weight = weight.saturating_add(Tuple::run_checked()?); )* );
+		Ok(weight)
+	}
+
+	fn mark_applied() {
+		for_tuples!( #( Tuple::mark_applied(); )* );
+	}
+}
+
+/// Runs `M` if its pallet is at version `M::FROM`, then bumps the version.
+pub struct Versioned<M>(PhantomData<M>);
+
+impl<M: Migration> MigrationList for Versioned<M> {
+	fn run() -> Weight {
+		if storage_version(M::PALLET) != M::FROM {
+			return RocksDbWeight::get().reads(1);
+		}
+
+		debug::info!("🚚 Migrating {} storage from version {}", M::PALLET, M::FROM);
+		let weight = M::migrate();
+		set_storage_version(M::PALLET, M::FROM + 1);
+
+		weight.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+	}
+
+	#[cfg(feature = "std")]
+	fn run_checked() -> Result<Weight, &'static str> {
+		if storage_version(M::PALLET) != M::FROM {
+			return Ok(Self::run());
+		}
+
+		M::pre_upgrade()?;
+		let weight = Self::run();
+		M::post_upgrade()?;
+
+		Ok(weight)
+	}
+
+	fn mark_applied() {
+		if storage_version(M::PALLET) <= M::FROM {
+			set_storage_version(M::PALLET, M::FROM + 1);
+		}
+	}
+}
+
+/// Clears the key of the removed `Sudo` pallet, leaving root to governance.
+///
+/// The pallet is no longer part of the runtime, so its only storage item is removed by its raw
+/// key, on every upgrade until it is dropped from [`Migrations`]. Runtimes built with the `sudo`
+/// feature keep the pallet and its key.
+#[cfg(not(feature = "sudo"))]
+pub struct RemoveSudo;
+
+#[cfg(not(feature = "sudo"))]
+impl MigrationList for RemoveSudo {
+	fn run() -> Weight {
+		match take_storage_value::<AccountId>(b"Sudo", b"Key", &[]) {
+			Some(_) => {
+				debug::info!("🚚 Removing the sudo key");
+				RocksDbWeight::get().reads_writes(1, 1)
+			},
+			None => RocksDbWeight::get().reads(1),
+		}
+	}
+
+	#[cfg(feature = "std")]
+	fn run_checked() -> Result<Weight, &'static str> {
+		let weight = Self::run();
+		match get_storage_value::<AccountId>(b"Sudo", b"Key", &[]) {
+			Some(_) => Err("the sudo key is still set"),
+			None => Ok(weight),
+		}
+	}
+
+	fn mark_applied() {}
+}
@@ -0,0 +1,41 @@
+//! Constants that differ between the networks sharing this runtime.
+//!
+//! The `gama-runtime` and `colombo-runtime` packages compile the same sources and each enables
+//! the feature of its network. The feature is forwarded to the WASM build, so native and WASM
+//! runtimes of a package always agree on the profile.
+
+#[cfg(all(feature = "gama", feature = "colombo"))]
+compile_error!("the `gama` and `colombo` features are mutually exclusive");
+
+#[cfg(not(any(feature = "gama", feature = "colombo")))]
+compile_error!("either the `gama` or the `colombo` feature must be enabled");
+
+#[cfg(feature = "gama")]
+mod profile {
+	use node_primitives::BlockNumber;
+	use crate::constants::time::MINUTES;
+
+	pub const SPEC_NAME: &str = "gama";
+	pub const IMPL_NAME: &str = "aochain-gama";
+	pub const EPOCH_DURATION_IN_BLOCKS: BlockNumber = 10 * MINUTES;
+	pub const SS58_PREFIX: u8 = 98;
+	pub const TOKEN_SYMBOL: &str = "COM";
+}
+
+#[cfg(feature = "colombo")]
+mod profile {
+	use node_primitives::BlockNumber;
+	use crate::constants::time::HOURS;
+
+	pub const SPEC_NAME: &str = "colombo";
+	pub const IMPL_NAME: &str = "aochain-colombo";
+	pub const EPOCH_DURATION_IN_BLOCKS: BlockNumber = 1 * HOURS;
+	/// Next to the one of gama, so testnet addresses never pass for gama or generic Substrate ones.
+	pub const SS58_PREFIX: u8 = 99;
+	pub const TOKEN_SYMBOL: &str = "TCOM";
+}
+
+pub use profile::*;
+
+/// Decimals of the native token, the same on every network.
+pub const TOKEN_DECIMALS: u8 = 10;
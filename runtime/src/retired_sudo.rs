@@ -0,0 +1,24 @@
+//! Takes the place of the removed `Sudo` pallet in `construct_runtime!`.
+//!
+//! Calls and events are encoded with the index of their pallet among the pallets having calls,
+//! resp. events. Dropping `Sudo` would shift the index of every pallet after it, and the calls
+//! stored by the scheduler, democracy preimages, multisig and proxy announcements would decode
+//! as calls of another pallet. This pallet keeps those indices: it has no call, no storage, and
+//! its only event is never deposited.
+
+use frame_support::{decl_event, decl_module};
+
+pub trait Trait: frame_system::Trait {
+	type Event: From<Event> + Into<<Self as frame_system::Trait>::Event>;
+}
+
+decl_event!(
+	pub enum Event {
+		/// Never deposited, the pallet only needs an event index.
+		Retired,
+	}
+);
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {}
+}